@@ -4,7 +4,7 @@ use tokio::io::AsyncReadExt;
 use tokio::time::Instant;
 use tokio::{fs::File, io::AsyncSeekExt};
 
-use dckv::{KVMap, Key, Deserializer, Value, Filter};
+use dckv::{Control, KVMap, Key, Deserializer, Value, Filter};
 
 #[derive(Clone)]
 struct Shared;
@@ -17,17 +17,20 @@ impl Deserializer for Shared {
         key: Key,
         length: usize,
         vr: Option<u16>,
-    ) {
-        let value = Value::read(reader, length).await.unwrap();
+    ) -> dckv::Result<Control> {
+        let value = Value::read(reader, length).await?;
 
         println!(
-            "({:04x},{:04x}) {} {} {}",
+            "({:04x},{:04x}) {} {} {} {}",
             key.group(),
             key.element(),
+            key.keyword().unwrap_or_default(),
             key.vr().unwrap_or_default(),
             key.level(),
             value.to_string(vr),
         );
+
+        Ok(Control::Continue)
     }
 }
 
@@ -0,0 +1,132 @@
+use std::collections::HashSet;
+use std::io::{Read, Seek, SeekFrom};
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+use crate::{machine, Result, Value};
+
+/// Selects the values the parser defers: instead of being read, they are
+/// appended as a [`BulkDataRef`] into the source, to be fetched later.
+///
+/// The policy only applies to seekable sources: the values of a deflated
+/// dataset, which has no offsets in the source, and of in-memory
+/// [`BytesDeserializer`](crate::BytesDeserializer) parses, which share the
+/// source anyway, are always appended inline.
+///
+/// ```
+/// use dckv::BulkDataPolicy;
+///
+/// // Values over 64 KiB, and every OB, OW and UN value.
+/// let policy = BulkDataPolicy::new().threshold(64 * 1024).vrs(["OB", "OW", "UN"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BulkDataPolicy {
+    threshold: Option<usize>,
+    vrs: HashSet<u16>,
+}
+
+impl BulkDataPolicy {
+    /// Defers no value.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Defers the values longer than `length` bytes.
+    #[inline]
+    pub fn threshold(mut self, length: usize) -> Self {
+        self.threshold = Some(length);
+        self
+    }
+
+    /// Defers the values of `vr`, e.g. `"OB"`.
+    #[inline]
+    pub fn vr(mut self, vr: &str) -> Self {
+        if let &[first, second] = vr.as_bytes() {
+            self.vrs.insert(u16::from_be_bytes([first, second]));
+        }
+        self
+    }
+
+    #[inline]
+    pub fn vrs<'v, I>(self, vrs: I) -> Self
+    where
+        I: IntoIterator<Item = &'v str>,
+    {
+        vrs.into_iter().fold(self, Self::vr)
+    }
+
+    /// Whether a value of `length` bytes and VR `vr` is deferred. Empty
+    /// values never are.
+    #[inline]
+    pub(crate) fn defers(&self, length: usize, vr: u16) -> bool {
+        length > 0
+            && (self.threshold.is_some_and(|threshold| length > threshold)
+                || self.vrs.contains(&vr))
+    }
+}
+
+/// Location of a deferred value in the source the dataset was parsed
+/// from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BulkDataRef {
+    offset: u64,
+    length: usize,
+    swap_width: Option<usize>,
+}
+
+impl BulkDataRef {
+    #[inline]
+    pub(crate) fn new(offset: u64, length: usize, swap_width: Option<usize>) -> Self {
+        Self {
+            offset,
+            length,
+            swap_width,
+        }
+    }
+
+    /// Position of the value from the start of the source.
+    #[inline]
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    #[inline]
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    /// Reads the value from the source, converting big endian binary
+    /// numbers to little endian like the parser does for inline values.
+    pub async fn read<R>(&self, reader: &mut R) -> Result<Value>
+    where
+        R: AsyncReadExt + AsyncSeekExt + Unpin,
+    {
+        reader.seek(SeekFrom::Start(self.offset)).await?;
+
+        let mut bytes = vec![0; self.length];
+        reader.read_exact(&mut bytes).await?;
+
+        if let Some(width) = self.swap_width {
+            machine::swap(&mut bytes, width);
+        }
+
+        Ok(Value::new(bytes))
+    }
+
+    /// Blocking [`BulkDataRef::read`].
+    pub fn read_sync<R>(&self, reader: &mut R) -> Result<Value>
+    where
+        R: Read + Seek,
+    {
+        reader.seek(SeekFrom::Start(self.offset))?;
+
+        let mut bytes = vec![0; self.length];
+        reader.read_exact(&mut bytes)?;
+
+        if let Some(width) = self.swap_width {
+            machine::swap(&mut bytes, width);
+        }
+
+        Ok(Value::new(bytes))
+    }
+}
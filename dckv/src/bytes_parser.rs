@@ -0,0 +1,164 @@
+use bytes::Bytes;
+
+use crate::machine::{inflate_bytes, swap, Input, Machine, Options, Step};
+use crate::{Control, FileMeta, Filter, KeyRef, PrivateDictionary, Result, MAX_DEPTH};
+
+/// In-memory dataset read forward, handing out views of its bytes.
+struct Source {
+    bytes: Bytes,
+    position: usize,
+}
+
+impl Source {
+    #[inline]
+    fn new(bytes: Bytes) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    /// Reads up to `length` bytes, fewer at the end.
+    #[inline]
+    fn read(&mut self, length: usize) -> &[u8] {
+        let start = self.position;
+        self.position = self.bytes.len().min(start.saturating_add(length));
+
+        &self.bytes[start..self.position]
+    }
+
+    #[inline]
+    fn peek(&self, length: usize) -> &[u8] {
+        let end = self.bytes.len().min(self.position.saturating_add(length));
+
+        &self.bytes[self.position..end]
+    }
+
+    /// Views the next `length` bytes.
+    #[inline]
+    fn take(&mut self, length: usize) -> Result<Bytes> {
+        let end = self.position + length;
+        if end > self.bytes.len() {
+            return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+        }
+
+        let bytes = self.bytes.slice(self.position..end);
+        self.position = end;
+
+        Ok(bytes)
+    }
+
+    #[inline]
+    fn skip(&mut self, length: u64) -> Result<()> {
+        self.take(length as usize).map(|_| ())
+    }
+
+    /// Bytes left to read.
+    #[inline]
+    fn rest(&self) -> &[u8] {
+        &self.bytes[self.position..]
+    }
+}
+
+/// Runs the steps of `machine` over `source`, until the parse ends or the
+/// rest of the source has to be inflated, returning whether it has. Values
+/// are appended as views of the source, or as a swapped copy when they
+/// hold big endian binary numbers.
+fn drive<S>(shared: &mut S, source: &mut Source, machine: &mut Machine<'_>) -> Result<bool>
+where
+    S: Clone + BytesDeserializer,
+{
+    // Reused by every key handed to the deserializer.
+    let mut key_buffer = Vec::with_capacity(64);
+    let mut input = Input::None;
+
+    loop {
+        input = match machine.resume(input)? {
+            Step::Read(length) => Input::Bytes(source.read(length)),
+            Step::Peek(length) => Input::Bytes(source.peek(length)),
+            Step::Skip(length) => {
+                source.skip(length)?;
+                Input::None
+            }
+            Step::Value {
+                vr,
+                length,
+                swap_width,
+            } => {
+                let value = source.take(length)?;
+                let value = match swap_width {
+                    Some(width) => {
+                        let mut bytes = value.to_vec();
+                        swap(&mut bytes, width);
+                        Bytes::from(bytes)
+                    }
+                    None => value,
+                };
+
+                let key = KeyRef::from_key_blocks(&mut key_buffer, machine.key_blocks());
+                Input::Control(shared.append(key, value, Some(vr))?)
+            }
+            // Values being already in memory, no bulk data policy is set.
+            Step::BulkData { .. } => unreachable!("bulk data policy of an in-memory parse"),
+            Step::Marker { vr } => {
+                let key = KeyRef::from_key_blocks(&mut key_buffer, machine.key_blocks());
+                Input::Control(shared.append(key, Bytes::new(), vr)?)
+            }
+            Step::Inflate => return Ok(true),
+            Step::End => return Ok(false),
+        };
+    }
+}
+
+/// Deserializer of a dataset held in memory. The parser copies nothing:
+/// values are reference-counted views of the input, and keys borrow a
+/// buffer reused for every key, so that it allocates per nesting level and
+/// private block, not per element. Big endian binary numbers and deflated
+/// datasets, which have to be converted, are the exception. What is kept
+/// of a key or value is up to the implementation, e.g. [`KVMap`](crate::KVMap)
+/// copies both into its own map.
+///
+/// Keys and control flow are the same as with [`Deserializer`](crate::Deserializer).
+/// Values being already in memory, bulk data policies do not apply.
+pub trait BytesDeserializer
+where
+    Self: Clone,
+{
+    /// Parses a DICOM file or a bare dataset, returning its file meta
+    /// information if present. The dataset is decoded according to the
+    /// transfer syntax (0002,0010), or guessed when there is none, until
+    /// its end or until an append returns [`Control::Stop`].
+    fn deserialize(&mut self, bytes: Bytes, filter: &Filter) -> Result<Option<FileMeta>> {
+        let options = Options {
+            filter,
+            bulk_data: None,
+            max_depth: self.max_depth(),
+            private: self.private_dictionary().cloned().unwrap_or_default(),
+        };
+        let mut machine = Machine::new(options, 0);
+
+        let mut source = Source::new(bytes);
+        if drive(self, &mut source, &mut machine)? {
+            let inflated = inflate_bytes(source.rest())?;
+            drive(self, &mut Source::new(Bytes::from(inflated)), &mut machine)?;
+        }
+
+        Ok(machine.into_meta())
+    }
+
+    /// Appends the key of an element, sequence or item with its value,
+    /// empty for sequences and items. Returning [`Control::Skip`] for a
+    /// sequence or an item skips everything nested in it.
+    fn append(&mut self, key: KeyRef<'_>, value: Bytes, vr: Option<u16>) -> Result<Control>;
+
+    /// Private dictionary resolving the VR of private elements in implicit
+    /// VR, none by default.
+    #[inline]
+    fn private_dictionary(&self) -> Option<&PrivateDictionary> {
+        None
+    }
+
+    /// Maximum number of nested sequence levels, beyond which parsing fails
+    /// with [`DCKVError::TooManyNestedLevels`]. [`MAX_DEPTH`] by default.
+    #[inline]
+    fn max_depth(&self) -> usize {
+        MAX_DEPTH
+    }
+}
@@ -0,0 +1,280 @@
+//! Specific Character Set (0008,0005) decoding (PS3.5 6.1, PS3.3 C.12.1.1.2).
+
+use encoding_rs::{
+    Encoding, EUC_JP, EUC_KR, GB18030, GBK, ISO_8859_15, ISO_8859_2, ISO_8859_3, ISO_8859_4,
+    ISO_8859_5, ISO_8859_6, ISO_8859_7, ISO_8859_8, SHIFT_JIS, UTF_8, WINDOWS_1252, WINDOWS_1254,
+    WINDOWS_874,
+};
+
+use crate::{vr, DCKVError, Result, Value};
+
+/// Code element invoked in the G0 area, bytes 0x21 to 0x7E.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum G0 {
+    Ascii,
+    /// JIS X 0201 Romaji, with a yen sign and an overline.
+    JisRoman,
+    /// JIS X 0208, two bytes per character.
+    JisX0208,
+    /// JIS X 0212, two bytes per character.
+    JisX0212,
+}
+
+/// Code element designated by an escape sequence.
+#[derive(Debug, Clone, Copy)]
+enum Designation {
+    G0(G0),
+    G1(&'static Encoding),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    /// Single character set without code extensions.
+    Plain(&'static Encoding),
+    /// ISO 2022 code extensions, starting with the code elements of the
+    /// first value. G1 is decoded by an encoding reading the bytes 0x80 to
+    /// 0xFF as they are.
+    Iso2022 {
+        g0: G0,
+        g1: Option<&'static Encoding>,
+    },
+}
+
+/// Character set of the text values of a dataset, from its (0008,0005)
+/// Specific Character Set or the one of an enclosing dataset.
+///
+/// ```
+/// use dckv::{CharacterSet, Value};
+///
+/// let charset = CharacterSet::from_value(&Value::new(b"ISO_IR 100".to_vec())).unwrap();
+/// let name = Value::new(b"Mu\xF1oz>Garc\xEDa^Jos\xE9".to_vec());
+/// assert_eq!(name.to_text(0x504e, &charset), "Muñoz>García^José");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CharacterSet {
+    kind: Kind,
+}
+
+impl Default for CharacterSet {
+    /// Default character repertoire, ASCII.
+    #[inline]
+    fn default() -> Self {
+        Self {
+            kind: Kind::Plain(UTF_8),
+        }
+    }
+}
+
+impl CharacterSet {
+    /// Character set of the defined terms of a (0008,0005) value.
+    pub fn from_value(value: &Value) -> Result<Self> {
+        let terms = value.to_strs(vr::CS)?;
+        let Some(first) = terms.first() else {
+            return Ok(Self::default());
+        };
+
+        let extended = terms.len() > 1 || first.starts_with("ISO 2022");
+        if !extended {
+            let encoding = match *first {
+                "ISO_IR 6" => UTF_8,
+                "ISO_IR 192" => UTF_8,
+                "GB18030" => GB18030,
+                "GBK" => GBK,
+                term => single_byte(term.trim_start_matches("ISO_IR "))
+                    .ok_or_else(|| DCKVError::UnsupportedCharacterSet(term.to_string()))?,
+            };
+
+            return Ok(Self {
+                kind: Kind::Plain(encoding),
+            });
+        }
+
+        for term in &terms[1..] {
+            let supported = term.strip_prefix("ISO 2022 IR ").is_some_and(|number| {
+                matches!(number, "6" | "87" | "159" | "149" | "58") || single_byte(number).is_some()
+            });
+            if !supported {
+                return Err(DCKVError::UnsupportedCharacterSet(term.to_string()));
+            }
+        }
+
+        // The first value may be empty for the default repertoire.
+        let number = first
+            .trim_start_matches("ISO 2022 IR ")
+            .trim_start_matches("ISO_IR ");
+        let (g0, g1) = match number {
+            "" | "6" => (G0::Ascii, None),
+            "13" => (G0::JisRoman, Some(SHIFT_JIS)),
+            number => (
+                G0::Ascii,
+                Some(
+                    single_byte(number)
+                        .ok_or_else(|| DCKVError::UnsupportedCharacterSet(first.to_string()))?,
+                ),
+            ),
+        };
+
+        Ok(Self {
+            kind: Kind::Iso2022 { g0, g1 },
+        })
+    }
+
+    /// Text of a value of `vr`. Only SH, LO, ST, LT, PN, UC and UT values
+    /// may hold characters out of the default repertoire.
+    pub fn decode(&self, bytes: &[u8], vr: u16) -> String {
+        let extended = matches!(
+            vr,
+            vr::SH | vr::LO | vr::ST | vr::LT | vr::PN | vr::UC | vr::UT
+        );
+
+        match (extended, self.kind) {
+            (false, _) => String::from_utf8_lossy(bytes).into_owned(),
+            (true, Kind::Plain(encoding)) => decode(encoding, bytes),
+            (true, Kind::Iso2022 { g0, g1 }) => decode_iso2022(bytes, vr, g0, g1),
+        }
+    }
+}
+
+/// Encoding of the single byte character set `ISO_IR <number>`.
+#[inline]
+fn single_byte(number: &str) -> Option<&'static Encoding> {
+    match number {
+        // Windows code pages are supersets of ISO 8859-1, 8859-9 and TIS 620.
+        "100" => Some(WINDOWS_1252),
+        "101" => Some(ISO_8859_2),
+        "109" => Some(ISO_8859_3),
+        "110" => Some(ISO_8859_4),
+        "144" => Some(ISO_8859_5),
+        "127" => Some(ISO_8859_6),
+        "126" => Some(ISO_8859_7),
+        "138" => Some(ISO_8859_8),
+        "148" => Some(WINDOWS_1254),
+        "203" => Some(ISO_8859_15),
+        "166" => Some(WINDOWS_874),
+        "13" => Some(SHIFT_JIS),
+        _ => None,
+    }
+}
+
+#[inline]
+fn decode(encoding: &'static Encoding, bytes: &[u8]) -> String {
+    encoding.decode_without_bom_handling(bytes).0.into_owned()
+}
+
+/// Code element switched to by the escape sequence starting `bytes`, after
+/// the ESC, with the length of the sequence.
+#[inline]
+fn escape(bytes: &[u8]) -> Option<(usize, Designation)> {
+    let g0 = |g0| Some((2, Designation::G0(g0)));
+    let g1 = |encoding| Some((2, Designation::G1(encoding)));
+
+    match bytes {
+        [b'(', b'B', ..] => g0(G0::Ascii),
+        [b'(', b'J', ..] => g0(G0::JisRoman),
+        [b'$', b'B', ..] | [b'$', b'@', ..] => g0(G0::JisX0208),
+        [b'$', b'(', b'D', ..] => Some((3, Designation::G0(G0::JisX0212))),
+        [b'$', b')', b'C', ..] => Some((3, Designation::G1(EUC_KR))),
+        [b'$', b')', b'A', ..] => Some((3, Designation::G1(GBK))),
+        [b')', b'I', ..] => g1(SHIFT_JIS),
+        [b'-', b'A', ..] => g1(WINDOWS_1252),
+        [b'-', b'B', ..] => g1(ISO_8859_2),
+        [b'-', b'C', ..] => g1(ISO_8859_3),
+        [b'-', b'D', ..] => g1(ISO_8859_4),
+        [b'-', b'L', ..] => g1(ISO_8859_5),
+        [b'-', b'G', ..] => g1(ISO_8859_6),
+        [b'-', b'F', ..] => g1(ISO_8859_7),
+        [b'-', b'H', ..] => g1(ISO_8859_8),
+        [b'-', b'M', ..] => g1(WINDOWS_1254),
+        [b'-', b'b', ..] => g1(ISO_8859_15),
+        [b'-', b'T', ..] => g1(WINDOWS_874),
+        _ => None,
+    }
+}
+
+/// Whether `byte` delimits values of `vr`, or lines, before which the code
+/// elements of the first value are invoked again (PS3.5 6.1.2.5.3).
+#[inline]
+fn is_delimiter(byte: u8, vr: u16) -> bool {
+    match byte {
+        b'\r' | b'\n' | b'\x0C' | b'\t' => true,
+        b'\\' => !matches!(vr, vr::LT | vr::ST | vr::UT),
+        b'^' | b'=' => vr == vr::PN,
+        _ => false,
+    }
+}
+
+fn decode_iso2022(
+    bytes: &[u8],
+    vr: u16,
+    initial_g0: G0,
+    initial_g1: Option<&'static Encoding>,
+) -> String {
+    let mut text = String::with_capacity(bytes.len());
+    let (mut g0, mut g1) = (initial_g0, initial_g1);
+
+    let mut position = 0;
+    while let Some(&byte) = bytes.get(position) {
+        let rest = &bytes[position..];
+
+        if byte == 0x1B {
+            match escape(&rest[1..]) {
+                Some((length, designation)) => {
+                    match designation {
+                        Designation::G0(element) => g0 = element,
+                        Designation::G1(encoding) => g1 = Some(encoding),
+                    }
+                    position += 1 + length;
+                }
+                None => {
+                    text.push(char::REPLACEMENT_CHARACTER);
+                    position += 1;
+                }
+            }
+            continue;
+        }
+
+        if byte >= 0x80 {
+            let length = rest.iter().take_while(|&&byte| byte >= 0x80).count();
+            match g1 {
+                Some(encoding) => text.push_str(&decode(encoding, &rest[..length])),
+                None => (0..length).for_each(|_| text.push(char::REPLACEMENT_CHARACTER)),
+            }
+            position += length;
+            continue;
+        }
+
+        if matches!(g0, G0::JisX0208 | G0::JisX0212) && (0x21..=0x7E).contains(&byte) {
+            let length = rest
+                .iter()
+                .take_while(|&&byte| (0x21..=0x7E).contains(&byte))
+                .count();
+
+            // EUC-JP holds JIS X 0208 in G1, and JIS X 0212 after SS3.
+            let mut euc = Vec::with_capacity(length * 3 / 2);
+            for pair in rest[..length].chunks(2) {
+                if g0 == G0::JisX0212 {
+                    euc.push(0x8F);
+                }
+                euc.extend(pair.iter().map(|byte| byte | 0x80));
+            }
+            text.push_str(&decode(EUC_JP, &euc));
+
+            position += length;
+            continue;
+        }
+
+        if is_delimiter(byte, vr) {
+            (g0, g1) = (initial_g0, initial_g1);
+            text.push(byte as char);
+        } else {
+            text.push(match (g0, byte) {
+                (G0::JisRoman, b'\\') => '¥',
+                (G0::JisRoman, b'~') => '‾',
+                _ => byte as char,
+            });
+        }
+        position += 1;
+    }
+
+    text
+}
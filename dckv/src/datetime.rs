@@ -0,0 +1,256 @@
+//! DA, TM and DT values (PS3.5 6.2), and their ranges in queries
+//! (PS3.4 C.2.2.2.5).
+//!
+//! Values of partial precision, e.g. a DT of `2024` or a TM of `10`, stand
+//! for their whole period: they parse to its first instant, or to its last
+//! one when they end a range.
+
+use chrono::{
+    DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone, Utc,
+};
+
+use crate::{DCKVError, Result};
+
+/// Inclusive range of dates or times, open when a bound is missing.
+///
+/// ```
+/// use dckv::Value;
+///
+/// // (0008,0020) Study Date of a query.
+/// let range = Value::new(b"20240101-".to_vec()).to_date_range().unwrap();
+/// assert!(range.contains(&Value::new(b"20240315".to_vec()).to_date().unwrap()));
+/// assert_eq!(range.end(), None);
+///
+/// // A DT of partial precision stands for its whole period.
+/// let range = Value::new(b"202402".to_vec()).to_datetime_range(None).unwrap();
+/// let leap_day = Value::new(b"20240229235959+0000".to_vec()).to_datetime(None).unwrap();
+/// assert!(range.contains(&leap_day));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateTimeRange<T> {
+    start: Option<T>,
+    end: Option<T>,
+}
+
+impl<T> DateTimeRange<T>
+where
+    T: PartialOrd + Copy,
+{
+    #[inline]
+    pub fn start(&self) -> Option<T> {
+        self.start
+    }
+
+    #[inline]
+    pub fn end(&self) -> Option<T> {
+        self.end
+    }
+
+    #[inline]
+    pub fn contains(&self, value: &T) -> bool {
+        self.start.is_none_or(|start| start <= *value) && self.end.is_none_or(|end| *value <= end)
+    }
+}
+
+#[inline]
+fn invalid(text: &str) -> DCKVError {
+    DCKVError::InvalidDateTime(text.to_string())
+}
+
+/// Number of the ASCII digits of `text`.
+#[inline]
+fn number(text: &str) -> Option<u32> {
+    match text.bytes().all(|byte| byte.is_ascii_digit()) && !text.is_empty() {
+        true => text.parse().ok(),
+        false => None,
+    }
+}
+
+/// Last day of a month.
+#[inline]
+fn last_day(year: i32, month: u32) -> Option<u32> {
+    let next = match month {
+        12 => NaiveDate::from_ymd_opt(year + 1, 1, 1)?,
+        _ => NaiveDate::from_ymd_opt(year, month + 1, 1)?,
+    };
+
+    next.pred_opt().map(|date| date.day())
+}
+
+/// DA value, `YYYYMMDD` or the legacy `YYYY.MM.DD`.
+pub(crate) fn parse_date(text: &str) -> Result<NaiveDate> {
+    if !text.is_ascii() {
+        return Err(invalid(text));
+    }
+
+    let compact = match text.as_bytes() {
+        [_, _, _, _, b'.', _, _, b'.', _, _] => text.replace('.', ""),
+        _ => text.to_string(),
+    };
+
+    if compact.len() != 8 {
+        return Err(invalid(text));
+    }
+
+    let year = number(&compact[..4]).ok_or_else(|| invalid(text))?;
+    let month = number(&compact[4..6]).ok_or_else(|| invalid(text))?;
+    let day = number(&compact[6..]).ok_or_else(|| invalid(text))?;
+
+    NaiveDate::from_ymd_opt(year as i32, month, day).ok_or_else(|| invalid(text))
+}
+
+/// TM value, `HH[MM[SS[.F{1,6}]]]` or the legacy `HH:MM:SS.F`. Missing
+/// components, and the missing digits of the fraction, are the earliest,
+/// or the `latest` ones: `10:30:15.5` ends at 10:30:15.599999.
+pub(crate) fn parse_time(text: &str, latest: bool) -> Result<NaiveTime> {
+    if !text.is_ascii() {
+        return Err(invalid(text));
+    }
+
+    let compact = text.replace(':', "");
+    let (main, fraction) = match compact.split_once('.') {
+        Some((main, fraction)) => (main, Some(fraction)),
+        None => (compact.as_str(), None),
+    };
+
+    if !matches!(main.len(), 2 | 4 | 6) || (fraction.is_some() && main.len() != 6) {
+        return Err(invalid(text));
+    }
+
+    let component = |range: std::ops::Range<usize>, missing: u32| match main.get(range) {
+        Some(digits) => number(digits).ok_or_else(|| invalid(text)),
+        None => Ok(if latest { missing } else { 0 }),
+    };
+
+    let hour = component(0..2, 0)?;
+    let minute = component(2..4, 59)?;
+    let second = component(4..6, 59)?;
+    let micro = match fraction {
+        Some(digits) if (1..=6).contains(&digits.len()) => {
+            let scale = 10u32.pow(6 - digits.len() as u32);
+            let micro = number(digits).ok_or_else(|| invalid(text))? * scale;
+            match latest {
+                true => micro + scale - 1,
+                false => micro,
+            }
+        }
+        Some(_) => return Err(invalid(text)),
+        None if latest => 999_999,
+        None => 0,
+    };
+
+    // Leap seconds are the 60th second of a minute.
+    let time = match second {
+        60 => NaiveTime::from_hms_micro_opt(hour, minute, 59, 1_000_000 + micro),
+        _ => NaiveTime::from_hms_micro_opt(hour, minute, second, micro),
+    };
+
+    time.ok_or_else(|| invalid(text))
+}
+
+/// Timezone offset, `&ZZXX`, as in (0008,0201) Timezone Offset From UTC.
+pub(crate) fn parse_offset(text: &str) -> Result<FixedOffset> {
+    let sign = match text.as_bytes().first() {
+        Some(b'+') => 1,
+        Some(b'-') => -1,
+        _ => return Err(invalid(text)),
+    };
+
+    if text.len() != 5 || !text.is_ascii() {
+        return Err(invalid(text));
+    }
+
+    let hours = number(&text[1..3]).ok_or_else(|| invalid(text))? as i32;
+    let minutes = number(&text[3..]).ok_or_else(|| invalid(text))? as i32;
+
+    // Offsets range from -12:00 to +14:00.
+    let limit = if sign < 0 { 12 * 60 } else { 14 * 60 };
+    if minutes > 59 || hours * 60 + minutes > limit {
+        return Err(invalid(text));
+    }
+
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60)).ok_or_else(|| invalid(text))
+}
+
+/// DT value, `YYYY[MM[DD[HH[MM[SS[.F{1,6}]]]]]][&ZZXX]`. Without its own
+/// offset, the value is in `offset`, or in UTC. Missing components are the
+/// earliest, or the `latest` ones.
+pub(crate) fn parse_datetime(
+    text: &str,
+    offset: Option<FixedOffset>,
+    latest: bool,
+) -> Result<DateTime<FixedOffset>> {
+    if !text.is_ascii() {
+        return Err(invalid(text));
+    }
+
+    let (body, own_offset) = match text.len().checked_sub(5) {
+        Some(at) if matches!(text.as_bytes()[at], b'+' | b'-') => {
+            (&text[..at], Some(parse_offset(&text[at..])?))
+        }
+        _ => (text, None),
+    };
+
+    let date_length = body.find('.').unwrap_or(body.len()).min(8);
+    if !matches!(date_length, 4 | 6 | 8) {
+        return Err(invalid(text));
+    }
+
+    let year = number(&body[..4]).ok_or_else(|| invalid(text))? as i32;
+    let month = match body.get(4..6).filter(|_| date_length >= 6) {
+        Some(digits) => number(digits).ok_or_else(|| invalid(text))?,
+        None if latest => 12,
+        None => 1,
+    };
+    let day = match body.get(6..8).filter(|_| date_length == 8) {
+        Some(digits) => number(digits).ok_or_else(|| invalid(text))?,
+        None if latest => last_day(year, month).ok_or_else(|| invalid(text))?,
+        None => 1,
+    };
+
+    let date = NaiveDate::from_ymd_opt(year, month, day).ok_or_else(|| invalid(text))?;
+    let time = match &body[date_length..] {
+        "" if latest => NaiveTime::from_hms_micro_opt(23, 59, 59, 999_999),
+        "" => Some(NaiveTime::MIN),
+        time => Some(parse_time(time, latest).map_err(|_| invalid(text))?),
+    }
+    .ok_or_else(|| invalid(text))?;
+
+    let offset = own_offset.or(offset).unwrap_or(Utc.fix());
+
+    offset
+        .from_local_datetime(&NaiveDateTime::new(date, time))
+        .single()
+        .ok_or_else(|| invalid(text))
+}
+
+/// Range of a query, `<start>-<end>` with an optional bound, or a single
+/// value standing for its whole period. `parse` gets whether the value ends
+/// the range.
+pub(crate) fn parse_range<T, F>(text: &str, parse: F) -> Result<DateTimeRange<T>>
+where
+    F: Fn(&str, bool) -> Result<T>,
+{
+    // A single DT value may hold a negative offset, so it is tried first.
+    if let (Ok(start), Ok(end)) = (parse(text, false), parse(text, true)) {
+        return Ok(DateTimeRange {
+            start: Some(start),
+            end: Some(end),
+        });
+    }
+
+    let bound = |text: &str, latest: bool| match text {
+        "" => Ok(None),
+        text => parse(text, latest).map(Some),
+    };
+
+    text.match_indices('-')
+        .find_map(
+            |(at, _)| match (bound(&text[..at], false), bound(&text[at + 1..], true)) {
+                (Ok(None), Ok(None)) => None,
+                (Ok(start), Ok(end)) => Some(DateTimeRange { start, end }),
+                _ => None,
+            },
+        )
+        .ok_or_else(|| invalid(text))
+}
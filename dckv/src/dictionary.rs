@@ -0,0 +1,280 @@
+//! DICOM data dictionary (PS3.6): keyword, name, VR, VM and retirement of
+//! the standard elements, and of the private ones given to the parser in a
+//! [`PrivateDictionary`]. It resolves the VR of elements encoded with an
+//! implicit VR transfer syntax.
+//!
+//! The registry is generated by `tools/dictionary.py` from the DocBook
+//! source of PS3.6. The one shipped holds the file meta, directory and
+//! about 1800 data elements of the composite IODs, including the enhanced
+//! multi-frame functional groups; run the script on `part06.xml` for the
+//! whole standard. Elements missing from it read as UN in implicit VR,
+//! sequences among them being recognized by their first item.
+
+use crate::private::is_creator;
+use crate::{vr, Key, KeyRef};
+
+mod registry;
+
+use registry::{ELEMENTS, KEYWORDS, REPEATING};
+
+/// Data element of a dictionary.
+///
+/// ```
+/// use dckv::Dictionary;
+///
+/// let entry = Dictionary::get(0x7FE00010).unwrap();
+/// assert_eq!(entry.keyword(), "PixelData");
+/// assert_eq!(entry.vrs().collect::<Vec<_>>(), ["OB", "OW"]);
+///
+/// // Overlay Data of the second overlay, (6002,3000).
+/// let entry = Dictionary::get(0x60023000).unwrap();
+/// assert_eq!(entry.name(), "Overlay Data");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DictionaryEntry {
+    tag: u32,
+    mask: u32,
+    keyword: &'static str,
+    name: &'static str,
+    vr: &'static str,
+    vm: &'static str,
+    retired: bool,
+}
+
+impl DictionaryEntry {
+    /// Entry of `tag`, `vr` listing the VRs the element may have, e.g.
+    /// `US or SS`. The high byte of the element of private entries is
+    /// ignored, e.g. `0x00291010` stands for (0029,xx10).
+    #[inline]
+    pub const fn new(
+        tag: u32,
+        keyword: &'static str,
+        name: &'static str,
+        vr: &'static str,
+        vm: &'static str,
+    ) -> Self {
+        Self {
+            tag,
+            mask: u32::MAX,
+            keyword,
+            name,
+            vr,
+            vm,
+            retired: false,
+        }
+    }
+
+    /// Entry of a repeating group, matching the tags equal to `tag` in the
+    /// bits of `mask`.
+    #[inline]
+    pub const fn repeating(self, mask: u32) -> Self {
+        Self { mask, ..self }
+    }
+
+    #[inline]
+    pub const fn retired(self) -> Self {
+        Self {
+            retired: true,
+            ..self
+        }
+    }
+
+    /// Tag, with the `x` digits of repeating groups being 0.
+    #[inline]
+    pub fn tag(&self) -> u32 {
+        self.tag
+    }
+
+    #[inline]
+    pub fn keyword(&self) -> &'static str {
+        self.keyword
+    }
+
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// VRs of the element, as listed by PS3.6, e.g. `US or SS`.
+    #[inline]
+    pub fn vr(&self) -> &'static str {
+        self.vr
+    }
+
+    #[inline]
+    pub fn vrs(&self) -> impl Iterator<Item = &'static str> {
+        self.vr.split(" or ")
+    }
+
+    /// Value multiplicity, e.g. `1`, `2-n` or `3-3n`.
+    #[inline]
+    pub fn vm(&self) -> &'static str {
+        self.vm
+    }
+
+    #[inline]
+    pub fn is_retired(&self) -> bool {
+        self.retired
+    }
+
+    /// VR of the element in an implicit VR transfer syntax: OW when it may
+    /// be OB or OW (PS3.5 A.1), the first one otherwise.
+    pub(crate) fn implicit_vr(&self) -> u16 {
+        let vr = match self.vrs().any(|vr| vr == "OW") {
+            true => "OW",
+            false => self.vrs().next().unwrap_or("UN"),
+        };
+
+        match vr.as_bytes() {
+            &[first, second] => u16::from_be_bytes([first, second]),
+            _ => vr::UN,
+        }
+    }
+
+    /// Whether the entry stands for `tag`. Repeating groups only hold even
+    /// groups, e.g. (6000-601E,3000), odd ones being private (PS3.5 7.6).
+    #[inline]
+    fn matches(&self, tag: u32) -> bool {
+        let repeats_group = self.mask & 0x00FF0000 == 0;
+        tag & self.mask == self.tag && !(repeats_group && tag & 0x00010000 != 0)
+    }
+}
+
+/// Lookups in the standard dictionary.
+pub struct Dictionary;
+
+impl Dictionary {
+    /// Standard element of `tag`, including repeating groups.
+    pub fn get(tag: u32) -> Option<&'static DictionaryEntry> {
+        match ELEMENTS.binary_search_by_key(&tag, |entry| entry.tag) {
+            Ok(index) => Some(&ELEMENTS[index]),
+            Err(_) => REPEATING.iter().find(|entry| entry.matches(tag)),
+        }
+    }
+
+    /// Standard element of `keyword`, e.g. `PatientName`.
+    pub fn by_keyword(keyword: &str) -> Option<&'static DictionaryEntry> {
+        let index = KEYWORDS
+            .binary_search_by_key(&keyword, |&(keyword, _)| keyword)
+            .ok()?;
+
+        Self::get(KEYWORDS[index].1)
+    }
+
+    /// Standard elements sorted by tag, then the ones of repeating groups.
+    pub fn entries() -> impl Iterator<Item = &'static DictionaryEntry> {
+        ELEMENTS.iter().chain(REPEATING)
+    }
+}
+
+/// Private elements by creator, the value of the (gggg,0010-00FF) private
+/// creator element reserving their block, e.g. `SIEMENS CSA HEADER`. Given
+/// to the parser by a deserializer, it resolves the VR of private elements
+/// in implicit VR.
+///
+/// ```
+/// use dckv::{DictionaryEntry, PrivateDictionary};
+///
+/// static CSA: &[DictionaryEntry] = &[
+///     DictionaryEntry::new(0x00291010, "CSAImageHeaderInfo", "CSA Image Header Info", "OB", "1"),
+/// ];
+/// let dictionary = PrivateDictionary::new().creator("SIEMENS CSA HEADER", CSA);
+///
+/// // Element 0x10 of the block 0x11.
+/// let entry = dictionary.get("SIEMENS CSA HEADER", 0x00291110).unwrap();
+/// assert_eq!(entry.keyword(), "CSAImageHeaderInfo");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrivateDictionary {
+    creators: Vec<(&'static str, &'static [DictionaryEntry])>,
+}
+
+impl PrivateDictionary {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the private elements of `creator`, keyed by their tag in the
+    /// block 0x10. Entries added earlier for the same creator are looked up
+    /// first.
+    #[inline]
+    pub fn creator(mut self, creator: &'static str, entries: &'static [DictionaryEntry]) -> Self {
+        self.creators.push((creator, entries));
+        self
+    }
+
+    /// Private element of `tag`, in a block reserved by `creator`.
+    pub fn get(&self, creator: &str, tag: u32) -> Option<&'static DictionaryEntry> {
+        self.creators
+            .iter()
+            .filter(|(registered, _)| *registered == creator)
+            .flat_map(|(_, entries)| entries.iter())
+            .find(|entry| (entry.tag ^ tag) & 0xFFFF00FF == 0)
+    }
+}
+
+impl KeyRef<'_> {
+    /// Keyword of the element of the key, at its level, if it is standard.
+    #[inline]
+    pub fn keyword(&self) -> Option<&'static str> {
+        let tag = ((self.group() as u32) << 16) + self.element() as u32;
+
+        Dictionary::get(tag).map(DictionaryEntry::keyword)
+    }
+}
+
+impl Key {
+    #[inline]
+    pub fn keyword(&self) -> Option<&'static str> {
+        self.as_key_ref().keyword()
+    }
+
+    /// Top-level key of the standard element of `keyword`, with the VR it
+    /// has in an implicit VR transfer syntax, e.g. OW for Pixel Data.
+    /// Sequences have the key of their start.
+    ///
+    /// ```
+    /// use dckv::Key;
+    ///
+    /// let key = Key::from_keyword("PatientName").unwrap();
+    /// assert_eq!((key.group(), key.element()), (0x0010, 0x0010));
+    /// assert_eq!(key.vr().unwrap(), "PN");
+    /// assert_eq!(key.keyword(), Some("PatientName"));
+    /// ```
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        let entry = Dictionary::by_keyword(keyword)?;
+        let block = match entry.implicit_vr() {
+            vr::SQ => crate::block::sequence(entry.tag),
+            vr => crate::block::element(entry.tag, vr),
+        };
+
+        Some(Self {
+            bytes: block.to_be_bytes().to_vec(),
+        })
+    }
+}
+
+/// Returns the VR registered for `tag`, in `private` under its `creator`
+/// for private data elements, falling back to the rules that apply to whole
+/// classes of elements (group lengths, private creators). Unknown elements
+/// are reported as `UN`.
+pub(crate) fn vr(tag: u32, creator: Option<&str>, private: &PrivateDictionary) -> u16 {
+    if let Some(entry) = Dictionary::get(tag) {
+        return entry.implicit_vr();
+    }
+
+    if let Some(entry) = creator.and_then(|creator| private.get(creator, tag)) {
+        return entry.implicit_vr();
+    }
+
+    if tag & 0xFFFF == 0x0000 {
+        // Group length.
+        vr::UL
+    } else if is_creator(tag) {
+        // Private creator.
+        vr::LO
+    } else {
+        vr::UN
+    }
+}
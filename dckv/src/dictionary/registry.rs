@@ -0,0 +1,3811 @@
+//! Registry of DICOM data elements (PS3.6 6, 7 and 8), generated by
+//! `tools/dictionary.py`.
+
+use super::DictionaryEntry as E;
+
+/// Elements sorted by tag.
+#[rustfmt::skip]
+pub(super) static ELEMENTS: &[E] = &[
+    E::new(0x00020000, "FileMetaInformationGroupLength", "File Meta Information Group Length", "UL", "1"),
+    E::new(0x00020001, "FileMetaInformationVersion", "File Meta Information Version", "OB", "1"),
+    E::new(0x00020002, "MediaStorageSOPClassUID", "Media Storage SOP Class UID", "UI", "1"),
+    E::new(0x00020003, "MediaStorageSOPInstanceUID", "Media Storage SOP Instance UID", "UI", "1"),
+    E::new(0x00020010, "TransferSyntaxUID", "Transfer Syntax UID", "UI", "1"),
+    E::new(0x00020012, "ImplementationClassUID", "Implementation Class UID", "UI", "1"),
+    E::new(0x00020013, "ImplementationVersionName", "Implementation Version Name", "SH", "1"),
+    E::new(0x00020016, "SourceApplicationEntityTitle", "Source Application Entity Title", "AE", "1"),
+    E::new(0x00020017, "SendingApplicationEntityTitle", "Sending Application Entity Title", "AE", "1"),
+    E::new(0x00020018, "ReceivingApplicationEntityTitle", "Receiving Application Entity Title", "AE", "1"),
+    E::new(0x00020026, "SourcePresentationAddress", "Source Presentation Address", "UR", "1"),
+    E::new(0x00020027, "SendingPresentationAddress", "Sending Presentation Address", "UR", "1"),
+    E::new(0x00020028, "ReceivingPresentationAddress", "Receiving Presentation Address", "UR", "1"),
+    E::new(0x00020100, "PrivateInformationCreatorUID", "Private Information Creator UID", "UI", "1"),
+    E::new(0x00020102, "PrivateInformation", "Private Information", "OB", "1"),
+    E::new(0x00041130, "FileSetID", "File-set ID", "CS", "1"),
+    E::new(0x00041141, "FileSetDescriptorFileID", "File-set Descriptor File ID", "CS", "1-8"),
+    E::new(0x00041142, "SpecificCharacterSetOfFileSetDescriptorFile", "Specific Character Set of File-set Descriptor File", "CS", "1"),
+    E::new(0x00041200, "OffsetOfTheFirstDirectoryRecordOfTheRootDirectoryEntity", "Offset of the First Directory Record of the Root Directory Entity", "UL", "1"),
+    E::new(0x00041202, "OffsetOfTheLastDirectoryRecordOfTheRootDirectoryEntity", "Offset of the Last Directory Record of the Root Directory Entity", "UL", "1"),
+    E::new(0x00041212, "FileSetConsistencyFlag", "File-set Consistency Flag", "US", "1"),
+    E::new(0x00041220, "DirectoryRecordSequence", "Directory Record Sequence", "SQ", "1"),
+    E::new(0x00041400, "OffsetOfTheNextDirectoryRecord", "Offset of the Next Directory Record", "UL", "1"),
+    E::new(0x00041410, "RecordInUseFlag", "Record in Use Flag", "US", "1"),
+    E::new(0x00041420, "OffsetOfReferencedLowerLevelDirectoryEntity", "Offset of Referenced Lower-Level Directory Entity", "UL", "1"),
+    E::new(0x00041430, "DirectoryRecordType", "Directory Record Type", "CS", "1"),
+    E::new(0x00041432, "PrivateRecordUID", "Private Record UID", "UI", "1"),
+    E::new(0x00041500, "ReferencedFileID", "Referenced File ID", "CS", "1-8"),
+    E::new(0x00041504, "MRDRDirectoryRecordOffset", "MRDR Directory Record Offset", "UL", "1").retired(),
+    E::new(0x00041510, "ReferencedSOPClassUIDInFile", "Referenced SOP Class UID in File", "UI", "1"),
+    E::new(0x00041511, "ReferencedSOPInstanceUIDInFile", "Referenced SOP Instance UID in File", "UI", "1"),
+    E::new(0x00041512, "ReferencedTransferSyntaxUIDInFile", "Referenced Transfer Syntax UID in File", "UI", "1"),
+    E::new(0x0004151A, "ReferencedRelatedGeneralSOPClassUIDInFile", "Referenced Related General SOP Class UID in File", "UI", "1-n"),
+    E::new(0x00041600, "NumberOfReferences", "Number of References", "UL", "1").retired(),
+    E::new(0x00080001, "LengthToEnd", "Length to End", "UL", "1").retired(),
+    E::new(0x00080005, "SpecificCharacterSet", "Specific Character Set", "CS", "1-n"),
+    E::new(0x00080006, "LanguageCodeSequence", "Language Code Sequence", "SQ", "1"),
+    E::new(0x00080008, "ImageType", "Image Type", "CS", "2-n"),
+    E::new(0x00080010, "RecognitionCode", "Recognition Code", "SH", "1").retired(),
+    E::new(0x00080012, "InstanceCreationDate", "Instance Creation Date", "DA", "1"),
+    E::new(0x00080013, "InstanceCreationTime", "Instance Creation Time", "TM", "1"),
+    E::new(0x00080014, "InstanceCreatorUID", "Instance Creator UID", "UI", "1"),
+    E::new(0x00080015, "InstanceCoercionDateTime", "Instance Coercion Date Time", "DT", "1"),
+    E::new(0x00080016, "SOPClassUID", "SOP Class UID", "UI", "1"),
+    E::new(0x00080018, "SOPInstanceUID", "SOP Instance UID", "UI", "1"),
+    E::new(0x0008001A, "RelatedGeneralSOPClassUID", "Related General SOP Class UID", "UI", "1-n"),
+    E::new(0x0008001B, "OriginalSpecializedSOPClassUID", "Original Specialized SOP Class UID", "UI", "1"),
+    E::new(0x00080020, "StudyDate", "Study Date", "DA", "1"),
+    E::new(0x00080021, "SeriesDate", "Series Date", "DA", "1"),
+    E::new(0x00080022, "AcquisitionDate", "Acquisition Date", "DA", "1"),
+    E::new(0x00080023, "ContentDate", "Content Date", "DA", "1"),
+    E::new(0x00080024, "OverlayDate", "Overlay Date", "DA", "1").retired(),
+    E::new(0x00080025, "CurveDate", "Curve Date", "DA", "1").retired(),
+    E::new(0x0008002A, "AcquisitionDateTime", "Acquisition DateTime", "DT", "1"),
+    E::new(0x00080030, "StudyTime", "Study Time", "TM", "1"),
+    E::new(0x00080031, "SeriesTime", "Series Time", "TM", "1"),
+    E::new(0x00080032, "AcquisitionTime", "Acquisition Time", "TM", "1"),
+    E::new(0x00080033, "ContentTime", "Content Time", "TM", "1"),
+    E::new(0x00080034, "OverlayTime", "Overlay Time", "TM", "1").retired(),
+    E::new(0x00080035, "CurveTime", "Curve Time", "TM", "1").retired(),
+    E::new(0x00080040, "DataSetType", "Data Set Type", "US", "1").retired(),
+    E::new(0x00080041, "DataSetSubtype", "Data Set Subtype", "LO", "1").retired(),
+    E::new(0x00080042, "NuclearMedicineSeriesType", "Nuclear Medicine Series Type", "CS", "1").retired(),
+    E::new(0x00080050, "AccessionNumber", "Accession Number", "SH", "1"),
+    E::new(0x00080051, "IssuerOfAccessionNumberSequence", "Issuer of Accession Number Sequence", "SQ", "1"),
+    E::new(0x00080052, "QueryRetrieveLevel", "Query/Retrieve Level", "CS", "1"),
+    E::new(0x00080053, "QueryRetrieveView", "Query/Retrieve View", "CS", "1"),
+    E::new(0x00080054, "RetrieveAETitle", "Retrieve AE Title", "AE", "1-n"),
+    E::new(0x00080055, "StationAETitle", "Station AE Title", "AE", "1-n"),
+    E::new(0x00080056, "InstanceAvailability", "Instance Availability", "CS", "1"),
+    E::new(0x00080058, "FailedSOPInstanceUIDList", "Failed SOP Instance UID List", "UI", "1-n"),
+    E::new(0x00080060, "Modality", "Modality", "CS", "1"),
+    E::new(0x00080061, "ModalitiesInStudy", "Modalities in Study", "CS", "1-n"),
+    E::new(0x00080062, "SOPClassesInStudy", "SOP Classes in Study", "UI", "1-n"),
+    E::new(0x00080063, "AnatomicRegionsInStudyCodeSequence", "Anatomic Regions in Study Code Sequence", "SQ", "1"),
+    E::new(0x00080064, "ConversionType", "Conversion Type", "CS", "1"),
+    E::new(0x00080068, "PresentationIntentType", "Presentation Intent Type", "CS", "1"),
+    E::new(0x00080070, "Manufacturer", "Manufacturer", "LO", "1"),
+    E::new(0x00080080, "InstitutionName", "Institution Name", "LO", "1"),
+    E::new(0x00080081, "InstitutionAddress", "Institution Address", "ST", "1"),
+    E::new(0x00080082, "InstitutionCodeSequence", "Institution Code Sequence", "SQ", "1"),
+    E::new(0x00080090, "ReferringPhysicianName", "Referring Physician's Name", "PN", "1"),
+    E::new(0x00080092, "ReferringPhysicianAddress", "Referring Physician's Address", "ST", "1"),
+    E::new(0x00080094, "ReferringPhysicianTelephoneNumbers", "Referring Physician's Telephone Numbers", "SH", "1-n"),
+    E::new(0x00080096, "ReferringPhysicianIdentificationSequence", "Referring Physician Identification Sequence", "SQ", "1"),
+    E::new(0x00080100, "CodeValue", "Code Value", "SH", "1"),
+    E::new(0x00080102, "CodingSchemeDesignator", "Coding Scheme Designator", "SH", "1"),
+    E::new(0x00080103, "CodingSchemeVersion", "Coding Scheme Version", "SH", "1"),
+    E::new(0x00080104, "CodeMeaning", "Code Meaning", "LO", "1"),
+    E::new(0x00080105, "MappingResource", "Mapping Resource", "CS", "1"),
+    E::new(0x00080106, "ContextGroupVersion", "Context Group Version", "DT", "1"),
+    E::new(0x00080107, "ContextGroupLocalVersion", "Context Group Local Version", "DT", "1"),
+    E::new(0x0008010B, "ContextGroupExtensionFlag", "Context Group Extension Flag", "CS", "1"),
+    E::new(0x0008010C, "CodingSchemeUID", "Coding Scheme UID", "UI", "1"),
+    E::new(0x0008010D, "ContextGroupExtensionCreatorUID", "Context Group Extension Creator UID", "UI", "1"),
+    E::new(0x0008010F, "ContextIdentifier", "Context Identifier", "CS", "1"),
+    E::new(0x00080110, "CodingSchemeIdentificationSequence", "Coding Scheme Identification Sequence", "SQ", "1"),
+    E::new(0x00080112, "CodingSchemeRegistry", "Coding Scheme Registry", "LO", "1"),
+    E::new(0x00080114, "CodingSchemeExternalID", "Coding Scheme External ID", "ST", "1"),
+    E::new(0x00080115, "CodingSchemeName", "Coding Scheme Name", "ST", "1"),
+    E::new(0x00080116, "CodingSchemeResponsibleOrganization", "Coding Scheme Responsible Organization", "ST", "1"),
+    E::new(0x00080117, "ContextUID", "Context UID", "UI", "1"),
+    E::new(0x00080118, "MappingResourceUID", "Mapping Resource UID", "UI", "1"),
+    E::new(0x00080119, "LongCodeValue", "Long Code Value", "UC", "1"),
+    E::new(0x00080120, "URNCodeValue", "URN Code Value", "UR", "1"),
+    E::new(0x00080121, "EquivalentCodeSequence", "Equivalent Code Sequence", "SQ", "1"),
+    E::new(0x00080122, "MappingResourceName", "Mapping Resource Name", "LO", "1"),
+    E::new(0x00080123, "ContextGroupIdentificationSequence", "Context Group Identification Sequence", "SQ", "1"),
+    E::new(0x00080124, "MappingResourceIdentificationSequence", "Mapping Resource Identification Sequence", "SQ", "1"),
+    E::new(0x00080201, "TimezoneOffsetFromUTC", "Timezone Offset From UTC", "SH", "1"),
+    E::new(0x00080300, "PrivateDataElementCharacteristicsSequence", "Private Data Element Characteristics Sequence", "SQ", "1"),
+    E::new(0x00080301, "PrivateGroupReference", "Private Group Reference", "US", "1"),
+    E::new(0x00080302, "PrivateCreatorReference", "Private Creator Reference", "LO", "1"),
+    E::new(0x00080303, "BlockIdentifyingInformationStatus", "Block Identifying Information Status", "CS", "1"),
+    E::new(0x00080304, "NonidentifyingPrivateElements", "Nonidentifying Private Elements", "US", "1-n"),
+    E::new(0x00080305, "DeidentificationActionSequence", "Deidentification Action Sequence", "SQ", "1"),
+    E::new(0x00080306, "IdentifyingPrivateElements", "Identifying Private Elements", "US", "1-n"),
+    E::new(0x00080307, "DeidentificationAction", "Deidentification Action", "CS", "1"),
+    E::new(0x00081000, "NetworkID", "Network ID", "AE", "1").retired(),
+    E::new(0x00081010, "StationName", "Station Name", "SH", "1"),
+    E::new(0x00081030, "StudyDescription", "Study Description", "LO", "1"),
+    E::new(0x00081032, "ProcedureCodeSequence", "Procedure Code Sequence", "SQ", "1"),
+    E::new(0x0008103E, "SeriesDescription", "Series Description", "LO", "1"),
+    E::new(0x00081040, "InstitutionalDepartmentName", "Institutional Department Name", "LO", "1"),
+    E::new(0x00081048, "PhysiciansOfRecord", "Physician(s) of Record", "PN", "1-n"),
+    E::new(0x00081049, "PhysiciansOfRecordIdentificationSequence", "Physician(s) of Record Identification Sequence", "SQ", "1"),
+    E::new(0x00081050, "PerformingPhysicianName", "Performing Physician's Name", "PN", "1-n"),
+    E::new(0x00081052, "PerformingPhysicianIdentificationSequence", "Performing Physician Identification Sequence", "SQ", "1"),
+    E::new(0x00081060, "NameOfPhysiciansReadingStudy", "Name of Physician(s) Reading Study", "PN", "1-n"),
+    E::new(0x00081062, "PhysiciansReadingStudyIdentificationSequence", "Physician(s) Reading Study Identification Sequence", "SQ", "1"),
+    E::new(0x00081070, "OperatorsName", "Operators' Name", "PN", "1-n"),
+    E::new(0x00081072, "OperatorIdentificationSequence", "Operator Identification Sequence", "SQ", "1"),
+    E::new(0x00081080, "AdmittingDiagnosesDescription", "Admitting Diagnoses Description", "LO", "1-n"),
+    E::new(0x00081084, "AdmittingDiagnosesCodeSequence", "Admitting Diagnoses Code Sequence", "SQ", "1"),
+    E::new(0x00081090, "ManufacturerModelName", "Manufacturer's Model Name", "LO", "1"),
+    E::new(0x00081100, "ReferencedResultsSequence", "Referenced Results Sequence", "SQ", "1").retired(),
+    E::new(0x00081110, "ReferencedStudySequence", "Referenced Study Sequence", "SQ", "1"),
+    E::new(0x00081111, "ReferencedPerformedProcedureStepSequence", "Referenced Performed Procedure Step Sequence", "SQ", "1"),
+    E::new(0x00081115, "ReferencedSeriesSequence", "Referenced Series Sequence", "SQ", "1"),
+    E::new(0x00081120, "ReferencedPatientSequence", "Referenced Patient Sequence", "SQ", "1"),
+    E::new(0x00081125, "ReferencedVisitSequence", "Referenced Visit Sequence", "SQ", "1"),
+    E::new(0x00081130, "ReferencedOverlaySequence", "Referenced Overlay Sequence", "SQ", "1").retired(),
+    E::new(0x00081134, "ReferencedStereometricInstanceSequence", "Referenced Stereometric Instance Sequence", "SQ", "1"),
+    E::new(0x00081140, "ReferencedImageSequence", "Referenced Image Sequence", "SQ", "1"),
+    E::new(0x00081145, "ReferencedCurveSequence", "Referenced Curve Sequence", "SQ", "1").retired(),
+    E::new(0x0008114A, "ReferencedInstanceSequence", "Referenced Instance Sequence", "SQ", "1"),
+    E::new(0x0008114B, "ReferencedRealWorldValueMappingInstanceSequence", "Referenced Real World Value Mapping Instance Sequence", "SQ", "1"),
+    E::new(0x00081150, "ReferencedSOPClassUID", "Referenced SOP Class UID", "UI", "1"),
+    E::new(0x00081155, "ReferencedSOPInstanceUID", "Referenced SOP Instance UID", "UI", "1"),
+    E::new(0x00081156, "DefinitionSourceSequence", "Definition Source Sequence", "SQ", "1"),
+    E::new(0x0008115A, "SOPClassesSupported", "SOP Classes Supported", "UI", "1-n"),
+    E::new(0x00081160, "ReferencedFrameNumber", "Referenced Frame Number", "IS", "1-n"),
+    E::new(0x00081161, "SimpleFrameList", "Simple Frame List", "UL", "1-n"),
+    E::new(0x00081162, "CalculatedFrameList", "Calculated Frame List", "UL", "3-3n"),
+    E::new(0x00081163, "TimeRange", "Time Range", "FD", "2"),
+    E::new(0x00081164, "FrameExtractionSequence", "Frame Extraction Sequence", "SQ", "1"),
+    E::new(0x00081167, "MultiFrameSourceSOPInstanceUID", "Multi-frame Source SOP Instance UID", "UI", "1"),
+    E::new(0x00081190, "RetrieveURL", "Retrieve URL", "UR", "1"),
+    E::new(0x00081195, "TransactionUID", "Transaction UID", "UI", "1"),
+    E::new(0x00081196, "WarningReason", "Warning Reason", "US", "1"),
+    E::new(0x00081197, "FailureReason", "Failure Reason", "US", "1"),
+    E::new(0x00081198, "FailedSOPSequence", "Failed SOP Sequence", "SQ", "1"),
+    E::new(0x00081199, "ReferencedSOPSequence", "Referenced SOP Sequence", "SQ", "1"),
+    E::new(0x0008119A, "OtherFailuresSequence", "Other Failures Sequence", "SQ", "1"),
+    E::new(0x00081200, "StudiesContainingOtherReferencedInstancesSequence", "Studies Containing Other Referenced Instances Sequence", "SQ", "1"),
+    E::new(0x00081250, "RelatedSeriesSequence", "Related Series Sequence", "SQ", "1"),
+    E::new(0x00082110, "LossyImageCompressionRetired", "Lossy Image Compression (Retired)", "CS", "1").retired(),
+    E::new(0x00082111, "DerivationDescription", "Derivation Description", "ST", "1"),
+    E::new(0x00082112, "SourceImageSequence", "Source Image Sequence", "SQ", "1"),
+    E::new(0x00082120, "StageName", "Stage Name", "SH", "1"),
+    E::new(0x00082122, "StageNumber", "Stage Number", "IS", "1"),
+    E::new(0x00082124, "NumberOfStages", "Number of Stages", "IS", "1"),
+    E::new(0x00082127, "ViewName", "View Name", "SH", "1"),
+    E::new(0x00082128, "ViewNumber", "View Number", "IS", "1"),
+    E::new(0x00082129, "NumberOfEventTimers", "Number of Event Timers", "IS", "1"),
+    E::new(0x0008212A, "NumberOfViewsInStage", "Number of Views in Stage", "IS", "1"),
+    E::new(0x00082130, "EventElapsedTimes", "Event Elapsed Time(s)", "DS", "1-n"),
+    E::new(0x00082132, "EventTimerNames", "Event Timer Name(s)", "LO", "1-n"),
+    E::new(0x00082133, "EventTimerSequence", "Event Timer Sequence", "SQ", "1"),
+    E::new(0x00082134, "EventTimeOffset", "Event Time Offset", "FD", "1"),
+    E::new(0x00082135, "EventCodeSequence", "Event Code Sequence", "SQ", "1"),
+    E::new(0x00082142, "StartTrim", "Start Trim", "IS", "1"),
+    E::new(0x00082143, "StopTrim", "Stop Trim", "IS", "1"),
+    E::new(0x00082144, "RecommendedDisplayFrameRate", "Recommended Display Frame Rate", "IS", "1"),
+    E::new(0x00082200, "TransducerPosition", "Transducer Position", "CS", "1").retired(),
+    E::new(0x00082204, "TransducerOrientation", "Transducer Orientation", "CS", "1").retired(),
+    E::new(0x00082208, "AnatomicStructure", "Anatomic Structure", "CS", "1").retired(),
+    E::new(0x00082218, "AnatomicRegionSequence", "Anatomic Region Sequence", "SQ", "1"),
+    E::new(0x00082220, "AnatomicRegionModifierSequence", "Anatomic Region Modifier Sequence", "SQ", "1"),
+    E::new(0x00082228, "PrimaryAnatomicStructureSequence", "Primary Anatomic Structure Sequence", "SQ", "1"),
+    E::new(0x00082229, "AnatomicStructureSpaceOrRegionSequence", "Anatomic Structure, Space or Region Sequence", "SQ", "1").retired(),
+    E::new(0x00082230, "PrimaryAnatomicStructureModifierSequence", "Primary Anatomic Structure Modifier Sequence", "SQ", "1"),
+    E::new(0x00083001, "AlternateRepresentationSequence", "Alternate Representation Sequence", "SQ", "1"),
+    E::new(0x00083010, "IrradiationEventUID", "Irradiation Event UID", "UI", "1-n"),
+    E::new(0x00084000, "IdentifyingComments", "Identifying Comments", "LT", "1").retired(),
+    E::new(0x00089007, "FrameType", "Frame Type", "CS", "4"),
+    E::new(0x00089092, "ReferencedImageEvidenceSequence", "Referenced Image Evidence Sequence", "SQ", "1"),
+    E::new(0x00089121, "ReferencedRawDataSequence", "Referenced Raw Data Sequence", "SQ", "1"),
+    E::new(0x00089123, "CreatorVersionUID", "Creator-Version UID", "UI", "1"),
+    E::new(0x00089124, "DerivationImageSequence", "Derivation Image Sequence", "SQ", "1"),
+    E::new(0x00089154, "SourceImageEvidenceSequence", "Source Image Evidence Sequence", "SQ", "1"),
+    E::new(0x00089205, "PixelPresentation", "Pixel Presentation", "CS", "1"),
+    E::new(0x00089206, "VolumetricProperties", "Volumetric Properties", "CS", "1"),
+    E::new(0x00089207, "VolumeBasedCalculationTechnique", "Volume Based Calculation Technique", "CS", "1"),
+    E::new(0x00089208, "ComplexImageComponent", "Complex Image Component", "CS", "1"),
+    E::new(0x00089209, "AcquisitionContrast", "Acquisition Contrast", "CS", "1"),
+    E::new(0x00089215, "DerivationCodeSequence", "Derivation Code Sequence", "SQ", "1"),
+    E::new(0x00089237, "ReferencedPresentationStateSequence", "Referenced Presentation State Sequence", "SQ", "1"),
+    E::new(0x00089410, "ReferencedOtherPlaneSequence", "Referenced Other Plane Sequence", "SQ", "1"),
+    E::new(0x00089458, "FrameDisplaySequence", "Frame Display Sequence", "SQ", "1"),
+    E::new(0x00089459, "RecommendedDisplayFrameRateInFloat", "Recommended Display Frame Rate in Float", "FL", "1"),
+    E::new(0x00089460, "SkipFrameRangeFlag", "Skip Frame Range Flag", "CS", "1"),
+    E::new(0x00100010, "PatientName", "Patient's Name", "PN", "1"),
+    E::new(0x00100020, "PatientID", "Patient ID", "LO", "1"),
+    E::new(0x00100021, "IssuerOfPatientID", "Issuer of Patient ID", "LO", "1"),
+    E::new(0x00100022, "TypeOfPatientID", "Type of Patient ID", "CS", "1"),
+    E::new(0x00100024, "IssuerOfPatientIDQualifiersSequence", "Issuer of Patient ID Qualifiers Sequence", "SQ", "1"),
+    E::new(0x00100030, "PatientBirthDate", "Patient's Birth Date", "DA", "1"),
+    E::new(0x00100032, "PatientBirthTime", "Patient's Birth Time", "TM", "1"),
+    E::new(0x00100033, "PatientBirthDateInAlternativeCalendar", "Patient's Birth Date in Alternative Calendar", "LO", "1"),
+    E::new(0x00100034, "PatientDeathDateInAlternativeCalendar", "Patient's Death Date in Alternative Calendar", "LO", "1"),
+    E::new(0x00100035, "PatientAlternativeCalendar", "Patient's Alternative Calendar", "CS", "1"),
+    E::new(0x00100040, "PatientSex", "Patient's Sex", "CS", "1"),
+    E::new(0x00100050, "PatientInsurancePlanCodeSequence", "Patient's Insurance Plan Code Sequence", "SQ", "1"),
+    E::new(0x00100101, "PatientPrimaryLanguageCodeSequence", "Patient's Primary Language Code Sequence", "SQ", "1"),
+    E::new(0x00100102, "PatientPrimaryLanguageModifierCodeSequence", "Patient's Primary Language Modifier Code Sequence", "SQ", "1"),
+    E::new(0x00100200, "QualityControlSubject", "Quality Control Subject", "CS", "1"),
+    E::new(0x00100201, "QualityControlSubjectTypeCodeSequence", "Quality Control Subject Type Code Sequence", "SQ", "1"),
+    E::new(0x00100212, "StrainDescription", "Strain Description", "UC", "1"),
+    E::new(0x00101000, "OtherPatientIDs", "Other Patient IDs", "LO", "1-n").retired(),
+    E::new(0x00101001, "OtherPatientNames", "Other Patient Names", "PN", "1-n"),
+    E::new(0x00101002, "OtherPatientIDsSequence", "Other Patient IDs Sequence", "SQ", "1"),
+    E::new(0x00101005, "PatientBirthName", "Patient's Birth Name", "PN", "1"),
+    E::new(0x00101010, "PatientAge", "Patient's Age", "AS", "1"),
+    E::new(0x00101020, "PatientSize", "Patient's Size", "DS", "1"),
+    E::new(0x00101021, "PatientSizeCodeSequence", "Patient's Size Code Sequence", "SQ", "1"),
+    E::new(0x00101022, "PatientBodyMassIndex", "Patient's Body Mass Index", "DS", "1"),
+    E::new(0x00101023, "MeasuredAPDimension", "Measured AP Dimension", "DS", "1"),
+    E::new(0x00101024, "MeasuredLateralDimension", "Measured Lateral Dimension", "DS", "1"),
+    E::new(0x00101030, "PatientWeight", "Patient's Weight", "DS", "1"),
+    E::new(0x00101040, "PatientAddress", "Patient's Address", "LO", "1"),
+    E::new(0x00101050, "InsurancePlanIdentification", "Insurance Plan Identification", "LO", "1-n").retired(),
+    E::new(0x00101060, "PatientMotherBirthName", "Patient's Mother's Birth Name", "PN", "1"),
+    E::new(0x00101080, "MilitaryRank", "Military Rank", "LO", "1"),
+    E::new(0x00101081, "BranchOfService", "Branch of Service", "LO", "1"),
+    E::new(0x00101090, "MedicalRecordLocator", "Medical Record Locator", "LO", "1").retired(),
+    E::new(0x00101100, "ReferencedPatientPhotoSequence", "Referenced Patient Photo Sequence", "SQ", "1"),
+    E::new(0x00102000, "MedicalAlerts", "Medical Alerts", "LO", "1-n"),
+    E::new(0x00102110, "Allergies", "Allergies", "LO", "1-n"),
+    E::new(0x00102150, "CountryOfResidence", "Country of Residence", "LO", "1"),
+    E::new(0x00102152, "RegionOfResidence", "Region of Residence", "LO", "1"),
+    E::new(0x00102154, "PatientTelephoneNumbers", "Patient's Telephone Numbers", "SH", "1-n"),
+    E::new(0x00102155, "PatientTelecomInformation", "Patient's Telecom Information", "LT", "1"),
+    E::new(0x00102160, "EthnicGroup", "Ethnic Group", "SH", "1"),
+    E::new(0x00102180, "Occupation", "Occupation", "SH", "1"),
+    E::new(0x001021A0, "SmokingStatus", "Smoking Status", "CS", "1"),
+    E::new(0x001021B0, "AdditionalPatientHistory", "Additional Patient History", "LT", "1"),
+    E::new(0x001021C0, "PregnancyStatus", "Pregnancy Status", "US", "1"),
+    E::new(0x001021D0, "LastMenstrualDate", "Last Menstrual Date", "DA", "1"),
+    E::new(0x001021F0, "PatientReligiousPreference", "Patient's Religious Preference", "LO", "1"),
+    E::new(0x00102201, "PatientSpeciesDescription", "Patient Species Description", "LO", "1"),
+    E::new(0x00102202, "PatientSpeciesCodeSequence", "Patient Species Code Sequence", "SQ", "1"),
+    E::new(0x00102203, "PatientSexNeutered", "Patient's Sex Neutered", "CS", "1"),
+    E::new(0x00102210, "AnatomicalOrientationType", "Anatomical Orientation Type", "CS", "1"),
+    E::new(0x00102292, "PatientBreedDescription", "Patient Breed Description", "LO", "1"),
+    E::new(0x00102293, "PatientBreedCodeSequence", "Patient Breed Code Sequence", "SQ", "1"),
+    E::new(0x00102294, "BreedRegistrationSequence", "Breed Registration Sequence", "SQ", "1"),
+    E::new(0x00102295, "BreedRegistrationNumber", "Breed Registration Number", "LO", "1"),
+    E::new(0x00102296, "BreedRegistryCodeSequence", "Breed Registry Code Sequence", "SQ", "1"),
+    E::new(0x00102297, "ResponsiblePerson", "Responsible Person", "PN", "1"),
+    E::new(0x00102298, "ResponsiblePersonRole", "Responsible Person Role", "CS", "1"),
+    E::new(0x00102299, "ResponsibleOrganization", "Responsible Organization", "LO", "1"),
+    E::new(0x00104000, "PatientComments", "Patient Comments", "LT", "1"),
+    E::new(0x00109431, "ExaminedBodyThickness", "Examined Body Thickness", "FL", "1"),
+    E::new(0x00120010, "ClinicalTrialSponsorName", "Clinical Trial Sponsor Name", "LO", "1"),
+    E::new(0x00120020, "ClinicalTrialProtocolID", "Clinical Trial Protocol ID", "LO", "1"),
+    E::new(0x00120021, "ClinicalTrialProtocolName", "Clinical Trial Protocol Name", "LO", "1"),
+    E::new(0x00120030, "ClinicalTrialSiteID", "Clinical Trial Site ID", "LO", "1"),
+    E::new(0x00120031, "ClinicalTrialSiteName", "Clinical Trial Site Name", "LO", "1"),
+    E::new(0x00120040, "ClinicalTrialSubjectID", "Clinical Trial Subject ID", "LO", "1"),
+    E::new(0x00120042, "ClinicalTrialSubjectReadingID", "Clinical Trial Subject Reading ID", "LO", "1"),
+    E::new(0x00120050, "ClinicalTrialTimePointID", "Clinical Trial Time Point ID", "LO", "1"),
+    E::new(0x00120051, "ClinicalTrialTimePointDescription", "Clinical Trial Time Point Description", "ST", "1"),
+    E::new(0x00120060, "ClinicalTrialCoordinatingCenterName", "Clinical Trial Coordinating Center Name", "LO", "1"),
+    E::new(0x00120062, "PatientIdentityRemoved", "Patient Identity Removed", "CS", "1"),
+    E::new(0x00120063, "DeidentificationMethod", "De-identification Method", "LO", "1-n"),
+    E::new(0x00120064, "DeidentificationMethodCodeSequence", "De-identification Method Code Sequence", "SQ", "1"),
+    E::new(0x00120071, "ClinicalTrialSeriesID", "Clinical Trial Series ID", "LO", "1"),
+    E::new(0x00120072, "ClinicalTrialSeriesDescription", "Clinical Trial Series Description", "LO", "1"),
+    E::new(0x00120081, "ClinicalTrialProtocolEthicsCommitteeName", "Clinical Trial Protocol Ethics Committee Name", "LO", "1"),
+    E::new(0x00120082, "ClinicalTrialProtocolEthicsCommitteeApprovalNumber", "Clinical Trial Protocol Ethics Committee Approval Number", "LO", "1"),
+    E::new(0x00120083, "ConsentForClinicalTrialUseSequence", "Consent for Clinical Trial Use Sequence", "SQ", "1"),
+    E::new(0x00120084, "DistributionType", "Distribution Type", "CS", "1"),
+    E::new(0x00120085, "ConsentForDistributionFlag", "Consent for Distribution Flag", "CS", "1"),
+    E::new(0x00180010, "ContrastBolusAgent", "Contrast/Bolus Agent", "LO", "1"),
+    E::new(0x00180012, "ContrastBolusAgentSequence", "Contrast/Bolus Agent Sequence", "SQ", "1"),
+    E::new(0x00180014, "ContrastBolusAdministrationRouteSequence", "Contrast/Bolus Administration Route Sequence", "SQ", "1"),
+    E::new(0x00180015, "BodyPartExamined", "Body Part Examined", "CS", "1"),
+    E::new(0x00180020, "ScanningSequence", "Scanning Sequence", "CS", "1-n"),
+    E::new(0x00180021, "SequenceVariant", "Sequence Variant", "CS", "1-n"),
+    E::new(0x00180022, "ScanOptions", "Scan Options", "CS", "1-n"),
+    E::new(0x00180023, "MRAcquisitionType", "MR Acquisition Type", "CS", "1"),
+    E::new(0x00180024, "SequenceName", "Sequence Name", "SH", "1"),
+    E::new(0x00180026, "InterventionDrugInformationSequence", "Intervention Drug Information Sequence", "SQ", "1"),
+    E::new(0x00180027, "InterventionDrugStopTime", "Intervention Drug Stop Time", "TM", "1"),
+    E::new(0x00180028, "InterventionDrugDose", "Intervention Drug Dose", "DS", "1"),
+    E::new(0x00180029, "InterventionDrugCodeSequence", "Intervention Drug Code Sequence", "SQ", "1"),
+    E::new(0x0018002A, "AdditionalDrugSequence", "Additional Drug Sequence", "SQ", "1"),
+    E::new(0x00180030, "Radionuclide", "Radionuclide", "LO", "1-n").retired(),
+    E::new(0x00180031, "Radiopharmaceutical", "Radiopharmaceutical", "LO", "1"),
+    E::new(0x00180034, "InterventionDrugName", "Intervention Drug Name", "LO", "1"),
+    E::new(0x00180035, "InterventionDrugStartTime", "Intervention Drug Start Time", "TM", "1"),
+    E::new(0x00180040, "CineRate", "Cine Rate", "IS", "1"),
+    E::new(0x00180050, "SliceThickness", "Slice Thickness", "DS", "1"),
+    E::new(0x00180060, "KVP", "KVP", "DS", "1"),
+    E::new(0x00180070, "CountsAccumulated", "Counts Accumulated", "IS", "1"),
+    E::new(0x00180071, "AcquisitionTerminationCondition", "Acquisition Termination Condition", "CS", "1"),
+    E::new(0x00180072, "EffectiveDuration", "Effective Duration", "DS", "1"),
+    E::new(0x00180073, "AcquisitionStartCondition", "Acquisition Start Condition", "CS", "1"),
+    E::new(0x00180074, "AcquisitionStartConditionData", "Acquisition Start Condition Data", "IS", "1"),
+    E::new(0x00180075, "AcquisitionTerminationConditionData", "Acquisition Termination Condition Data", "IS", "1"),
+    E::new(0x00180080, "RepetitionTime", "Repetition Time", "DS", "1"),
+    E::new(0x00180081, "EchoTime", "Echo Time", "DS", "1"),
+    E::new(0x00180082, "InversionTime", "Inversion Time", "DS", "1"),
+    E::new(0x00180083, "NumberOfAverages", "Number of Averages", "DS", "1"),
+    E::new(0x00180084, "ImagingFrequency", "Imaging Frequency", "DS", "1"),
+    E::new(0x00180085, "ImagedNucleus", "Imaged Nucleus", "SH", "1"),
+    E::new(0x00180086, "EchoNumbers", "Echo Number(s)", "IS", "1-n"),
+    E::new(0x00180087, "MagneticFieldStrength", "Magnetic Field Strength", "DS", "1"),
+    E::new(0x00180088, "SpacingBetweenSlices", "Spacing Between Slices", "DS", "1"),
+    E::new(0x00180089, "NumberOfPhaseEncodingSteps", "Number of Phase Encoding Steps", "IS", "1"),
+    E::new(0x00180090, "DataCollectionDiameter", "Data Collection Diameter", "DS", "1"),
+    E::new(0x00180091, "EchoTrainLength", "Echo Train Length", "IS", "1"),
+    E::new(0x00180093, "PercentSampling", "Percent Sampling", "DS", "1"),
+    E::new(0x00180094, "PercentPhaseFieldOfView", "Percent Phase Field of View", "DS", "1"),
+    E::new(0x00180095, "PixelBandwidth", "Pixel Bandwidth", "DS", "1"),
+    E::new(0x00181000, "DeviceSerialNumber", "Device Serial Number", "LO", "1"),
+    E::new(0x00181004, "PlateID", "Plate ID", "LO", "1"),
+    E::new(0x00181005, "GeneratorID", "Generator ID", "LO", "1"),
+    E::new(0x00181007, "CassetteID", "Cassette ID", "LO", "1"),
+    E::new(0x00181008, "GantryID", "Gantry ID", "LO", "1"),
+    E::new(0x00181010, "SecondaryCaptureDeviceID", "Secondary Capture Device ID", "LO", "1"),
+    E::new(0x00181012, "DateOfSecondaryCapture", "Date of Secondary Capture", "DA", "1"),
+    E::new(0x00181014, "TimeOfSecondaryCapture", "Time of Secondary Capture", "TM", "1"),
+    E::new(0x00181016, "SecondaryCaptureDeviceManufacturer", "Secondary Capture Device Manufacturer", "LO", "1"),
+    E::new(0x00181018, "SecondaryCaptureDeviceManufacturerModelName", "Secondary Capture Device Manufacturer's Model Name", "LO", "1"),
+    E::new(0x00181019, "SecondaryCaptureDeviceSoftwareVersions", "Secondary Capture Device Software Versions", "LO", "1-n"),
+    E::new(0x00181020, "SoftwareVersions", "Software Versions", "LO", "1-n"),
+    E::new(0x00181022, "VideoImageFormatAcquired", "Video Image Format Acquired", "SH", "1"),
+    E::new(0x00181023, "DigitalImageFormatAcquired", "Digital Image Format Acquired", "LO", "1"),
+    E::new(0x00181030, "ProtocolName", "Protocol Name", "LO", "1"),
+    E::new(0x00181040, "ContrastBolusRoute", "Contrast/Bolus Route", "LO", "1"),
+    E::new(0x00181041, "ContrastBolusVolume", "Contrast/Bolus Volume", "DS", "1"),
+    E::new(0x00181042, "ContrastBolusStartTime", "Contrast/Bolus Start Time", "TM", "1"),
+    E::new(0x00181043, "ContrastBolusStopTime", "Contrast/Bolus Stop Time", "TM", "1"),
+    E::new(0x00181044, "ContrastBolusTotalDose", "Contrast/Bolus Total Dose", "DS", "1"),
+    E::new(0x00181046, "ContrastFlowRate", "Contrast Flow Rate", "DS", "1-n"),
+    E::new(0x00181047, "ContrastFlowDuration", "Contrast Flow Duration", "DS", "1-n"),
+    E::new(0x00181048, "ContrastBolusIngredient", "Contrast/Bolus Ingredient", "CS", "1"),
+    E::new(0x00181049, "ContrastBolusIngredientConcentration", "Contrast/Bolus Ingredient Concentration", "DS", "1"),
+    E::new(0x00181050, "SpatialResolution", "Spatial Resolution", "DS", "1"),
+    E::new(0x00181060, "TriggerTime", "Trigger Time", "DS", "1"),
+    E::new(0x00181061, "TriggerSourceOrType", "Trigger Source or Type", "LO", "1"),
+    E::new(0x00181062, "NominalInterval", "Nominal Interval", "IS", "1"),
+    E::new(0x00181063, "FrameTime", "Frame Time", "DS", "1"),
+    E::new(0x00181064, "CardiacFramingType", "Cardiac Framing Type", "LO", "1"),
+    E::new(0x00181065, "FrameTimeVector", "Frame Time Vector", "DS", "1-n"),
+    E::new(0x00181066, "FrameDelay", "Frame Delay", "DS", "1"),
+    E::new(0x00181067, "ImageTriggerDelay", "Image Trigger Delay", "DS", "1"),
+    E::new(0x00181068, "MultiplexGroupTimeOffset", "Multiplex Group Time Offset", "DS", "1"),
+    E::new(0x00181069, "TriggerTimeOffset", "Trigger Time Offset", "DS", "1"),
+    E::new(0x0018106A, "SynchronizationTrigger", "Synchronization Trigger", "CS", "1"),
+    E::new(0x0018106C, "SynchronizationChannel", "Synchronization Channel", "US", "2"),
+    E::new(0x0018106E, "TriggerSamplePosition", "Trigger Sample Position", "UL", "1"),
+    E::new(0x00181070, "RadiopharmaceuticalRoute", "Radiopharmaceutical Route", "LO", "1"),
+    E::new(0x00181071, "RadiopharmaceuticalVolume", "Radiopharmaceutical Volume", "DS", "1"),
+    E::new(0x00181072, "RadiopharmaceuticalStartTime", "Radiopharmaceutical Start Time", "TM", "1"),
+    E::new(0x00181073, "RadiopharmaceuticalStopTime", "Radiopharmaceutical Stop Time", "TM", "1"),
+    E::new(0x00181074, "RadionuclideTotalDose", "Radionuclide Total Dose", "DS", "1"),
+    E::new(0x00181075, "RadionuclideHalfLife", "Radionuclide Half Life", "DS", "1"),
+    E::new(0x00181076, "RadionuclidePositronFraction", "Radionuclide Positron Fraction", "DS", "1"),
+    E::new(0x00181077, "RadiopharmaceuticalSpecificActivity", "Radiopharmaceutical Specific Activity", "DS", "1"),
+    E::new(0x00181078, "RadiopharmaceuticalStartDateTime", "Radiopharmaceutical Start Date Time", "DT", "1"),
+    E::new(0x00181079, "RadiopharmaceuticalStopDateTime", "Radiopharmaceutical Stop Date Time", "DT", "1"),
+    E::new(0x00181080, "BeatRejectionFlag", "Beat Rejection Flag", "CS", "1"),
+    E::new(0x00181081, "LowRRValue", "Low R-R Value", "IS", "1"),
+    E::new(0x00181082, "HighRRValue", "High R-R Value", "IS", "1"),
+    E::new(0x00181083, "IntervalsAcquired", "Intervals Acquired", "IS", "1"),
+    E::new(0x00181084, "IntervalsRejected", "Intervals Rejected", "IS", "1"),
+    E::new(0x00181085, "PVCRejection", "PVC Rejection", "LO", "1"),
+    E::new(0x00181086, "SkipBeats", "Skip Beats", "IS", "1"),
+    E::new(0x00181088, "HeartRate", "Heart Rate", "IS", "1"),
+    E::new(0x00181094, "TriggerWindow", "Trigger Window", "IS", "1"),
+    E::new(0x00181100, "ReconstructionDiameter", "Reconstruction Diameter", "DS", "1"),
+    E::new(0x00181110, "DistanceSourceToDetector", "Distance Source to Detector", "DS", "1"),
+    E::new(0x00181111, "DistanceSourceToPatient", "Distance Source to Patient", "DS", "1"),
+    E::new(0x00181120, "GantryDetectorTilt", "Gantry/Detector Tilt", "DS", "1"),
+    E::new(0x00181121, "GantryDetectorSlew", "Gantry Detector Slew", "DS", "1"),
+    E::new(0x00181130, "TableHeight", "Table Height", "DS", "1"),
+    E::new(0x00181131, "TableTraverse", "Table Traverse", "DS", "1"),
+    E::new(0x00181134, "TableMotion", "Table Motion", "CS", "1"),
+    E::new(0x00181135, "TableVerticalIncrement", "Table Vertical Increment", "DS", "1-n"),
+    E::new(0x00181136, "TableLateralIncrement", "Table Lateral Increment", "DS", "1-n"),
+    E::new(0x00181137, "TableLongitudinalIncrement", "Table Longitudinal Increment", "DS", "1-n"),
+    E::new(0x00181138, "TableAngle", "Table Angle", "DS", "1"),
+    E::new(0x0018113A, "TableType", "Table Type", "CS", "1"),
+    E::new(0x00181140, "RotationDirection", "Rotation Direction", "CS", "1"),
+    E::new(0x00181142, "RadialPosition", "Radial Position", "DS", "1-n"),
+    E::new(0x00181143, "ScanArc", "Scan Arc", "DS", "1"),
+    E::new(0x00181144, "AngularStep", "Angular Step", "DS", "1"),
+    E::new(0x00181145, "CenterOfRotationOffset", "Center of Rotation Offset", "DS", "1"),
+    E::new(0x00181147, "FieldOfViewShape", "Field of View Shape", "CS", "1"),
+    E::new(0x00181149, "FieldOfViewDimensions", "Field of View Dimension(s)", "IS", "1-2"),
+    E::new(0x00181150, "ExposureTime", "Exposure Time", "IS", "1"),
+    E::new(0x00181151, "XRayTubeCurrent", "X-Ray Tube Current", "IS", "1"),
+    E::new(0x00181152, "Exposure", "Exposure", "IS", "1"),
+    E::new(0x00181153, "ExposureInuAs", "Exposure in µAs", "IS", "1"),
+    E::new(0x00181154, "AveragePulseWidth", "Average Pulse Width", "DS", "1"),
+    E::new(0x00181155, "RadiationSetting", "Radiation Setting", "CS", "1"),
+    E::new(0x00181156, "RectificationType", "Rectification Type", "CS", "1"),
+    E::new(0x0018115A, "RadiationMode", "Radiation Mode", "CS", "1"),
+    E::new(0x0018115E, "ImageAndFluoroscopyAreaDoseProduct", "Image and Fluoroscopy Area Dose Product", "DS", "1"),
+    E::new(0x00181160, "FilterType", "Filter Type", "SH", "1"),
+    E::new(0x00181161, "TypeOfFilters", "Type of Filters", "LO", "1-n"),
+    E::new(0x00181162, "IntensifierSize", "Intensifier Size", "DS", "1"),
+    E::new(0x00181164, "ImagerPixelSpacing", "Imager Pixel Spacing", "DS", "2"),
+    E::new(0x00181166, "Grid", "Grid", "CS", "1-n"),
+    E::new(0x00181170, "GeneratorPower", "Generator Power", "IS", "1"),
+    E::new(0x00181180, "CollimatorGridName", "Collimator/grid Name", "SH", "1"),
+    E::new(0x00181181, "CollimatorType", "Collimator Type", "CS", "1"),
+    E::new(0x00181182, "FocalDistance", "Focal Distance", "IS", "1-2"),
+    E::new(0x00181183, "XFocusCenter", "X Focus Center", "DS", "1-2"),
+    E::new(0x00181184, "YFocusCenter", "Y Focus Center", "DS", "1-2"),
+    E::new(0x00181190, "FocalSpots", "Focal Spot(s)", "DS", "1-n"),
+    E::new(0x00181191, "AnodeTargetMaterial", "Anode Target Material", "CS", "1"),
+    E::new(0x001811A0, "BodyPartThickness", "Body Part Thickness", "DS", "1"),
+    E::new(0x001811A2, "CompressionForce", "Compression Force", "DS", "1"),
+    E::new(0x00181200, "DateOfLastCalibration", "Date of Last Calibration", "DA", "1-n"),
+    E::new(0x00181201, "TimeOfLastCalibration", "Time of Last Calibration", "TM", "1-n"),
+    E::new(0x00181210, "ConvolutionKernel", "Convolution Kernel", "SH", "1-n"),
+    E::new(0x00181242, "ActualFrameDuration", "Actual Frame Duration", "IS", "1"),
+    E::new(0x00181243, "CountRate", "Count Rate", "IS", "1"),
+    E::new(0x00181250, "ReceiveCoilName", "Receive Coil Name", "SH", "1"),
+    E::new(0x00181251, "TransmitCoilName", "Transmit Coil Name", "SH", "1"),
+    E::new(0x00181260, "PlateType", "Plate Type", "SH", "1"),
+    E::new(0x00181261, "PhosphorType", "Phosphor Type", "LO", "1"),
+    E::new(0x00181300, "ScanVelocity", "Scan Velocity", "DS", "1"),
+    E::new(0x00181301, "WholeBodyTechnique", "Whole Body Technique", "CS", "1-n"),
+    E::new(0x00181302, "ScanLength", "Scan Length", "IS", "1"),
+    E::new(0x00181310, "AcquisitionMatrix", "Acquisition Matrix", "US", "4"),
+    E::new(0x00181312, "InPlanePhaseEncodingDirection", "In-plane Phase Encoding Direction", "CS", "1"),
+    E::new(0x00181314, "FlipAngle", "Flip Angle", "DS", "1"),
+    E::new(0x00181315, "VariableFlipAngleFlag", "Variable Flip Angle Flag", "CS", "1"),
+    E::new(0x00181316, "SAR", "SAR", "DS", "1"),
+    E::new(0x00181318, "dBdt", "dB/dt", "DS", "1"),
+    E::new(0x00181400, "AcquisitionDeviceProcessingDescription", "Acquisition Device Processing Description", "LO", "1"),
+    E::new(0x00181401, "AcquisitionDeviceProcessingCode", "Acquisition Device Processing Code", "LO", "1"),
+    E::new(0x00181402, "CassetteOrientation", "Cassette Orientation", "CS", "1"),
+    E::new(0x00181403, "CassetteSize", "Cassette Size", "CS", "1"),
+    E::new(0x00181404, "ExposuresOnPlate", "Exposures on Plate", "US", "1"),
+    E::new(0x00181405, "RelativeXRayExposure", "Relative X-Ray Exposure", "IS", "1"),
+    E::new(0x00181411, "ExposureIndex", "Exposure Index", "DS", "1"),
+    E::new(0x00181412, "TargetExposureIndex", "Target Exposure Index", "DS", "1"),
+    E::new(0x00181413, "DeviationIndex", "Deviation Index", "DS", "1"),
+    E::new(0x00181450, "ColumnAngulation", "Column Angulation", "DS", "1"),
+    E::new(0x00181460, "TomoLayerHeight", "Tomo Layer Height", "DS", "1"),
+    E::new(0x00181470, "TomoAngle", "Tomo Angle", "DS", "1"),
+    E::new(0x00181480, "TomoTime", "Tomo Time", "DS", "1"),
+    E::new(0x00181490, "TomoType", "Tomo Type", "CS", "1"),
+    E::new(0x00181491, "TomoClass", "Tomo Class", "CS", "1"),
+    E::new(0x00181495, "NumberOfTomosynthesisSourceImages", "Number of Tomosynthesis Source Images", "IS", "1"),
+    E::new(0x00181500, "PositionerMotion", "Positioner Motion", "CS", "1"),
+    E::new(0x00181508, "PositionerType", "Positioner Type", "CS", "1"),
+    E::new(0x00181510, "PositionerPrimaryAngle", "Positioner Primary Angle", "DS", "1"),
+    E::new(0x00181511, "PositionerSecondaryAngle", "Positioner Secondary Angle", "DS", "1"),
+    E::new(0x00181520, "PositionerPrimaryAngleIncrement", "Positioner Primary Angle Increment", "DS", "1-n"),
+    E::new(0x00181521, "PositionerSecondaryAngleIncrement", "Positioner Secondary Angle Increment", "DS", "1-n"),
+    E::new(0x00181530, "DetectorPrimaryAngle", "Detector Primary Angle", "DS", "1"),
+    E::new(0x00181531, "DetectorSecondaryAngle", "Detector Secondary Angle", "DS", "1"),
+    E::new(0x00181600, "ShutterShape", "Shutter Shape", "CS", "1-3"),
+    E::new(0x00181602, "ShutterLeftVerticalEdge", "Shutter Left Vertical Edge", "IS", "1"),
+    E::new(0x00181604, "ShutterRightVerticalEdge", "Shutter Right Vertical Edge", "IS", "1"),
+    E::new(0x00181606, "ShutterUpperHorizontalEdge", "Shutter Upper Horizontal Edge", "IS", "1"),
+    E::new(0x00181608, "ShutterLowerHorizontalEdge", "Shutter Lower Horizontal Edge", "IS", "1"),
+    E::new(0x00181610, "CenterOfCircularShutter", "Center of Circular Shutter", "IS", "2"),
+    E::new(0x00181612, "RadiusOfCircularShutter", "Radius of Circular Shutter", "IS", "1"),
+    E::new(0x00181620, "VerticesOfThePolygonalShutter", "Vertices of the Polygonal Shutter", "IS", "2-2n"),
+    E::new(0x00181622, "ShutterPresentationValue", "Shutter Presentation Value", "US", "1"),
+    E::new(0x00181623, "ShutterOverlayGroup", "Shutter Overlay Group", "US", "1"),
+    E::new(0x00181624, "ShutterPresentationColorCIELabValue", "Shutter Presentation Color CIE Lab Value", "US", "3"),
+    E::new(0x00181700, "CollimatorShape", "Collimator Shape", "CS", "1-3"),
+    E::new(0x00181702, "CollimatorLeftVerticalEdge", "Collimator Left Vertical Edge", "IS", "1"),
+    E::new(0x00181704, "CollimatorRightVerticalEdge", "Collimator Right Vertical Edge", "IS", "1"),
+    E::new(0x00181706, "CollimatorUpperHorizontalEdge", "Collimator Upper Horizontal Edge", "IS", "1"),
+    E::new(0x00181708, "CollimatorLowerHorizontalEdge", "Collimator Lower Horizontal Edge", "IS", "1"),
+    E::new(0x00181710, "CenterOfCircularCollimator", "Center of Circular Collimator", "IS", "2"),
+    E::new(0x00181712, "RadiusOfCircularCollimator", "Radius of Circular Collimator", "IS", "1"),
+    E::new(0x00181720, "VerticesOfThePolygonalCollimator", "Vertices of the Polygonal Collimator", "IS", "2-2n"),
+    E::new(0x00181800, "AcquisitionTimeSynchronized", "Acquisition Time Synchronized", "CS", "1"),
+    E::new(0x00181801, "TimeSource", "Time Source", "SH", "1"),
+    E::new(0x00181802, "TimeDistributionProtocol", "Time Distribution Protocol", "CS", "1"),
+    E::new(0x00181803, "NTPSourceAddress", "NTP Source Address", "LO", "1"),
+    E::new(0x00182001, "PageNumberVector", "Page Number Vector", "IS", "1-n"),
+    E::new(0x00182002, "FrameLabelVector", "Frame Label Vector", "SH", "1-n"),
+    E::new(0x00182003, "FramePrimaryAngleVector", "Frame Primary Angle Vector", "DS", "1-n"),
+    E::new(0x00182004, "FrameSecondaryAngleVector", "Frame Secondary Angle Vector", "DS", "1-n"),
+    E::new(0x00182005, "SliceLocationVector", "Slice Location Vector", "DS", "1-n"),
+    E::new(0x00182006, "DisplayWindowLabelVector", "Display Window Label Vector", "SH", "1-n"),
+    E::new(0x00182010, "NominalScannedPixelSpacing", "Nominal Scanned Pixel Spacing", "DS", "2"),
+    E::new(0x00182020, "DigitizingDeviceTransportDirection", "Digitizing Device Transport Direction", "CS", "1"),
+    E::new(0x00182030, "RotationOfScannedFilm", "Rotation of Scanned Film", "DS", "1"),
+    E::new(0x00183100, "IVUSAcquisition", "IVUS Acquisition", "CS", "1"),
+    E::new(0x00183101, "IVUSPullbackRate", "IVUS Pullback Rate", "DS", "1"),
+    E::new(0x00183102, "IVUSGatedRate", "IVUS Gated Rate", "DS", "1"),
+    E::new(0x00183103, "IVUSPullbackStartFrameNumber", "IVUS Pullback Start Frame Number", "IS", "1"),
+    E::new(0x00183104, "IVUSPullbackStopFrameNumber", "IVUS Pullback Stop Frame Number", "IS", "1"),
+    E::new(0x00183105, "LesionNumber", "Lesion Number", "IS", "1-n"),
+    E::new(0x00185000, "OutputPower", "Output Power", "SH", "1-n"),
+    E::new(0x00185010, "TransducerData", "Transducer Data", "LO", "1-n"),
+    E::new(0x00185012, "FocusDepth", "Focus Depth", "DS", "1"),
+    E::new(0x00185020, "ProcessingFunction", "Processing Function", "LO", "1"),
+    E::new(0x00185021, "PostprocessingFunction", "Postprocessing Function", "LO", "1").retired(),
+    E::new(0x00185022, "MechanicalIndex", "Mechanical Index", "DS", "1"),
+    E::new(0x00185024, "BoneThermalIndex", "Bone Thermal Index", "DS", "1"),
+    E::new(0x00185026, "CranialThermalIndex", "Cranial Thermal Index", "DS", "1"),
+    E::new(0x00185027, "SoftTissueThermalIndex", "Soft Tissue Thermal Index", "DS", "1"),
+    E::new(0x00185028, "SoftTissueFocusThermalIndex", "Soft Tissue-focus Thermal Index", "DS", "1"),
+    E::new(0x00185029, "SoftTissueSurfaceThermalIndex", "Soft Tissue-surface Thermal Index", "DS", "1"),
+    E::new(0x00185050, "DepthOfScanField", "Depth of Scan Field", "IS", "1"),
+    E::new(0x00185100, "PatientPosition", "Patient Position", "CS", "1"),
+    E::new(0x00185101, "ViewPosition", "View Position", "CS", "1"),
+    E::new(0x00185104, "ProjectionEponymousNameCodeSequence", "Projection Eponymous Name Code Sequence", "SQ", "1"),
+    E::new(0x00186000, "Sensitivity", "Sensitivity", "DS", "1"),
+    E::new(0x00186011, "SequenceOfUltrasoundRegions", "Sequence of Ultrasound Regions", "SQ", "1"),
+    E::new(0x00186012, "RegionSpatialFormat", "Region Spatial Format", "US", "1"),
+    E::new(0x00186014, "RegionDataType", "Region Data Type", "US", "1"),
+    E::new(0x00186016, "RegionFlags", "Region Flags", "UL", "1"),
+    E::new(0x00186018, "RegionLocationMinX0", "Region Location Min X0", "UL", "1"),
+    E::new(0x0018601A, "RegionLocationMinY0", "Region Location Min Y0", "UL", "1"),
+    E::new(0x0018601C, "RegionLocationMaxX1", "Region Location Max X1", "UL", "1"),
+    E::new(0x0018601E, "RegionLocationMaxY1", "Region Location Max Y1", "UL", "1"),
+    E::new(0x00186020, "ReferencePixelX0", "Reference Pixel X0", "SL", "1"),
+    E::new(0x00186022, "ReferencePixelY0", "Reference Pixel Y0", "SL", "1"),
+    E::new(0x00186024, "PhysicalUnitsXDirection", "Physical Units X Direction", "US", "1"),
+    E::new(0x00186026, "PhysicalUnitsYDirection", "Physical Units Y Direction", "US", "1"),
+    E::new(0x00186028, "ReferencePixelPhysicalValueX", "Reference Pixel Physical Value X", "FD", "1"),
+    E::new(0x0018602A, "ReferencePixelPhysicalValueY", "Reference Pixel Physical Value Y", "FD", "1"),
+    E::new(0x0018602C, "PhysicalDeltaX", "Physical Delta X", "FD", "1"),
+    E::new(0x0018602E, "PhysicalDeltaY", "Physical Delta Y", "FD", "1"),
+    E::new(0x00186030, "TransducerFrequency", "Transducer Frequency", "UL", "1"),
+    E::new(0x00186031, "TransducerType", "Transducer Type", "CS", "1"),
+    E::new(0x00186032, "PulseRepetitionFrequency", "Pulse Repetition Frequency", "UL", "1"),
+    E::new(0x00186034, "DopplerCorrectionAngle", "Doppler Correction Angle", "FD", "1"),
+    E::new(0x00186036, "SteeringAngle", "Steering Angle", "FD", "1"),
+    E::new(0x00187000, "DetectorConditionsNominalFlag", "Detector Conditions Nominal Flag", "CS", "1"),
+    E::new(0x00187001, "DetectorTemperature", "Detector Temperature", "DS", "1"),
+    E::new(0x00187004, "DetectorType", "Detector Type", "CS", "1"),
+    E::new(0x00187005, "DetectorConfiguration", "Detector Configuration", "CS", "1"),
+    E::new(0x00187006, "DetectorDescription", "Detector Description", "LT", "1"),
+    E::new(0x00187008, "DetectorMode", "Detector Mode", "LT", "1"),
+    E::new(0x0018700A, "DetectorID", "Detector ID", "SH", "1"),
+    E::new(0x0018700C, "DateOfLastDetectorCalibration", "Date of Last Detector Calibration", "DA", "1"),
+    E::new(0x0018700E, "TimeOfLastDetectorCalibration", "Time of Last Detector Calibration", "TM", "1"),
+    E::new(0x00187010, "ExposuresOnDetectorSinceLastCalibration", "Exposures on Detector Since Last Calibration", "IS", "1"),
+    E::new(0x00187011, "ExposuresOnDetectorSinceManufactured", "Exposures on Detector Since Manufactured", "IS", "1"),
+    E::new(0x00187012, "DetectorTimeSinceLastExposure", "Detector Time Since Last Exposure", "DS", "1"),
+    E::new(0x00187014, "DetectorActiveTime", "Detector Active Time", "DS", "1"),
+    E::new(0x00187016, "DetectorActivationOffsetFromExposure", "Detector Activation Offset From Exposure", "DS", "1"),
+    E::new(0x0018701A, "DetectorBinning", "Detector Binning", "DS", "2"),
+    E::new(0x00187020, "DetectorElementPhysicalSize", "Detector Element Physical Size", "DS", "2"),
+    E::new(0x00187022, "DetectorElementSpacing", "Detector Element Spacing", "DS", "2"),
+    E::new(0x00187024, "DetectorActiveShape", "Detector Active Shape", "CS", "1"),
+    E::new(0x00187026, "DetectorActiveDimensions", "Detector Active Dimension(s)", "DS", "1-2"),
+    E::new(0x00187028, "DetectorActiveOrigin", "Detector Active Origin", "DS", "2"),
+    E::new(0x0018702A, "DetectorManufacturerName", "Detector Manufacturer Name", "LO", "1"),
+    E::new(0x0018702B, "DetectorManufacturerModelName", "Detector Manufacturer's Model Name", "LO", "1"),
+    E::new(0x00187030, "FieldOfViewOrigin", "Field of View Origin", "DS", "2"),
+    E::new(0x00187032, "FieldOfViewRotation", "Field of View Rotation", "DS", "1"),
+    E::new(0x00187034, "FieldOfViewHorizontalFlip", "Field of View Horizontal Flip", "CS", "1"),
+    E::new(0x00187036, "PixelDataAreaOriginRelativeToFOV", "Pixel Data Area Origin Relative to FOV", "FL", "2"),
+    E::new(0x00187038, "PixelDataAreaRotationAngleRelativeToFOV", "Pixel Data Area Rotation Angle Relative to FOV", "FL", "1"),
+    E::new(0x00187040, "GridAbsorbingMaterial", "Grid Absorbing Material", "LT", "1"),
+    E::new(0x00187041, "GridSpacingMaterial", "Grid Spacing Material", "LT", "1"),
+    E::new(0x00187042, "GridThickness", "Grid Thickness", "DS", "1"),
+    E::new(0x00187044, "GridPitch", "Grid Pitch", "DS", "1"),
+    E::new(0x00187046, "GridAspectRatio", "Grid Aspect Ratio", "IS", "2"),
+    E::new(0x00187048, "GridPeriod", "Grid Period", "DS", "1"),
+    E::new(0x0018704C, "GridFocalDistance", "Grid Focal Distance", "DS", "1"),
+    E::new(0x00187050, "FilterMaterial", "Filter Material", "CS", "1-n"),
+    E::new(0x00187052, "FilterThicknessMinimum", "Filter Thickness Minimum", "DS", "1-n"),
+    E::new(0x00187054, "FilterThicknessMaximum", "Filter Thickness Maximum", "DS", "1-n"),
+    E::new(0x00187056, "FilterBeamPathLengthMinimum", "Filter Beam Path Length Minimum", "FL", "1-n"),
+    E::new(0x00187058, "FilterBeamPathLengthMaximum", "Filter Beam Path Length Maximum", "FL", "1-n"),
+    E::new(0x00187060, "ExposureControlMode", "Exposure Control Mode", "CS", "1"),
+    E::new(0x00187062, "ExposureControlModeDescription", "Exposure Control Mode Description", "LT", "1"),
+    E::new(0x00187064, "ExposureStatus", "Exposure Status", "CS", "1"),
+    E::new(0x00187065, "PhototimerSetting", "Phototimer Setting", "DS", "1"),
+    E::new(0x00188150, "ExposureTimeInuS", "Exposure Time in µS", "DS", "1"),
+    E::new(0x00188151, "XRayTubeCurrentInuA", "X-Ray Tube Current in µA", "DS", "1"),
+    E::new(0x00189004, "ContentQualification", "Content Qualification", "CS", "1"),
+    E::new(0x00189005, "PulseSequenceName", "Pulse Sequence Name", "SH", "1"),
+    E::new(0x00189006, "MRImagingModifierSequence", "MR Imaging Modifier Sequence", "SQ", "1"),
+    E::new(0x00189008, "EchoPulseSequence", "Echo Pulse Sequence", "CS", "1"),
+    E::new(0x00189009, "InversionRecovery", "Inversion Recovery", "CS", "1"),
+    E::new(0x00189010, "FlowCompensation", "Flow Compensation", "CS", "1"),
+    E::new(0x00189011, "MultipleSpinEcho", "Multiple Spin Echo", "CS", "1"),
+    E::new(0x00189012, "MultiPlanarExcitation", "Multi-planar Excitation", "CS", "1"),
+    E::new(0x00189014, "PhaseContrast", "Phase Contrast", "CS", "1"),
+    E::new(0x00189015, "TimeOfFlightContrast", "Time of Flight Contrast", "CS", "1"),
+    E::new(0x00189016, "Spoiling", "Spoiling", "CS", "1"),
+    E::new(0x00189017, "SteadyStatePulseSequence", "Steady State Pulse Sequence", "CS", "1"),
+    E::new(0x00189018, "EchoPlanarPulseSequence", "Echo Planar Pulse Sequence", "CS", "1"),
+    E::new(0x00189019, "TagAngleFirstAxis", "Tag Angle First Axis", "FD", "1"),
+    E::new(0x00189020, "MagnetizationTransfer", "Magnetization Transfer", "CS", "1"),
+    E::new(0x00189021, "T2Preparation", "T2 Preparation", "CS", "1"),
+    E::new(0x00189022, "BloodSignalNulling", "Blood Signal Nulling", "CS", "1"),
+    E::new(0x00189024, "SaturationRecovery", "Saturation Recovery", "CS", "1"),
+    E::new(0x00189025, "SpectrallySelectedSuppression", "Spectrally Selected Suppression", "CS", "1"),
+    E::new(0x00189026, "SpectrallySelectedExcitation", "Spectrally Selected Excitation", "CS", "1"),
+    E::new(0x00189027, "SpatialPresaturation", "Spatial Presaturation", "CS", "1"),
+    E::new(0x00189028, "Tagging", "Tagging", "CS", "1"),
+    E::new(0x00189029, "OversamplingPhase", "Oversampling Phase", "CS", "1"),
+    E::new(0x00189030, "TagSpacingFirstDimension", "Tag Spacing First Dimension", "FD", "1"),
+    E::new(0x00189032, "GeometryOfKSpaceTraversal", "Geometry of k-Space Traversal", "CS", "1"),
+    E::new(0x00189033, "SegmentedKSpaceTraversal", "Segmented k-Space Traversal", "CS", "1"),
+    E::new(0x00189034, "RectilinearPhaseEncodeReordering", "Rectilinear Phase Encode Reordering", "CS", "1"),
+    E::new(0x00189035, "TagThickness", "Tag Thickness", "FD", "1"),
+    E::new(0x00189036, "PartialFourierDirection", "Partial Fourier Direction", "CS", "1"),
+    E::new(0x00189037, "CardiacSynchronizationTechnique", "Cardiac Synchronization Technique", "CS", "1"),
+    E::new(0x00189041, "ReceiveCoilManufacturerName", "Receive Coil Manufacturer Name", "LO", "1"),
+    E::new(0x00189042, "MRReceiveCoilSequence", "MR Receive Coil Sequence", "SQ", "1"),
+    E::new(0x00189043, "ReceiveCoilType", "Receive Coil Type", "CS", "1"),
+    E::new(0x00189044, "QuadratureReceiveCoil", "Quadrature Receive Coil", "CS", "1"),
+    E::new(0x00189045, "MultiCoilDefinitionSequence", "Multi Coil Definition Sequence", "SQ", "1"),
+    E::new(0x00189046, "MultiCoilConfiguration", "Multi Coil Configuration", "LO", "1"),
+    E::new(0x00189047, "MultiCoilElementName", "Multi Coil Element Name", "SH", "1"),
+    E::new(0x00189048, "MultiCoilElementUsed", "Multi Coil Element Used", "CS", "1"),
+    E::new(0x00189049, "MRTransmitCoilSequence", "MR Transmit Coil Sequence", "SQ", "1"),
+    E::new(0x00189050, "TransmitCoilManufacturerName", "Transmit Coil Manufacturer Name", "LO", "1"),
+    E::new(0x00189051, "TransmitCoilType", "Transmit Coil Type", "CS", "1"),
+    E::new(0x00189052, "SpectralWidth", "Spectral Width", "FD", "1-2"),
+    E::new(0x00189053, "ChemicalShiftReference", "Chemical Shift Reference", "FD", "1-2"),
+    E::new(0x00189054, "VolumeLocalizationTechnique", "Volume Localization Technique", "CS", "1"),
+    E::new(0x00189058, "MRAcquisitionFrequencyEncodingSteps", "MR Acquisition Frequency Encoding Steps", "US", "1"),
+    E::new(0x00189059, "Decoupling", "Decoupling", "CS", "1"),
+    E::new(0x00189060, "DecoupledNucleus", "Decoupled Nucleus", "CS", "1-2"),
+    E::new(0x00189061, "DecouplingFrequency", "Decoupling Frequency", "FD", "1-2"),
+    E::new(0x00189062, "DecouplingMethod", "Decoupling Method", "CS", "1"),
+    E::new(0x00189063, "DecouplingChemicalShiftReference", "Decoupling Chemical Shift Reference", "FD", "1-2"),
+    E::new(0x00189064, "KSpaceFiltering", "k-space Filtering", "CS", "1"),
+    E::new(0x00189065, "TimeDomainFiltering", "Time Domain Filtering", "CS", "1-2"),
+    E::new(0x00189066, "NumberOfZeroFills", "Number of Zero Fills", "US", "1-2"),
+    E::new(0x00189067, "BaselineCorrection", "Baseline Correction", "CS", "1"),
+    E::new(0x00189069, "ParallelReductionFactorInPlane", "Parallel Reduction Factor In-plane", "FD", "1"),
+    E::new(0x00189070, "CardiacRRIntervalSpecified", "Cardiac R-R Interval Specified", "FD", "1"),
+    E::new(0x00189073, "AcquisitionDuration", "Acquisition Duration", "FD", "1"),
+    E::new(0x00189074, "FrameAcquisitionDateTime", "Frame Acquisition Date Time", "DT", "1"),
+    E::new(0x00189075, "DiffusionDirectionality", "Diffusion Directionality", "CS", "1"),
+    E::new(0x00189076, "DiffusionGradientDirectionSequence", "Diffusion Gradient Direction Sequence", "SQ", "1"),
+    E::new(0x00189077, "ParallelAcquisition", "Parallel Acquisition", "CS", "1"),
+    E::new(0x00189078, "ParallelAcquisitionTechnique", "Parallel Acquisition Technique", "CS", "1"),
+    E::new(0x00189079, "InversionTimes", "Inversion Times", "FD", "1-n"),
+    E::new(0x00189080, "MetaboliteMapDescription", "Metabolite Map Description", "ST", "1"),
+    E::new(0x00189081, "PartialFourier", "Partial Fourier", "CS", "1"),
+    E::new(0x00189082, "EffectiveEchoTime", "Effective Echo Time", "FD", "1"),
+    E::new(0x00189083, "MetaboliteMapCodeSequence", "Metabolite Map Code Sequence", "SQ", "1"),
+    E::new(0x00189084, "ChemicalShiftSequence", "Chemical Shift Sequence", "SQ", "1"),
+    E::new(0x00189085, "CardiacSignalSource", "Cardiac Signal Source", "CS", "1"),
+    E::new(0x00189087, "DiffusionBValue", "Diffusion b-value", "FD", "1"),
+    E::new(0x00189089, "DiffusionGradientOrientation", "Diffusion Gradient Orientation", "FD", "3"),
+    E::new(0x00189090, "VelocityEncodingDirection", "Velocity Encoding Direction", "FD", "3"),
+    E::new(0x00189091, "VelocityEncodingMinimumValue", "Velocity Encoding Minimum Value", "FD", "1"),
+    E::new(0x00189092, "VelocityEncodingAcquisitionSequence", "Velocity Encoding Acquisition Sequence", "SQ", "1"),
+    E::new(0x00189093, "NumberOfKSpaceTrajectories", "Number of k-Space Trajectories", "US", "1"),
+    E::new(0x00189094, "CoverageOfKSpace", "Coverage of k-Space", "CS", "1"),
+    E::new(0x00189095, "SpectroscopyAcquisitionPhaseRows", "Spectroscopy Acquisition Phase Rows", "UL", "1"),
+    E::new(0x00189096, "ParallelReductionFactorInPlaneRetired", "Parallel Reduction Factor In-plane (Retired)", "FD", "1").retired(),
+    E::new(0x00189098, "TransmitterFrequency", "Transmitter Frequency", "FD", "1-2"),
+    E::new(0x00189100, "ResonantNucleus", "Resonant Nucleus", "CS", "1-2"),
+    E::new(0x00189101, "FrequencyCorrection", "Frequency Correction", "CS", "1"),
+    E::new(0x00189103, "MRSpectroscopyFOVGeometrySequence", "MR Spectroscopy FOV Geometry Sequence", "SQ", "1"),
+    E::new(0x00189104, "SlabThickness", "Slab Thickness", "FD", "1"),
+    E::new(0x00189105, "SlabOrientation", "Slab Orientation", "FD", "3"),
+    E::new(0x00189106, "MidSlabPosition", "Mid Slab Position", "FD", "3"),
+    E::new(0x00189107, "MRSpatialSaturationSequence", "MR Spatial Saturation Sequence", "SQ", "1"),
+    E::new(0x00189112, "MRTimingAndRelatedParametersSequence", "MR Timing and Related Parameters Sequence", "SQ", "1"),
+    E::new(0x00189114, "MREchoSequence", "MR Echo Sequence", "SQ", "1"),
+    E::new(0x00189115, "MRModifierSequence", "MR Modifier Sequence", "SQ", "1"),
+    E::new(0x00189117, "MRDiffusionSequence", "MR Diffusion Sequence", "SQ", "1"),
+    E::new(0x00189118, "CardiacSynchronizationSequence", "Cardiac Synchronization Sequence", "SQ", "1"),
+    E::new(0x00189119, "MRAveragesSequence", "MR Averages Sequence", "SQ", "1"),
+    E::new(0x00189125, "MRFOVGeometrySequence", "MRFOV Geometry Sequence", "SQ", "1"),
+    E::new(0x00189126, "VolumeLocalizationSequence", "Volume Localization Sequence", "SQ", "1"),
+    E::new(0x00189127, "SpectroscopyAcquisitionDataColumns", "Spectroscopy Acquisition Data Columns", "UL", "1"),
+    E::new(0x00189147, "DiffusionAnisotropyType", "Diffusion Anisotropy Type", "CS", "1"),
+    E::new(0x00189151, "FrameReferenceDateTime", "Frame Reference Date Time", "DT", "1"),
+    E::new(0x00189152, "MRMetaboliteMapSequence", "MR Metabolite Map Sequence", "SQ", "1"),
+    E::new(0x00189155, "ParallelReductionFactorOutOfPlane", "Parallel Reduction Factor out-of-plane", "FD", "1"),
+    E::new(0x00189159, "SpectroscopyAcquisitionOutOfPlanePhaseSteps", "Spectroscopy Acquisition Out of Plane Phase Steps", "UL", "1"),
+    E::new(0x00189166, "BulkMotionStatus", "Bulk Motion Status", "CS", "1").retired(),
+    E::new(0x00189168, "ParallelReductionFactorSecondInPlane", "Parallel Reduction Factor Second In-plane", "FD", "1"),
+    E::new(0x00189169, "CardiacBeatRejectionTechnique", "Cardiac Beat Rejection Technique", "CS", "1"),
+    E::new(0x00189170, "RespiratoryMotionCompensationTechnique", "Respiratory Motion Compensation Technique", "CS", "1"),
+    E::new(0x00189171, "RespiratorySignalSource", "Respiratory Signal Source", "CS", "1"),
+    E::new(0x00189172, "BulkMotionCompensationTechnique", "Bulk Motion Compensation Technique", "CS", "1"),
+    E::new(0x00189173, "BulkMotionSignalSource", "Bulk Motion Signal Source", "CS", "1"),
+    E::new(0x00189174, "ApplicableSafetyStandardAgency", "Applicable Safety Standard Agency", "CS", "1"),
+    E::new(0x00189175, "ApplicableSafetyStandardDescription", "Applicable Safety Standard Description", "LO", "1"),
+    E::new(0x00189176, "OperatingModeSequence", "Operating Mode Sequence", "SQ", "1"),
+    E::new(0x00189177, "OperatingModeType", "Operating Mode Type", "CS", "1"),
+    E::new(0x00189178, "OperatingMode", "Operating Mode", "CS", "1"),
+    E::new(0x00189179, "SpecificAbsorptionRateDefinition", "Specific Absorption Rate Definition", "CS", "1"),
+    E::new(0x00189180, "GradientOutputType", "Gradient Output Type", "CS", "1"),
+    E::new(0x00189181, "SpecificAbsorptionRateValue", "Specific Absorption Rate Value", "FD", "1"),
+    E::new(0x00189182, "GradientOutput", "Gradient Output", "FD", "1"),
+    E::new(0x00189183, "FlowCompensationDirection", "Flow Compensation Direction", "CS", "1"),
+    E::new(0x00189184, "TaggingDelay", "Tagging Delay", "FD", "1"),
+    E::new(0x00189185, "RespiratoryMotionCompensationTechniqueDescription", "Respiratory Motion Compensation Technique Description", "ST", "1"),
+    E::new(0x00189186, "RespiratorySignalSourceID", "Respiratory Signal Source ID", "SH", "1"),
+    E::new(0x00189195, "ChemicalShiftMinimumIntegrationLimitInHz", "Chemical Shift Minimum Integration Limit in Hz", "FD", "1").retired(),
+    E::new(0x00189196, "ChemicalShiftMaximumIntegrationLimitInHz", "Chemical Shift Maximum Integration Limit in Hz", "FD", "1").retired(),
+    E::new(0x00189197, "MRVelocityEncodingSequence", "MR Velocity Encoding Sequence", "SQ", "1"),
+    E::new(0x00189198, "FirstOrderPhaseCorrection", "First Order Phase Correction", "CS", "1"),
+    E::new(0x00189199, "WaterReferencedPhaseCorrection", "Water Referenced Phase Correction", "CS", "1"),
+    E::new(0x00189200, "MRSpectroscopyAcquisitionType", "MR Spectroscopy Acquisition Type", "CS", "1"),
+    E::new(0x00189214, "RespiratoryCyclePosition", "Respiratory Cycle Position", "CS", "1"),
+    E::new(0x00189217, "VelocityEncodingMaximumValue", "Velocity Encoding Maximum Value", "FD", "1"),
+    E::new(0x00189218, "TagSpacingSecondDimension", "Tag Spacing Second Dimension", "FD", "1"),
+    E::new(0x00189219, "TagAngleSecondAxis", "Tag Angle Second Axis", "SS", "1"),
+    E::new(0x00189220, "FrameAcquisitionDuration", "Frame Acquisition Duration", "FD", "1"),
+    E::new(0x00189226, "MRImageFrameTypeSequence", "MR Image Frame Type Sequence", "SQ", "1"),
+    E::new(0x00189227, "MRSpectroscopyFrameTypeSequence", "MR Spectroscopy Frame Type Sequence", "SQ", "1"),
+    E::new(0x00189231, "MRAcquisitionPhaseEncodingStepsInPlane", "MR Acquisition Phase Encoding Steps in-plane", "US", "1"),
+    E::new(0x00189232, "MRAcquisitionPhaseEncodingStepsOutOfPlane", "MR Acquisition Phase Encoding Steps out-of-plane", "US", "1"),
+    E::new(0x00189234, "SpectroscopyAcquisitionPhaseColumns", "Spectroscopy Acquisition Phase Columns", "UL", "1"),
+    E::new(0x00189236, "CardiacCyclePosition", "Cardiac Cycle Position", "CS", "1"),
+    E::new(0x00189239, "SpecificAbsorptionRateSequence", "Specific Absorption Rate Sequence", "SQ", "1"),
+    E::new(0x00189240, "RFEchoTrainLength", "RF Echo Train Length", "US", "1"),
+    E::new(0x00189241, "GradientEchoTrainLength", "Gradient Echo Train Length", "US", "1"),
+    E::new(0x00189250, "ArterialSpinLabelingContrast", "Arterial Spin Labeling Contrast", "CS", "1"),
+    E::new(0x00189251, "MRArterialSpinLabelingSequence", "MR Arterial Spin Labeling Sequence", "SQ", "1"),
+    E::new(0x00189295, "ChemicalShiftMinimumIntegrationLimitInppm", "Chemical Shift Minimum Integration Limit in ppm", "FD", "1"),
+    E::new(0x00189296, "ChemicalShiftMaximumIntegrationLimitInppm", "Chemical Shift Maximum Integration Limit in ppm", "FD", "1"),
+    E::new(0x00189297, "WaterReferenceAcquisition", "Water Reference Acquisition", "CS", "1"),
+    E::new(0x00189298, "EchoPeakPosition", "Echo Peak Position", "IS", "1"),
+    E::new(0x00189301, "CTAcquisitionTypeSequence", "CT Acquisition Type Sequence", "SQ", "1"),
+    E::new(0x00189302, "AcquisitionType", "Acquisition Type", "CS", "1"),
+    E::new(0x00189303, "TubeAngle", "Tube Angle", "FD", "1"),
+    E::new(0x00189304, "CTAcquisitionDetailsSequence", "CT Acquisition Details Sequence", "SQ", "1"),
+    E::new(0x00189305, "RevolutionTime", "Revolution Time", "FD", "1"),
+    E::new(0x00189306, "SingleCollimationWidth", "Single Collimation Width", "FD", "1"),
+    E::new(0x00189307, "TotalCollimationWidth", "Total Collimation Width", "FD", "1"),
+    E::new(0x00189308, "CTTableDynamicsSequence", "CT Table Dynamics Sequence", "SQ", "1"),
+    E::new(0x00189309, "TableSpeed", "Table Speed", "FD", "1"),
+    E::new(0x00189310, "TableFeedPerRotation", "Table Feed per Rotation", "FD", "1"),
+    E::new(0x00189311, "SpiralPitchFactor", "Spiral Pitch Factor", "FD", "1"),
+    E::new(0x00189312, "CTGeometrySequence", "CT Geometry Sequence", "SQ", "1"),
+    E::new(0x00189313, "DataCollectionCenterPatient", "Data Collection Center (Patient)", "FD", "3"),
+    E::new(0x00189314, "CTReconstructionSequence", "CT Reconstruction Sequence", "SQ", "1"),
+    E::new(0x00189315, "ReconstructionAlgorithm", "Reconstruction Algorithm", "CS", "1"),
+    E::new(0x00189316, "ConvolutionKernelGroup", "Convolution Kernel Group", "CS", "1"),
+    E::new(0x00189317, "ReconstructionFieldOfView", "Reconstruction Field of View", "FD", "2"),
+    E::new(0x00189318, "ReconstructionTargetCenterPatient", "Reconstruction Target Center (Patient)", "FD", "3"),
+    E::new(0x00189319, "ReconstructionAngle", "Reconstruction Angle", "FD", "1"),
+    E::new(0x00189320, "ImageFilter", "Image Filter", "SH", "1"),
+    E::new(0x00189321, "CTExposureSequence", "CT Exposure Sequence", "SQ", "1"),
+    E::new(0x00189322, "ReconstructionPixelSpacing", "Reconstruction Pixel Spacing", "FD", "2"),
+    E::new(0x00189323, "ExposureModulationType", "Exposure Modulation Type", "CS", "1-n"),
+    E::new(0x00189324, "EstimatedDoseSaving", "Estimated Dose Saving", "FD", "1"),
+    E::new(0x00189325, "CTXRayDetailsSequence", "CT X-Ray Details Sequence", "SQ", "1"),
+    E::new(0x00189326, "CTPositionSequence", "CT Position Sequence", "SQ", "1"),
+    E::new(0x00189327, "TablePosition", "Table Position", "FD", "1"),
+    E::new(0x00189328, "ExposureTimeInms", "Exposure Time in ms", "FD", "1"),
+    E::new(0x00189329, "CTImageFrameTypeSequence", "CT Image Frame Type Sequence", "SQ", "1"),
+    E::new(0x00189330, "XRayTubeCurrentInmA", "X-Ray Tube Current in mA", "FD", "1"),
+    E::new(0x00189332, "ExposureInmAs", "Exposure in mAs", "FD", "1"),
+    E::new(0x00189333, "ConstantVolumeFlag", "Constant Volume Flag", "CS", "1"),
+    E::new(0x00189334, "FluoroscopyFlag", "Fluoroscopy Flag", "CS", "1"),
+    E::new(0x00189335, "DistanceSourceToDataCollectionCenter", "Distance Source to Data Collection Center", "FD", "1"),
+    E::new(0x00189337, "ContrastBolusAgentNumber", "Contrast/Bolus Agent Number", "US", "1"),
+    E::new(0x00189338, "ContrastBolusIngredientCodeSequence", "Contrast/Bolus Ingredient Code Sequence", "SQ", "1"),
+    E::new(0x00189340, "ContrastAdministrationProfileSequence", "Contrast Administration Profile Sequence", "SQ", "1"),
+    E::new(0x00189341, "ContrastBolusUsageSequence", "Contrast/Bolus Usage Sequence", "SQ", "1"),
+    E::new(0x00189342, "ContrastBolusAgentAdministered", "Contrast/Bolus Agent Administered", "CS", "1"),
+    E::new(0x00189343, "ContrastBolusAgentDetected", "Contrast/Bolus Agent Detected", "CS", "1"),
+    E::new(0x00189344, "ContrastBolusAgentPhase", "Contrast/Bolus Agent Phase", "CS", "1"),
+    E::new(0x00189345, "CTDIvol", "CTDIvol", "FD", "1"),
+    E::new(0x00189346, "CTDIPhantomTypeCodeSequence", "CTDI Phantom Type Code Sequence", "SQ", "1"),
+    E::new(0x00189351, "CalciumScoringMassFactorPatient", "Calcium Scoring Mass Factor Patient", "FL", "1"),
+    E::new(0x00189352, "CalciumScoringMassFactorDevice", "Calcium Scoring Mass Factor Device", "FL", "3"),
+    E::new(0x00189353, "EnergyWeightingFactor", "Energy Weighting Factor", "FL", "1"),
+    E::new(0x00189360, "CTAdditionalXRaySourceSequence", "CT Additional X-Ray Source Sequence", "SQ", "1"),
+    E::new(0x00189401, "ProjectionPixelCalibrationSequence", "Projection Pixel Calibration Sequence", "SQ", "1"),
+    E::new(0x00189402, "DistanceSourceToIsocenter", "Distance Source to Isocenter", "FL", "1"),
+    E::new(0x00189403, "DistanceObjectToTableTop", "Distance Object to Table Top", "FL", "1"),
+    E::new(0x00189404, "ObjectPixelSpacingInCenterOfBeam", "Object Pixel Spacing in Center of Beam", "FL", "2"),
+    E::new(0x00189405, "PositionerPositionSequence", "Positioner Position Sequence", "SQ", "1"),
+    E::new(0x00189406, "TablePositionSequence", "Table Position Sequence", "SQ", "1"),
+    E::new(0x00189407, "CollimatorShapeSequence", "Collimator Shape Sequence", "SQ", "1"),
+    E::new(0x00189410, "PlanesInAcquisition", "Planes in Acquisition", "CS", "1"),
+    E::new(0x00189412, "XAXRFFrameCharacteristicsSequence", "XA/XRF Frame Characteristics Sequence", "SQ", "1"),
+    E::new(0x00189417, "FrameAcquisitionSequence", "Frame Acquisition Sequence", "SQ", "1"),
+    E::new(0x00189420, "XRayReceptorType", "X-Ray Receptor Type", "CS", "1"),
+    E::new(0x00189423, "AcquisitionProtocolName", "Acquisition Protocol Name", "LO", "1"),
+    E::new(0x00189424, "AcquisitionProtocolDescription", "Acquisition Protocol Description", "LT", "1"),
+    E::new(0x00189425, "ContrastBolusIngredientOpaque", "Contrast/Bolus Ingredient Opaque", "CS", "1"),
+    E::new(0x00189426, "DistanceReceptorPlaneToDetectorHousing", "Distance Receptor Plane to Detector Housing", "FL", "1"),
+    E::new(0x00189427, "IntensifierActiveShape", "Intensifier Active Shape", "CS", "1"),
+    E::new(0x00189428, "IntensifierActiveDimensions", "Intensifier Active Dimension(s)", "FL", "1-2"),
+    E::new(0x00189429, "PhysicalDetectorSize", "Physical Detector Size", "FL", "2"),
+    E::new(0x00189430, "PositionOfIsocenterProjection", "Position of Isocenter Projection", "FL", "2"),
+    E::new(0x00189432, "FieldOfViewSequence", "Field of View Sequence", "SQ", "1"),
+    E::new(0x00189433, "FieldOfViewDescription", "Field of View Description", "LO", "1"),
+    E::new(0x00189434, "ExposureControlSensingRegionsSequence", "Exposure Control Sensing Regions Sequence", "SQ", "1"),
+    E::new(0x00189435, "ExposureControlSensingRegionShape", "Exposure Control Sensing Region Shape", "CS", "1"),
+    E::new(0x00189436, "ExposureControlSensingRegionLeftVerticalEdge", "Exposure Control Sensing Region Left Vertical Edge", "SS", "1"),
+    E::new(0x00189437, "ExposureControlSensingRegionRightVerticalEdge", "Exposure Control Sensing Region Right Vertical Edge", "SS", "1"),
+    E::new(0x00189438, "ExposureControlSensingRegionUpperHorizontalEdge", "Exposure Control Sensing Region Upper Horizontal Edge", "SS", "1"),
+    E::new(0x00189439, "ExposureControlSensingRegionLowerHorizontalEdge", "Exposure Control Sensing Region Lower Horizontal Edge", "SS", "1"),
+    E::new(0x00189440, "CenterOfCircularExposureControlSensingRegion", "Center of Circular Exposure Control Sensing Region", "SS", "2"),
+    E::new(0x00189441, "RadiusOfCircularExposureControlSensingRegion", "Radius of Circular Exposure Control Sensing Region", "US", "1"),
+    E::new(0x00189442, "VerticesOfThePolygonalExposureControlSensingRegion", "Vertices of the Polygonal Exposure Control Sensing Region", "SS", "2-n"),
+    E::new(0x00189447, "ColumnAngulationPatient", "Column Angulation (Patient)", "FL", "1"),
+    E::new(0x00189449, "BeamAngle", "Beam Angle", "FL", "1"),
+    E::new(0x00189451, "FrameDetectorParametersSequence", "Frame Detector Parameters Sequence", "SQ", "1"),
+    E::new(0x00189452, "CalculatedAnatomyThickness", "Calculated Anatomy Thickness", "FL", "1"),
+    E::new(0x00189455, "CalibrationSequence", "Calibration Sequence", "SQ", "1"),
+    E::new(0x00189456, "ObjectThicknessSequence", "Object Thickness Sequence", "SQ", "1"),
+    E::new(0x00189457, "PlaneIdentification", "Plane Identification", "CS", "1"),
+    E::new(0x00189461, "FieldOfViewDimensionsInFloat", "Field of View Dimension(s) in Float", "FL", "1-2"),
+    E::new(0x00189462, "IsocenterReferenceSystemSequence", "Isocenter Reference System Sequence", "SQ", "1"),
+    E::new(0x00189463, "PositionerIsocenterPrimaryAngle", "Positioner Isocenter Primary Angle", "FL", "1"),
+    E::new(0x00189464, "PositionerIsocenterSecondaryAngle", "Positioner Isocenter Secondary Angle", "FL", "1"),
+    E::new(0x00189465, "PositionerIsocenterDetectorRotationAngle", "Positioner Isocenter Detector Rotation Angle", "FL", "1"),
+    E::new(0x00189466, "TableXPositionToIsocenter", "Table X Position to Isocenter", "FL", "1"),
+    E::new(0x00189467, "TableYPositionToIsocenter", "Table Y Position to Isocenter", "FL", "1"),
+    E::new(0x00189468, "TableZPositionToIsocenter", "Table Z Position to Isocenter", "FL", "1"),
+    E::new(0x00189469, "TableHorizontalRotationAngle", "Table Horizontal Rotation Angle", "FL", "1"),
+    E::new(0x00189470, "TableHeadTiltAngle", "Table Head Tilt Angle", "FL", "1"),
+    E::new(0x00189471, "TableCradleTiltAngle", "Table Cradle Tilt Angle", "FL", "1"),
+    E::new(0x00189472, "FrameDisplayShutterSequence", "Frame Display Shutter Sequence", "SQ", "1"),
+    E::new(0x00189473, "AcquiredImageAreaDoseProduct", "Acquired Image Area Dose Product", "FL", "1"),
+    E::new(0x00189474, "CArmPositionerTabletopRelationship", "C-arm Positioner Tabletop Relationship", "CS", "1"),
+    E::new(0x00189476, "XRayGeometrySequence", "X-Ray Geometry Sequence", "SQ", "1"),
+    E::new(0x00189477, "IrradiationEventIdentificationSequence", "Irradiation Event Identification Sequence", "SQ", "1"),
+    E::new(0x00189504, "XRay3DFrameTypeSequence", "X-Ray 3D Frame Type Sequence", "SQ", "1"),
+    E::new(0x00189506, "ContributingSourcesSequence", "Contributing Sources Sequence", "SQ", "1"),
+    E::new(0x00189507, "XRay3DAcquisitionSequence", "X-Ray 3D Acquisition Sequence", "SQ", "1"),
+    E::new(0x00189508, "PrimaryPositionerScanArc", "Primary Positioner Scan Arc", "FL", "1"),
+    E::new(0x00189509, "SecondaryPositionerScanArc", "Secondary Positioner Scan Arc", "FL", "1"),
+    E::new(0x00189510, "PrimaryPositionerScanStartAngle", "Primary Positioner Scan Start Angle", "FL", "1"),
+    E::new(0x00189511, "SecondaryPositionerScanStartAngle", "Secondary Positioner Scan Start Angle", "FL", "1"),
+    E::new(0x00189514, "PrimaryPositionerIncrement", "Primary Positioner Increment", "FL", "1"),
+    E::new(0x00189515, "SecondaryPositionerIncrement", "Secondary Positioner Increment", "FL", "1"),
+    E::new(0x00189516, "StartAcquisitionDateTime", "Start Acquisition Date Time", "DT", "1"),
+    E::new(0x00189517, "EndAcquisitionDateTime", "End Acquisition Date Time", "DT", "1"),
+    E::new(0x00189524, "ApplicationName", "Application Name", "LO", "1"),
+    E::new(0x00189525, "ApplicationVersion", "Application Version", "LO", "1"),
+    E::new(0x00189526, "ApplicationManufacturer", "Application Manufacturer", "LO", "1"),
+    E::new(0x00189527, "AlgorithmType", "Algorithm Type", "CS", "1"),
+    E::new(0x00189528, "AlgorithmDescription", "Algorithm Description", "LO", "1"),
+    E::new(0x00189530, "XRay3DReconstructionSequence", "X-Ray 3D Reconstruction Sequence", "SQ", "1"),
+    E::new(0x00189531, "ReconstructionDescription", "Reconstruction Description", "LO", "1"),
+    E::new(0x00189538, "PerProjectionAcquisitionSequence", "Per Projection Acquisition Sequence", "SQ", "1"),
+    E::new(0x00189601, "DiffusionBMatrixSequence", "Diffusion b-matrix Sequence", "SQ", "1"),
+    E::new(0x00189602, "DiffusionBValueXX", "Diffusion b-value XX", "FD", "1"),
+    E::new(0x00189603, "DiffusionBValueXY", "Diffusion b-value XY", "FD", "1"),
+    E::new(0x00189604, "DiffusionBValueXZ", "Diffusion b-value XZ", "FD", "1"),
+    E::new(0x00189605, "DiffusionBValueYY", "Diffusion b-value YY", "FD", "1"),
+    E::new(0x00189606, "DiffusionBValueYZ", "Diffusion b-value YZ", "FD", "1"),
+    E::new(0x00189607, "DiffusionBValueZZ", "Diffusion b-value ZZ", "FD", "1"),
+    E::new(0x00189701, "DecayCorrectionDateTime", "Decay Correction Date Time", "DT", "1"),
+    E::new(0x00189715, "StartDensityThreshold", "Start Density Threshold", "FD", "1"),
+    E::new(0x00189716, "StartRelativeDensityDifferenceThreshold", "Start Relative Density Difference Threshold", "FD", "1"),
+    E::new(0x00189717, "StartCardiacTriggerCountThreshold", "Start Cardiac Trigger Count Threshold", "FD", "1"),
+    E::new(0x00189718, "StartRespiratoryTriggerCountThreshold", "Start Respiratory Trigger Count Threshold", "FD", "1"),
+    E::new(0x00189719, "TerminationCountsThreshold", "Termination Counts Threshold", "FD", "1"),
+    E::new(0x00189720, "TerminationDensityThreshold", "Termination Density Threshold", "FD", "1"),
+    E::new(0x00189721, "TerminationRelativeDensityThreshold", "Termination Relative Density Threshold", "FD", "1"),
+    E::new(0x00189722, "TerminationTimeThreshold", "Termination Time Threshold", "FD", "1"),
+    E::new(0x00189723, "TerminationCardiacTriggerCountThreshold", "Termination Cardiac Trigger Count Threshold", "FD", "1"),
+    E::new(0x00189724, "TerminationRespiratoryTriggerCountThreshold", "Termination Respiratory Trigger Count Threshold", "FD", "1"),
+    E::new(0x00189725, "DetectorGeometry", "Detector Geometry", "CS", "1"),
+    E::new(0x00189726, "TransverseDetectorSeparation", "Transverse Detector Separation", "FD", "1"),
+    E::new(0x00189727, "AxialDetectorDimension", "Axial Detector Dimension", "FD", "1"),
+    E::new(0x00189729, "RadiopharmaceuticalAgentNumber", "Radiopharmaceutical Agent Number", "US", "1"),
+    E::new(0x00189732, "PETFrameAcquisitionSequence", "PET Frame Acquisition Sequence", "SQ", "1"),
+    E::new(0x00189733, "PETDetectorMotionDetailsSequence", "PET Detector Motion Details Sequence", "SQ", "1"),
+    E::new(0x00189734, "PETTableDynamicsSequence", "PET Table Dynamics Sequence", "SQ", "1"),
+    E::new(0x00189735, "PETPositionSequence", "PET Position Sequence", "SQ", "1"),
+    E::new(0x00189736, "PETFrameCorrectionFactorsSequence", "PET Frame Correction Factors Sequence", "SQ", "1"),
+    E::new(0x00189737, "RadiopharmaceuticalUsageSequence", "Radiopharmaceutical Usage Sequence", "SQ", "1"),
+    E::new(0x00189738, "AttenuationCorrectionSource", "Attenuation Correction Source", "CS", "1"),
+    E::new(0x00189739, "NumberOfIterations", "Number of Iterations", "US", "1"),
+    E::new(0x00189740, "NumberOfSubsets", "Number of Subsets", "US", "1"),
+    E::new(0x00189749, "PETReconstructionSequence", "PET Reconstruction Sequence", "SQ", "1"),
+    E::new(0x00189751, "PETFrameTypeSequence", "PET Frame Type Sequence", "SQ", "1"),
+    E::new(0x00189755, "TimeOfFlightInformationUsed", "Time of Flight Information Used", "CS", "1"),
+    E::new(0x00189756, "ReconstructionType", "Reconstruction Type", "CS", "1"),
+    E::new(0x00189758, "DecayCorrected", "Decay Corrected", "CS", "1"),
+    E::new(0x00189759, "AttenuationCorrected", "Attenuation Corrected", "CS", "1"),
+    E::new(0x00189760, "ScatterCorrected", "Scatter Corrected", "CS", "1"),
+    E::new(0x00189761, "DeadTimeCorrected", "Dead Time Corrected", "CS", "1"),
+    E::new(0x00189762, "GantryMotionCorrected", "Gantry Motion Corrected", "CS", "1"),
+    E::new(0x00189763, "PatientMotionCorrected", "Patient Motion Corrected", "CS", "1"),
+    E::new(0x00189764, "CountLossNormalizationCorrected", "Count Loss Normalization Corrected", "CS", "1"),
+    E::new(0x00189765, "RandomsCorrected", "Randoms Corrected", "CS", "1"),
+    E::new(0x00189766, "NonUniformRadialSamplingCorrected", "Non-uniform Radial Sampling Corrected", "CS", "1"),
+    E::new(0x00189767, "SensitivityCalibrated", "Sensitivity Calibrated", "CS", "1"),
+    E::new(0x00189768, "DetectorNormalizationCorrection", "Detector Normalization Correction", "CS", "1"),
+    E::new(0x00189769, "IterativeReconstructionMethod", "Iterative Reconstruction Method", "CS", "1"),
+    E::new(0x00189770, "AttenuationCorrectionTemporalRelationship", "Attenuation Correction Temporal Relationship", "CS", "1"),
+    E::new(0x00189771, "PatientPhysiologicalStateSequence", "Patient Physiological State Sequence", "SQ", "1"),
+    E::new(0x00189772, "PatientPhysiologicalStateCodeSequence", "Patient Physiological State Code Sequence", "SQ", "1"),
+    E::new(0x00189801, "DepthsOfFocus", "Depths of Focus", "FD", "1-n"),
+    E::new(0x00189803, "ExcludedIntervalsSequence", "Excluded Intervals Sequence", "SQ", "1"),
+    E::new(0x00189804, "ExclusionStartDateTime", "Exclusion Start Date Time", "DT", "1"),
+    E::new(0x00189805, "ExclusionDuration", "Exclusion Duration", "FD", "1"),
+    E::new(0x00189806, "USImageDescriptionSequence", "US Image Description Sequence", "SQ", "1"),
+    E::new(0x00189807, "ImageDataTypeSequence", "Image Data Type Sequence", "SQ", "1"),
+    E::new(0x00189808, "DataType", "Data Type", "CS", "1"),
+    E::new(0x00189809, "TransducerScanPatternCodeSequence", "Transducer Scan Pattern Code Sequence", "SQ", "1"),
+    E::new(0x0018980B, "AliasedDataType", "Aliased Data Type", "CS", "1"),
+    E::new(0x0018980C, "PositionMeasuringDeviceUsed", "Position Measuring Device Used", "CS", "1"),
+    E::new(0x0018980D, "TransducerGeometryCodeSequence", "Transducer Geometry Code Sequence", "SQ", "1"),
+    E::new(0x0018980E, "TransducerBeamSteeringCodeSequence", "Transducer Beam Steering Code Sequence", "SQ", "1"),
+    E::new(0x0018980F, "TransducerApplicationCodeSequence", "Transducer Application Code Sequence", "SQ", "1"),
+    E::new(0x0018A001, "ContributingEquipmentSequence", "Contributing Equipment Sequence", "SQ", "1"),
+    E::new(0x0018A002, "ContributionDateTime", "Contribution Date Time", "DT", "1"),
+    E::new(0x0018A003, "ContributionDescription", "Contribution Description", "ST", "1"),
+    E::new(0x0020000D, "StudyInstanceUID", "Study Instance UID", "UI", "1"),
+    E::new(0x0020000E, "SeriesInstanceUID", "Series Instance UID", "UI", "1"),
+    E::new(0x00200010, "StudyID", "Study ID", "SH", "1"),
+    E::new(0x00200011, "SeriesNumber", "Series Number", "IS", "1"),
+    E::new(0x00200012, "AcquisitionNumber", "Acquisition Number", "IS", "1"),
+    E::new(0x00200013, "InstanceNumber", "Instance Number", "IS", "1"),
+    E::new(0x00200019, "ItemNumber", "Item Number", "IS", "1"),
+    E::new(0x00200020, "PatientOrientation", "Patient Orientation", "CS", "2"),
+    E::new(0x00200022, "OverlayNumber", "Overlay Number", "IS", "1").retired(),
+    E::new(0x00200024, "CurveNumber", "Curve Number", "IS", "1").retired(),
+    E::new(0x00200026, "LUTNumber", "LUT Number", "IS", "1").retired(),
+    E::new(0x00200030, "ImagePosition", "Image Position", "DS", "3").retired(),
+    E::new(0x00200032, "ImagePositionPatient", "Image Position (Patient)", "DS", "3"),
+    E::new(0x00200035, "ImageOrientation", "Image Orientation", "DS", "6").retired(),
+    E::new(0x00200037, "ImageOrientationPatient", "Image Orientation (Patient)", "DS", "6"),
+    E::new(0x00200050, "Location", "Location", "DS", "1").retired(),
+    E::new(0x00200052, "FrameOfReferenceUID", "Frame of Reference UID", "UI", "1"),
+    E::new(0x00200060, "Laterality", "Laterality", "CS", "1"),
+    E::new(0x00200062, "ImageLaterality", "Image Laterality", "CS", "1"),
+    E::new(0x00200070, "ImageGeometryType", "Image Geometry Type", "LO", "1").retired(),
+    E::new(0x00200080, "MaskingImage", "Masking Image", "CS", "1-n").retired(),
+    E::new(0x002000AA, "ReportNumber", "Report Number", "IS", "1").retired(),
+    E::new(0x00200100, "TemporalPositionIdentifier", "Temporal Position Identifier", "IS", "1"),
+    E::new(0x00200105, "NumberOfTemporalPositions", "Number of Temporal Positions", "IS", "1"),
+    E::new(0x00200110, "TemporalResolution", "Temporal Resolution", "DS", "1"),
+    E::new(0x00200200, "SynchronizationFrameOfReferenceUID", "Synchronization Frame of Reference UID", "UI", "1"),
+    E::new(0x00200242, "SOPInstanceUIDOfConcatenationSource", "SOP Instance UID of Concatenation Source", "UI", "1"),
+    E::new(0x00201000, "SeriesInStudy", "Series in Study", "IS", "1").retired(),
+    E::new(0x00201001, "AcquisitionsInSeries", "Acquisitions in Series", "IS", "1").retired(),
+    E::new(0x00201002, "ImagesInAcquisition", "Images in Acquisition", "IS", "1"),
+    E::new(0x00201003, "ImagesInSeries", "Images in Series", "IS", "1").retired(),
+    E::new(0x00201004, "AcquisitionsInStudy", "Acquisitions in Study", "IS", "1").retired(),
+    E::new(0x00201005, "ImagesInStudy", "Images in Study", "IS", "1").retired(),
+    E::new(0x00201020, "Reference", "Reference", "LO", "1-n").retired(),
+    E::new(0x00201040, "PositionReferenceIndicator", "Position Reference Indicator", "LO", "1"),
+    E::new(0x00201041, "SliceLocation", "Slice Location", "DS", "1"),
+    E::new(0x00201070, "OtherStudyNumbers", "Other Study Numbers", "IS", "1-n").retired(),
+    E::new(0x00201200, "NumberOfPatientRelatedStudies", "Number of Patient Related Studies", "IS", "1"),
+    E::new(0x00201202, "NumberOfPatientRelatedSeries", "Number of Patient Related Series", "IS", "1"),
+    E::new(0x00201204, "NumberOfPatientRelatedInstances", "Number of Patient Related Instances", "IS", "1"),
+    E::new(0x00201206, "NumberOfStudyRelatedSeries", "Number of Study Related Series", "IS", "1"),
+    E::new(0x00201208, "NumberOfStudyRelatedInstances", "Number of Study Related Instances", "IS", "1"),
+    E::new(0x00201209, "NumberOfSeriesRelatedInstances", "Number of Series Related Instances", "IS", "1"),
+    E::new(0x00204000, "ImageComments", "Image Comments", "LT", "1"),
+    E::new(0x00209056, "StackID", "Stack ID", "SH", "1"),
+    E::new(0x00209057, "InStackPositionNumber", "In-Stack Position Number", "UL", "1"),
+    E::new(0x00209071, "FrameAnatomySequence", "Frame Anatomy Sequence", "SQ", "1"),
+    E::new(0x00209111, "FrameContentSequence", "Frame Content Sequence", "SQ", "1"),
+    E::new(0x00209113, "PlanePositionSequence", "Plane Position Sequence", "SQ", "1"),
+    E::new(0x00209116, "PlaneOrientationSequence", "Plane Orientation Sequence", "SQ", "1"),
+    E::new(0x00209128, "TemporalPositionIndex", "Temporal Position Index", "UL", "1"),
+    E::new(0x00209153, "NominalCardiacTriggerDelayTime", "Nominal Cardiac Trigger Delay Time", "FD", "1"),
+    E::new(0x00209154, "NominalCardiacTriggerTimePriorToRPeak", "Nominal Cardiac Trigger Time Prior To R-Peak", "FL", "1"),
+    E::new(0x00209155, "ActualCardiacTriggerTimePriorToRPeak", "Actual Cardiac Trigger Time Prior To R-Peak", "FL", "1"),
+    E::new(0x00209156, "FrameAcquisitionNumber", "Frame Acquisition Number", "US", "1"),
+    E::new(0x00209157, "DimensionIndexValues", "Dimension Index Values", "UL", "1-n"),
+    E::new(0x00209158, "FrameComments", "Frame Comments", "LT", "1"),
+    E::new(0x00209161, "ConcatenationUID", "Concatenation UID", "UI", "1"),
+    E::new(0x00209162, "InConcatenationNumber", "In-concatenation Number", "US", "1"),
+    E::new(0x00209163, "InConcatenationTotalNumber", "In-concatenation Total Number", "US", "1"),
+    E::new(0x00209164, "DimensionOrganizationUID", "Dimension Organization UID", "UI", "1"),
+    E::new(0x00209165, "DimensionIndexPointer", "Dimension Index Pointer", "AT", "1"),
+    E::new(0x00209167, "FunctionalGroupPointer", "Functional Group Pointer", "AT", "1"),
+    E::new(0x00209170, "UnassignedSharedConvertedAttributesSequence", "Unassigned Shared Converted Attributes Sequence", "SQ", "1"),
+    E::new(0x00209171, "UnassignedPerFrameConvertedAttributesSequence", "Unassigned Per Frame Converted Attributes Sequence", "SQ", "1"),
+    E::new(0x00209172, "ConversionSourceAttributesSequence", "Conversion Source Attributes Sequence", "SQ", "1"),
+    E::new(0x00209213, "DimensionIndexPrivateCreator", "Dimension Index Private Creator", "LO", "1"),
+    E::new(0x00209221, "DimensionOrganizationSequence", "Dimension Organization Sequence", "SQ", "1"),
+    E::new(0x00209222, "DimensionIndexSequence", "Dimension Index Sequence", "SQ", "1"),
+    E::new(0x00209238, "FunctionalGroupPrivateCreator", "Functional Group Private Creator", "LO", "1"),
+    E::new(0x00209241, "NominalPercentageOfCardiacPhase", "Nominal Percentage of Cardiac Phase", "FL", "1"),
+    E::new(0x00209245, "NominalPercentageOfRespiratoryPhase", "Nominal Percentage of Respiratory Phase", "FL", "1"),
+    E::new(0x00209246, "StartingRespiratoryAmplitude", "Starting Respiratory Amplitude", "FL", "1"),
+    E::new(0x00209247, "StartingRespiratoryPhase", "Starting Respiratory Phase", "CS", "1"),
+    E::new(0x00209248, "EndingRespiratoryAmplitude", "Ending Respiratory Amplitude", "FL", "1"),
+    E::new(0x00209249, "EndingRespiratoryPhase", "Ending Respiratory Phase", "CS", "1"),
+    E::new(0x00209250, "RespiratoryTriggerType", "Respiratory Trigger Type", "CS", "1"),
+    E::new(0x00209251, "RRIntervalTimeNominal", "R-R Interval Time Nominal", "FD", "1"),
+    E::new(0x00209252, "ActualCardiacTriggerDelayTime", "Actual Cardiac Trigger Delay Time", "FD", "1"),
+    E::new(0x00209253, "RespiratorySynchronizationSequence", "Respiratory Synchronization Sequence", "SQ", "1"),
+    E::new(0x00209254, "RespiratoryIntervalTime", "Respiratory Interval Time", "FD", "1"),
+    E::new(0x00209255, "NominalRespiratoryTriggerDelayTime", "Nominal Respiratory Trigger Delay Time", "FD", "1"),
+    E::new(0x00209256, "RespiratoryTriggerDelayThreshold", "Respiratory Trigger Delay Threshold", "FD", "1"),
+    E::new(0x00209257, "ActualRespiratoryTriggerDelayTime", "Actual Respiratory Trigger Delay Time", "FD", "1"),
+    E::new(0x00209301, "ImagePositionVolume", "Image Position (Volume)", "FD", "3"),
+    E::new(0x00209302, "ImageOrientationVolume", "Image Orientation (Volume)", "FD", "6"),
+    E::new(0x00209307, "UltrasoundAcquisitionGeometry", "Ultrasound Acquisition Geometry", "CS", "1"),
+    E::new(0x00209308, "ApexPosition", "Apex Position", "FD", "3"),
+    E::new(0x00209309, "VolumeToTransducerMappingMatrix", "Volume to Transducer Mapping Matrix", "FD", "16"),
+    E::new(0x0020930A, "VolumeToTableMappingMatrix", "Volume to Table Mapping Matrix", "FD", "16"),
+    E::new(0x0020930B, "VolumeToTransducerRelationship", "Volume to Transducer Relationship", "CS", "1"),
+    E::new(0x0020930C, "PatientFrameOfReferenceSource", "Patient Frame of Reference Source", "CS", "1"),
+    E::new(0x0020930D, "TemporalPositionTimeOffset", "Temporal Position Time Offset", "FD", "1"),
+    E::new(0x0020930E, "PlanePositionVolumeSequence", "Plane Position (Volume) Sequence", "SQ", "1"),
+    E::new(0x0020930F, "PlaneOrientationVolumeSequence", "Plane Orientation (Volume) Sequence", "SQ", "1"),
+    E::new(0x00209310, "TemporalPositionSequence", "Temporal Position Sequence", "SQ", "1"),
+    E::new(0x00209311, "DimensionOrganizationType", "Dimension Organization Type", "CS", "1"),
+    E::new(0x00209312, "VolumeFrameOfReferenceUID", "Volume Frame of Reference UID", "UI", "1"),
+    E::new(0x00209313, "TableFrameOfReferenceUID", "Table Frame of Reference UID", "UI", "1"),
+    E::new(0x00209421, "DimensionDescriptionLabel", "Dimension Description Label", "LO", "1"),
+    E::new(0x00209450, "PatientOrientationInFrameSequence", "Patient Orientation in Frame Sequence", "SQ", "1"),
+    E::new(0x00209453, "FrameLabel", "Frame Label", "LO", "1"),
+    E::new(0x00209518, "AcquisitionIndex", "Acquisition Index", "US", "1-n"),
+    E::new(0x00209529, "ContributingSOPInstancesReferenceSequence", "Contributing SOP Instances Reference Sequence", "SQ", "1"),
+    E::new(0x00209536, "ReconstructionIndex", "Reconstruction Index", "US", "1"),
+    E::new(0x00280002, "SamplesPerPixel", "Samples per Pixel", "US", "1"),
+    E::new(0x00280003, "SamplesPerPixelUsed", "Samples Per Pixel Used", "US", "1"),
+    E::new(0x00280004, "PhotometricInterpretation", "Photometric Interpretation", "CS", "1"),
+    E::new(0x00280005, "ImageDimensions", "Image Dimensions", "US", "1").retired(),
+    E::new(0x00280006, "PlanarConfiguration", "Planar Configuration", "US", "1"),
+    E::new(0x00280008, "NumberOfFrames", "Number of Frames", "IS", "1"),
+    E::new(0x00280009, "FrameIncrementPointer", "Frame Increment Pointer", "AT", "1-n"),
+    E::new(0x0028000A, "FrameDimensionPointer", "Frame Dimension Pointer", "AT", "1-n"),
+    E::new(0x00280010, "Rows", "Rows", "US", "1"),
+    E::new(0x00280011, "Columns", "Columns", "US", "1"),
+    E::new(0x00280012, "Planes", "Planes", "US", "1").retired(),
+    E::new(0x00280014, "UltrasoundColorDataPresent", "Ultrasound Color Data Present", "US", "1"),
+    E::new(0x00280030, "PixelSpacing", "Pixel Spacing", "DS", "2"),
+    E::new(0x00280031, "ZoomFactor", "Zoom Factor", "DS", "2"),
+    E::new(0x00280032, "ZoomCenter", "Zoom Center", "DS", "2"),
+    E::new(0x00280034, "PixelAspectRatio", "Pixel Aspect Ratio", "IS", "2"),
+    E::new(0x00280040, "ImageFormat", "Image Format", "CS", "1").retired(),
+    E::new(0x00280050, "ManipulatedImage", "Manipulated Image", "LO", "1-n").retired(),
+    E::new(0x00280051, "CorrectedImage", "Corrected Image", "CS", "1-n"),
+    E::new(0x0028005F, "CompressionRecognitionCode", "Compression Recognition Code", "LO", "1").retired(),
+    E::new(0x00280060, "CompressionCode", "Compression Code", "CS", "1").retired(),
+    E::new(0x00280100, "BitsAllocated", "Bits Allocated", "US", "1"),
+    E::new(0x00280101, "BitsStored", "Bits Stored", "US", "1"),
+    E::new(0x00280102, "HighBit", "High Bit", "US", "1"),
+    E::new(0x00280103, "PixelRepresentation", "Pixel Representation", "US", "1"),
+    E::new(0x00280104, "SmallestValidPixelValue", "Smallest Valid Pixel Value", "US or SS", "1").retired(),
+    E::new(0x00280105, "LargestValidPixelValue", "Largest Valid Pixel Value", "US or SS", "1").retired(),
+    E::new(0x00280106, "SmallestImagePixelValue", "Smallest Image Pixel Value", "US or SS", "1"),
+    E::new(0x00280107, "LargestImagePixelValue", "Largest Image Pixel Value", "US or SS", "1"),
+    E::new(0x00280108, "SmallestPixelValueInSeries", "Smallest Pixel Value in Series", "US or SS", "1"),
+    E::new(0x00280109, "LargestPixelValueInSeries", "Largest Pixel Value in Series", "US or SS", "1"),
+    E::new(0x00280110, "SmallestImagePixelValueInPlane", "Smallest Image Pixel Value in Plane", "US or SS", "1").retired(),
+    E::new(0x00280111, "LargestImagePixelValueInPlane", "Largest Image Pixel Value in Plane", "US or SS", "1").retired(),
+    E::new(0x00280120, "PixelPaddingValue", "Pixel Padding Value", "US or SS", "1"),
+    E::new(0x00280121, "PixelPaddingRangeLimit", "Pixel Padding Range Limit", "US or SS", "1"),
+    E::new(0x00280122, "FloatPixelPaddingValue", "Float Pixel Padding Value", "FL", "1"),
+    E::new(0x00280123, "DoubleFloatPixelPaddingValue", "Double Float Pixel Padding Value", "FD", "1"),
+    E::new(0x00280124, "FloatPixelPaddingRangeLimit", "Float Pixel Padding Range Limit", "FL", "1"),
+    E::new(0x00280125, "DoubleFloatPixelPaddingRangeLimit", "Double Float Pixel Padding Range Limit", "FD", "1"),
+    E::new(0x00280200, "ImageLocation", "Image Location", "US", "1").retired(),
+    E::new(0x00280300, "QualityControlImage", "Quality Control Image", "CS", "1"),
+    E::new(0x00280301, "BurnedInAnnotation", "Burned In Annotation", "CS", "1"),
+    E::new(0x00280302, "RecognizableVisualFeatures", "Recognizable Visual Features", "CS", "1"),
+    E::new(0x00280303, "LongitudinalTemporalInformationModified", "Longitudinal Temporal Information Modified", "CS", "1"),
+    E::new(0x00280304, "ReferencedColorPaletteInstanceUID", "Referenced Color Palette Instance UID", "UI", "1"),
+    E::new(0x00280A02, "PixelSpacingCalibrationType", "Pixel Spacing Calibration Type", "CS", "1"),
+    E::new(0x00280A04, "PixelSpacingCalibrationDescription", "Pixel Spacing Calibration Description", "LO", "1"),
+    E::new(0x00281040, "PixelIntensityRelationship", "Pixel Intensity Relationship", "CS", "1"),
+    E::new(0x00281041, "PixelIntensityRelationshipSign", "Pixel Intensity Relationship Sign", "SS", "1"),
+    E::new(0x00281050, "WindowCenter", "Window Center", "DS", "1-n"),
+    E::new(0x00281051, "WindowWidth", "Window Width", "DS", "1-n"),
+    E::new(0x00281052, "RescaleIntercept", "Rescale Intercept", "DS", "1"),
+    E::new(0x00281053, "RescaleSlope", "Rescale Slope", "DS", "1"),
+    E::new(0x00281054, "RescaleType", "Rescale Type", "LO", "1"),
+    E::new(0x00281055, "WindowCenterWidthExplanation", "Window Center & Width Explanation", "LO", "1-n"),
+    E::new(0x00281056, "VOILUTFunction", "VOILUT Function", "CS", "1"),
+    E::new(0x00281080, "GrayScale", "Gray Scale", "CS", "1").retired(),
+    E::new(0x00281090, "RecommendedViewingMode", "Recommended Viewing Mode", "CS", "1"),
+    E::new(0x00281100, "GrayLookupTableDescriptor", "Gray Lookup Table Descriptor", "US or SS", "3").retired(),
+    E::new(0x00281101, "RedPaletteColorLookupTableDescriptor", "Red Palette Color Lookup Table Descriptor", "US or SS", "3"),
+    E::new(0x00281102, "GreenPaletteColorLookupTableDescriptor", "Green Palette Color Lookup Table Descriptor", "US or SS", "3"),
+    E::new(0x00281103, "BluePaletteColorLookupTableDescriptor", "Blue Palette Color Lookup Table Descriptor", "US or SS", "3"),
+    E::new(0x00281104, "AlphaPaletteColorLookupTableDescriptor", "Alpha Palette Color Lookup Table Descriptor", "US", "3"),
+    E::new(0x00281111, "LargeRedPaletteColorLookupTableDescriptor", "Large Red Palette Color Lookup Table Descriptor", "US or SS", "4").retired(),
+    E::new(0x00281199, "PaletteColorLookupTableUID", "Palette Color Lookup Table UID", "UI", "1"),
+    E::new(0x00281200, "GrayLookupTableData", "Gray Lookup Table Data", "US or SS or OW", "1-n").retired(),
+    E::new(0x00281201, "RedPaletteColorLookupTableData", "Red Palette Color Lookup Table Data", "OW", "1"),
+    E::new(0x00281202, "GreenPaletteColorLookupTableData", "Green Palette Color Lookup Table Data", "OW", "1"),
+    E::new(0x00281203, "BluePaletteColorLookupTableData", "Blue Palette Color Lookup Table Data", "OW", "1"),
+    E::new(0x00281204, "AlphaPaletteColorLookupTableData", "Alpha Palette Color Lookup Table Data", "OW", "1"),
+    E::new(0x00281214, "LargePaletteColorLookupTableUID", "Large Palette Color Lookup Table UID", "UI", "1").retired(),
+    E::new(0x00281221, "SegmentedRedPaletteColorLookupTableData", "Segmented Red Palette Color Lookup Table Data", "OW", "1"),
+    E::new(0x00281222, "SegmentedGreenPaletteColorLookupTableData", "Segmented Green Palette Color Lookup Table Data", "OW", "1"),
+    E::new(0x00281223, "SegmentedBluePaletteColorLookupTableData", "Segmented Blue Palette Color Lookup Table Data", "OW", "1"),
+    E::new(0x00281224, "SegmentedAlphaPaletteColorLookupTableData", "Segmented Alpha Palette Color Lookup Table Data", "OW", "1"),
+    E::new(0x00281230, "StoredValueColorRangeSequence", "Stored Value Color Range Sequence", "SQ", "1"),
+    E::new(0x00281231, "MinimumStoredValueMapped", "Minimum Stored Value Mapped", "FD", "1"),
+    E::new(0x00281232, "MaximumStoredValueMapped", "Maximum Stored Value Mapped", "FD", "1"),
+    E::new(0x00281300, "BreastImplantPresent", "Breast Implant Present", "CS", "1"),
+    E::new(0x00281350, "PartialView", "Partial View", "CS", "1"),
+    E::new(0x00281351, "PartialViewDescription", "Partial View Description", "ST", "1"),
+    E::new(0x00281352, "PartialViewCodeSequence", "Partial View Code Sequence", "SQ", "1"),
+    E::new(0x0028135A, "SpatialLocationsPreserved", "Spatial Locations Preserved", "CS", "1"),
+    E::new(0x00281401, "DataFrameAssignmentSequence", "Data Frame Assignment Sequence", "SQ", "1"),
+    E::new(0x00281402, "DataPathAssignment", "Data Path Assignment", "CS", "1"),
+    E::new(0x00281403, "BitsMappedToColorLookupTable", "Bits Mapped to Color Lookup Table", "US", "1"),
+    E::new(0x00281404, "BlendingLUT1Sequence", "Blending LUT 1 Sequence", "SQ", "1"),
+    E::new(0x00281405, "BlendingLUT1TransferFunction", "Blending LUT 1 Transfer Function", "CS", "1"),
+    E::new(0x00281406, "BlendingWeightConstant", "Blending Weight Constant", "FD", "1"),
+    E::new(0x00281407, "BlendingLookupTableDescriptor", "Blending Lookup Table Descriptor", "US", "3"),
+    E::new(0x00281408, "BlendingLookupTableData", "Blending Lookup Table Data", "OW", "1"),
+    E::new(0x0028140B, "EnhancedPaletteColorLookupTableSequence", "Enhanced Palette Color Lookup Table Sequence", "SQ", "1"),
+    E::new(0x0028140C, "BlendingLUT2Sequence", "Blending LUT 2 Sequence", "SQ", "1"),
+    E::new(0x0028140D, "BlendingLUT2TransferFunction", "Blending LUT 2 Transfer Function", "CS", "1"),
+    E::new(0x0028140E, "DataPathID", "Data Path ID", "CS", "1"),
+    E::new(0x0028140F, "RGBLUTTransferFunction", "RGBLUT Transfer Function", "CS", "1"),
+    E::new(0x00281410, "AlphaLUTTransferFunction", "Alpha LUT Transfer Function", "CS", "1"),
+    E::new(0x00282000, "ICCProfile", "ICC Profile", "OB", "1"),
+    E::new(0x00282002, "ColorSpace", "Color Space", "CS", "1"),
+    E::new(0x00282110, "LossyImageCompression", "Lossy Image Compression", "CS", "1"),
+    E::new(0x00282112, "LossyImageCompressionRatio", "Lossy Image Compression Ratio", "DS", "1-n"),
+    E::new(0x00282114, "LossyImageCompressionMethod", "Lossy Image Compression Method", "CS", "1-n"),
+    E::new(0x00283000, "ModalityLUTSequence", "Modality LUT Sequence", "SQ", "1"),
+    E::new(0x00283002, "LUTDescriptor", "LUT Descriptor", "US or SS", "3"),
+    E::new(0x00283003, "LUTExplanation", "LUT Explanation", "LO", "1"),
+    E::new(0x00283004, "ModalityLUTType", "Modality LUT Type", "LO", "1"),
+    E::new(0x00283006, "LUTData", "LUT Data", "US or OW", "1-n"),
+    E::new(0x00283010, "VOILUTSequence", "VOI LUT Sequence", "SQ", "1"),
+    E::new(0x00284000, "ImagePresentationComments", "Image Presentation Comments", "LT", "1").retired(),
+    E::new(0x00285000, "BiPlaneAcquisitionSequence", "Bi-Plane Acquisition Sequence", "SQ", "1").retired(),
+    E::new(0x00286010, "RepresentativeFrameNumber", "Representative Frame Number", "US", "1"),
+    E::new(0x00286020, "FrameNumbersOfInterest", "Frame Numbers of Interest (FOI)", "US", "1-n"),
+    E::new(0x00286022, "FrameOfInterestDescription", "Frame of Interest Description", "LO", "1-n"),
+    E::new(0x00286023, "FrameOfInterestType", "Frame of Interest Type", "CS", "1-n"),
+    E::new(0x00286030, "MaskPointers", "Mask Pointer(s)", "US", "1-n").retired(),
+    E::new(0x00286040, "RWavePointer", "R Wave Pointer", "US", "1-n"),
+    E::new(0x00286100, "MaskSubtractionSequence", "Mask Subtraction Sequence", "SQ", "1"),
+    E::new(0x00286101, "MaskOperation", "Mask Operation", "CS", "1"),
+    E::new(0x00286102, "ApplicableFrameRange", "Applicable Frame Range", "US", "2-2n"),
+    E::new(0x00286110, "MaskFrameNumbers", "Mask Frame Numbers", "US", "1-n"),
+    E::new(0x00286112, "ContrastFrameAveraging", "Contrast Frame Averaging", "US", "1"),
+    E::new(0x00286114, "MaskSubPixelShift", "Mask Sub Pixel Shift", "FL", "2"),
+    E::new(0x00286120, "TIDOffset", "TID Offset", "SS", "1"),
+    E::new(0x00286190, "MaskOperationExplanation", "Mask Operation Explanation", "ST", "1"),
+    E::new(0x00287000, "EquipmentAdministratorSequence", "Equipment Administrator Sequence", "SQ", "1"),
+    E::new(0x00289001, "DataPointRows", "Data Point Rows", "UL", "1"),
+    E::new(0x00289002, "DataPointColumns", "Data Point Columns", "UL", "1"),
+    E::new(0x00289003, "SignalDomainColumns", "Signal Domain Columns", "CS", "1"),
+    E::new(0x00289099, "LargestMonochromePixelValue", "Largest Monochrome Pixel Value", "US", "1").retired(),
+    E::new(0x00289108, "DataRepresentation", "Data Representation", "CS", "1"),
+    E::new(0x00289110, "PixelMeasuresSequence", "Pixel Measures Sequence", "SQ", "1"),
+    E::new(0x00289132, "FrameVOILUTSequence", "Frame VOI LUT Sequence", "SQ", "1"),
+    E::new(0x00289145, "PixelValueTransformationSequence", "Pixel Value Transformation Sequence", "SQ", "1"),
+    E::new(0x00289411, "DisplayFilterPercentage", "Display Filter Percentage", "FL", "1"),
+    E::new(0x00289415, "FramePixelShiftSequence", "Frame Pixel Shift Sequence", "SQ", "1"),
+    E::new(0x00289416, "SubtractionItemID", "Subtraction Item ID", "US", "1"),
+    E::new(0x00289422, "PixelIntensityRelationshipLUTSequence", "Pixel Intensity Relationship LUT Sequence", "SQ", "1"),
+    E::new(0x00289443, "FramePixelDataPropertiesSequence", "Frame Pixel Data Properties Sequence", "SQ", "1"),
+    E::new(0x00289444, "GeometricalProperties", "Geometrical Properties", "CS", "1"),
+    E::new(0x00289445, "GeometricMaximumDistortion", "Geometric Maximum Distortion", "FL", "1"),
+    E::new(0x00289446, "ImageProcessingApplied", "Image Processing Applied", "CS", "1-n"),
+    E::new(0x00289454, "MaskSelectionMode", "Mask Selection Mode", "CS", "1"),
+    E::new(0x00289474, "LUTFunction", "LUT Function", "CS", "1"),
+    E::new(0x00289478, "MaskVisibilityPercentage", "Mask Visibility Percentage", "FL", "1"),
+    E::new(0x00289501, "PixelShiftSequence", "Pixel Shift Sequence", "SQ", "1"),
+    E::new(0x00289502, "RegionPixelShiftSequence", "Region Pixel Shift Sequence", "SQ", "1"),
+    E::new(0x00289503, "VerticesOfTheRegion", "Vertices of the Region", "SS", "2-2n"),
+    E::new(0x00289505, "MultiFramePresentationSequence", "Multi-frame Presentation Sequence", "SQ", "1"),
+    E::new(0x00289506, "PixelShiftFrameRange", "Pixel Shift Frame Range", "US", "2-2n"),
+    E::new(0x00289507, "LUTFrameRange", "LUT Frame Range", "US", "2-2n"),
+    E::new(0x00289520, "ImageToEquipmentMappingMatrix", "Image to Equipment Mapping Matrix", "DS", "16"),
+    E::new(0x00289537, "EquipmentCoordinateSystemIdentification", "Equipment Coordinate System Identification", "CS", "1"),
+    E::new(0x0032000A, "StudyStatusID", "Study Status ID", "CS", "1").retired(),
+    E::new(0x0032000C, "StudyPriorityID", "Study Priority ID", "CS", "1").retired(),
+    E::new(0x00320012, "StudyIDIssuer", "Study ID Issuer", "LO", "1").retired(),
+    E::new(0x00320032, "StudyVerifiedDate", "Study Verified Date", "DA", "1").retired(),
+    E::new(0x00320033, "StudyVerifiedTime", "Study Verified Time", "TM", "1").retired(),
+    E::new(0x00320034, "StudyReadDate", "Study Read Date", "DA", "1").retired(),
+    E::new(0x00320035, "StudyReadTime", "Study Read Time", "TM", "1").retired(),
+    E::new(0x00321000, "ScheduledStudyStartDate", "Scheduled Study Start Date", "DA", "1").retired(),
+    E::new(0x00321001, "ScheduledStudyStartTime", "Scheduled Study Start Time", "TM", "1").retired(),
+    E::new(0x00321010, "ScheduledStudyStopDate", "Scheduled Study Stop Date", "DA", "1").retired(),
+    E::new(0x00321011, "ScheduledStudyStopTime", "Scheduled Study Stop Time", "TM", "1").retired(),
+    E::new(0x00321020, "ScheduledStudyLocation", "Scheduled Study Location", "LO", "1").retired(),
+    E::new(0x00321021, "ScheduledStudyLocationAETitle", "Scheduled Study Location AE Title", "AE", "1-n").retired(),
+    E::new(0x00321030, "ReasonForStudy", "Reason for Study", "LO", "1").retired(),
+    E::new(0x00321031, "RequestingPhysicianIdentificationSequence", "Requesting Physician Identification Sequence", "SQ", "1"),
+    E::new(0x00321032, "RequestingPhysician", "Requesting Physician", "PN", "1"),
+    E::new(0x00321033, "RequestingService", "Requesting Service", "LO", "1"),
+    E::new(0x00321034, "RequestingServiceCodeSequence", "Requesting Service Code Sequence", "SQ", "1"),
+    E::new(0x00321040, "StudyArrivalDate", "Study Arrival Date", "DA", "1").retired(),
+    E::new(0x00321041, "StudyArrivalTime", "Study Arrival Time", "TM", "1").retired(),
+    E::new(0x00321050, "StudyCompletionDate", "Study Completion Date", "DA", "1").retired(),
+    E::new(0x00321051, "StudyCompletionTime", "Study Completion Time", "TM", "1").retired(),
+    E::new(0x00321055, "StudyComponentStatusID", "Study Component Status ID", "CS", "1").retired(),
+    E::new(0x00321060, "RequestedProcedureDescription", "Requested Procedure Description", "LO", "1"),
+    E::new(0x00321064, "RequestedProcedureCodeSequence", "Requested Procedure Code Sequence", "SQ", "1"),
+    E::new(0x00321066, "ReasonForVisit", "Reason for Visit", "UT", "1"),
+    E::new(0x00321067, "ReasonForVisitCodeSequence", "Reason for Visit Code Sequence", "SQ", "1"),
+    E::new(0x00321070, "RequestedContrastAgent", "Requested Contrast Agent", "LO", "1"),
+    E::new(0x00324000, "StudyComments", "Study Comments", "LT", "1").retired(),
+    E::new(0x00380004, "ReferencedPatientAliasSequence", "Referenced Patient Alias Sequence", "SQ", "1"),
+    E::new(0x00380008, "VisitStatusID", "Visit Status ID", "CS", "1"),
+    E::new(0x00380010, "AdmissionID", "Admission ID", "LO", "1"),
+    E::new(0x00380011, "IssuerOfAdmissionID", "Issuer of Admission ID", "LO", "1").retired(),
+    E::new(0x00380014, "IssuerOfAdmissionIDSequence", "Issuer of Admission ID Sequence", "SQ", "1"),
+    E::new(0x00380016, "RouteOfAdmissions", "Route of Admissions", "LO", "1"),
+    E::new(0x00380020, "AdmittingDate", "Admitting Date", "DA", "1"),
+    E::new(0x00380021, "AdmittingTime", "Admitting Time", "TM", "1"),
+    E::new(0x00380050, "SpecialNeeds", "Special Needs", "LO", "1"),
+    E::new(0x00380060, "ServiceEpisodeID", "Service Episode ID", "LO", "1"),
+    E::new(0x00380062, "ServiceEpisodeDescription", "Service Episode Description", "LO", "1"),
+    E::new(0x00380100, "PertinentDocumentsSequence", "Pertinent Documents Sequence", "SQ", "1"),
+    E::new(0x00380300, "CurrentPatientLocation", "Current Patient Location", "LO", "1"),
+    E::new(0x00380400, "PatientInstitutionResidence", "Patient's Institution Residence", "LO", "1"),
+    E::new(0x00380500, "PatientState", "Patient State", "LO", "1"),
+    E::new(0x00380502, "PatientClinicalTrialParticipationSequence", "Patient Clinical Trial Participation Sequence", "SQ", "1"),
+    E::new(0x00384000, "VisitComments", "Visit Comments", "LT", "1"),
+    E::new(0x003A0004, "WaveformOriginality", "Waveform Originality", "CS", "1"),
+    E::new(0x003A0005, "NumberOfWaveformChannels", "Number of Waveform Channels", "US", "1"),
+    E::new(0x003A0010, "NumberOfWaveformSamples", "Number of Waveform Samples", "UL", "1"),
+    E::new(0x003A001A, "SamplingFrequency", "Sampling Frequency", "DS", "1"),
+    E::new(0x003A0020, "MultiplexGroupLabel", "Multiplex Group Label", "SH", "1"),
+    E::new(0x003A0200, "ChannelDefinitionSequence", "Channel Definition Sequence", "SQ", "1"),
+    E::new(0x003A0202, "WaveformChannelNumber", "Waveform Channel Number", "IS", "1"),
+    E::new(0x003A0203, "ChannelLabel", "Channel Label", "SH", "1"),
+    E::new(0x003A0205, "ChannelStatus", "Channel Status", "CS", "1-n"),
+    E::new(0x003A0208, "ChannelSourceSequence", "Channel Source Sequence", "SQ", "1"),
+    E::new(0x003A0209, "ChannelSourceModifiersSequence", "Channel Source Modifiers Sequence", "SQ", "1"),
+    E::new(0x003A020A, "SourceWaveformSequence", "Source Waveform Sequence", "SQ", "1"),
+    E::new(0x003A020C, "ChannelDerivationDescription", "Channel Derivation Description", "LO", "1"),
+    E::new(0x003A0210, "ChannelSensitivity", "Channel Sensitivity", "DS", "1"),
+    E::new(0x003A0211, "ChannelSensitivityUnitsSequence", "Channel Sensitivity Units Sequence", "SQ", "1"),
+    E::new(0x003A0212, "ChannelSensitivityCorrectionFactor", "Channel Sensitivity Correction Factor", "DS", "1"),
+    E::new(0x003A0213, "ChannelBaseline", "Channel Baseline", "DS", "1"),
+    E::new(0x003A0214, "ChannelTimeSkew", "Channel Time Skew", "DS", "1"),
+    E::new(0x003A0215, "ChannelSampleSkew", "Channel Sample Skew", "DS", "1"),
+    E::new(0x003A0218, "ChannelOffset", "Channel Offset", "DS", "1"),
+    E::new(0x003A021A, "WaveformBitsStored", "Waveform Bits Stored", "US", "1"),
+    E::new(0x003A0220, "FilterLowFrequency", "Filter Low Frequency", "DS", "1"),
+    E::new(0x003A0221, "FilterHighFrequency", "Filter High Frequency", "DS", "1"),
+    E::new(0x003A0222, "NotchFilterFrequency", "Notch Filter Frequency", "DS", "1"),
+    E::new(0x003A0223, "NotchFilterBandwidth", "Notch Filter Bandwidth", "DS", "1"),
+    E::new(0x00400001, "ScheduledStationAETitle", "Scheduled Station AE Title", "AE", "1-n"),
+    E::new(0x00400002, "ScheduledProcedureStepStartDate", "Scheduled Procedure Step Start Date", "DA", "1"),
+    E::new(0x00400003, "ScheduledProcedureStepStartTime", "Scheduled Procedure Step Start Time", "TM", "1"),
+    E::new(0x00400004, "ScheduledProcedureStepEndDate", "Scheduled Procedure Step End Date", "DA", "1"),
+    E::new(0x00400005, "ScheduledProcedureStepEndTime", "Scheduled Procedure Step End Time", "TM", "1"),
+    E::new(0x00400006, "ScheduledPerformingPhysicianName", "Scheduled Performing Physician's Name", "PN", "1"),
+    E::new(0x00400007, "ScheduledProcedureStepDescription", "Scheduled Procedure Step Description", "LO", "1"),
+    E::new(0x00400008, "ScheduledProtocolCodeSequence", "Scheduled Protocol Code Sequence", "SQ", "1"),
+    E::new(0x00400009, "ScheduledProcedureStepID", "Scheduled Procedure Step ID", "SH", "1"),
+    E::new(0x0040000A, "StageCodeSequence", "Stage Code Sequence", "SQ", "1"),
+    E::new(0x0040000B, "ScheduledPerformingPhysicianIdentificationSequence", "Scheduled Performing Physician Identification Sequence", "SQ", "1"),
+    E::new(0x00400010, "ScheduledStationName", "Scheduled Station Name", "SH", "1-n"),
+    E::new(0x00400011, "ScheduledProcedureStepLocation", "Scheduled Procedure Step Location", "SH", "1"),
+    E::new(0x00400012, "PreMedication", "Pre Medication", "LO", "1"),
+    E::new(0x00400020, "ScheduledProcedureStepStatus", "Scheduled Procedure Step Status", "CS", "1"),
+    E::new(0x00400026, "OrderPlacerIdentifierSequence", "Order Placer Identifier Sequence", "SQ", "1"),
+    E::new(0x00400027, "OrderFillerIdentifierSequence", "Order Filler Identifier Sequence", "SQ", "1"),
+    E::new(0x00400031, "LocalNamespaceEntityID", "Local Namespace Entity ID", "UT", "1"),
+    E::new(0x00400032, "UniversalEntityID", "Universal Entity ID", "UT", "1"),
+    E::new(0x00400033, "UniversalEntityIDType", "Universal Entity ID Type", "CS", "1"),
+    E::new(0x00400035, "IdentifierTypeCode", "Identifier Type Code", "CS", "1"),
+    E::new(0x00400036, "AssigningFacilitySequence", "Assigning Facility Sequence", "SQ", "1"),
+    E::new(0x00400039, "AssigningJurisdictionCodeSequence", "Assigning Jurisdiction Code Sequence", "SQ", "1"),
+    E::new(0x0040003A, "AssigningAgencyOrDepartmentCodeSequence", "Assigning Agency or Department Code Sequence", "SQ", "1"),
+    E::new(0x00400100, "ScheduledProcedureStepSequence", "Scheduled Procedure Step Sequence", "SQ", "1"),
+    E::new(0x00400241, "PerformedStationAETitle", "Performed Station AE Title", "AE", "1"),
+    E::new(0x00400242, "PerformedStationName", "Performed Station Name", "SH", "1"),
+    E::new(0x00400243, "PerformedLocation", "Performed Location", "SH", "1"),
+    E::new(0x00400244, "PerformedProcedureStepStartDate", "Performed Procedure Step Start Date", "DA", "1"),
+    E::new(0x00400245, "PerformedProcedureStepStartTime", "Performed Procedure Step Start Time", "TM", "1"),
+    E::new(0x00400250, "PerformedProcedureStepEndDate", "Performed Procedure Step End Date", "DA", "1"),
+    E::new(0x00400251, "PerformedProcedureStepEndTime", "Performed Procedure Step End Time", "TM", "1"),
+    E::new(0x00400252, "PerformedProcedureStepStatus", "Performed Procedure Step Status", "CS", "1"),
+    E::new(0x00400253, "PerformedProcedureStepID", "Performed Procedure Step ID", "SH", "1"),
+    E::new(0x00400254, "PerformedProcedureStepDescription", "Performed Procedure Step Description", "LO", "1"),
+    E::new(0x00400255, "PerformedProcedureTypeDescription", "Performed Procedure Type Description", "LO", "1"),
+    E::new(0x00400260, "PerformedProtocolCodeSequence", "Performed Protocol Code Sequence", "SQ", "1"),
+    E::new(0x00400270, "ScheduledStepAttributesSequence", "Scheduled Step Attributes Sequence", "SQ", "1"),
+    E::new(0x00400275, "RequestAttributesSequence", "Request Attributes Sequence", "SQ", "1"),
+    E::new(0x00400280, "CommentsOnThePerformedProcedureStep", "Comments on the Performed Procedure Step", "ST", "1"),
+    E::new(0x00400281, "PerformedProcedureStepDiscontinuationReasonCodeSequence", "Performed Procedure Step Discontinuation Reason Code Sequence", "SQ", "1"),
+    E::new(0x00400293, "QuantitySequence", "Quantity Sequence", "SQ", "1"),
+    E::new(0x00400294, "Quantity", "Quantity", "DS", "1"),
+    E::new(0x00400295, "MeasuringUnitsSequence", "Measuring Units Sequence", "SQ", "1"),
+    E::new(0x00400296, "BillingItemSequence", "Billing Item Sequence", "SQ", "1"),
+    E::new(0x00400300, "TotalTimeOfFluoroscopy", "Total Time of Fluoroscopy", "US", "1").retired(),
+    E::new(0x00400301, "TotalNumberOfExposures", "Total Number of Exposures", "US", "1").retired(),
+    E::new(0x00400302, "EntranceDose", "Entrance Dose", "US", "1"),
+    E::new(0x00400303, "ExposedArea", "Exposed Area", "US", "1-2"),
+    E::new(0x00400306, "DistanceSourceToEntrance", "Distance Source to Entrance", "DS", "1"),
+    E::new(0x00400307, "DistanceSourceToSupport", "Distance Source to Support", "DS", "1").retired(),
+    E::new(0x0040030E, "ExposureDoseSequence", "Exposure Dose Sequence", "SQ", "1"),
+    E::new(0x00400310, "CommentsOnRadiationDose", "Comments on Radiation Dose", "ST", "1"),
+    E::new(0x00400312, "XRayOutput", "X-Ray Output", "DS", "1"),
+    E::new(0x00400314, "HalfValueLayer", "Half Value Layer", "DS", "1"),
+    E::new(0x00400316, "OrganDose", "Organ Dose", "DS", "1"),
+    E::new(0x00400318, "OrganExposed", "Organ Exposed", "CS", "1"),
+    E::new(0x00400320, "BillingProcedureStepSequence", "Billing Procedure Step Sequence", "SQ", "1"),
+    E::new(0x00400321, "FilmConsumptionSequence", "Film Consumption Sequence", "SQ", "1"),
+    E::new(0x00400324, "BillingSuppliesAndDevicesSequence", "Billing Supplies and Devices Sequence", "SQ", "1"),
+    E::new(0x00400330, "ReferencedProcedureStepSequence", "Referenced Procedure Step Sequence", "SQ", "1").retired(),
+    E::new(0x00400340, "PerformedSeriesSequence", "Performed Series Sequence", "SQ", "1"),
+    E::new(0x00400400, "CommentsOnTheScheduledProcedureStep", "Comments on the Scheduled Procedure Step", "LT", "1"),
+    E::new(0x00400440, "ProtocolContextSequence", "Protocol Context Sequence", "SQ", "1"),
+    E::new(0x00400441, "ContentItemModifierSequence", "Content Item Modifier Sequence", "SQ", "1"),
+    E::new(0x00400500, "ScheduledSpecimenSequence", "Scheduled Specimen Sequence", "SQ", "1"),
+    E::new(0x00400512, "ContainerIdentifier", "Container Identifier", "LO", "1"),
+    E::new(0x00400513, "IssuerOfTheContainerIdentifierSequence", "Issuer of the Container Identifier Sequence", "SQ", "1"),
+    E::new(0x00400515, "AlternateContainerIdentifierSequence", "Alternate Container Identifier Sequence", "SQ", "1"),
+    E::new(0x00400518, "ContainerTypeCodeSequence", "Container Type Code Sequence", "SQ", "1"),
+    E::new(0x0040051A, "ContainerDescription", "Container Description", "LO", "1"),
+    E::new(0x00400520, "ContainerComponentSequence", "Container Component Sequence", "SQ", "1"),
+    E::new(0x00400551, "SpecimenIdentifier", "Specimen Identifier", "LO", "1"),
+    E::new(0x00400554, "SpecimenUID", "Specimen UID", "UI", "1"),
+    E::new(0x00400555, "AcquisitionContextSequence", "Acquisition Context Sequence", "SQ", "1"),
+    E::new(0x00400556, "AcquisitionContextDescription", "Acquisition Context Description", "ST", "1"),
+    E::new(0x00400560, "SpecimenDescriptionSequence", "Specimen Description Sequence", "SQ", "1"),
+    E::new(0x00400562, "IssuerOfTheSpecimenIdentifierSequence", "Issuer of the Specimen Identifier Sequence", "SQ", "1"),
+    E::new(0x0040059A, "SpecimenTypeCodeSequence", "Specimen Type Code Sequence", "SQ", "1"),
+    E::new(0x00400600, "SpecimenShortDescription", "Specimen Short Description", "LO", "1"),
+    E::new(0x00400602, "SpecimenDetailedDescription", "Specimen Detailed Description", "UT", "1"),
+    E::new(0x00400610, "SpecimenPreparationSequence", "Specimen Preparation Sequence", "SQ", "1"),
+    E::new(0x00400612, "SpecimenPreparationStepContentItemSequence", "Specimen Preparation Step Content Item Sequence", "SQ", "1"),
+    E::new(0x00400620, "SpecimenLocalizationContentItemSequence", "Specimen Localization Content Item Sequence", "SQ", "1"),
+    E::new(0x004006FA, "SlideIdentifier", "Slide Identifier", "LO", "1").retired(),
+    E::new(0x00400710, "WholeSlideMicroscopyImageFrameTypeSequence", "Whole Slide Microscopy Image Frame Type Sequence", "SQ", "1"),
+    E::new(0x0040071A, "ImageCenterPointCoordinatesSequence", "Image Center Point Coordinates Sequence", "SQ", "1"),
+    E::new(0x0040072A, "XOffsetInSlideCoordinateSystem", "X Offset in Slide Coordinate System", "DS", "1"),
+    E::new(0x0040073A, "YOffsetInSlideCoordinateSystem", "Y Offset in Slide Coordinate System", "DS", "1"),
+    E::new(0x0040074A, "ZOffsetInSlideCoordinateSystem", "Z Offset in Slide Coordinate System", "DS", "1"),
+    E::new(0x004008EA, "MeasurementUnitsCodeSequence", "Measurement Units Code Sequence", "SQ", "1"),
+    E::new(0x00401001, "RequestedProcedureID", "Requested Procedure ID", "SH", "1"),
+    E::new(0x00401002, "ReasonForTheRequestedProcedure", "Reason for the Requested Procedure", "LO", "1"),
+    E::new(0x00401003, "RequestedProcedurePriority", "Requested Procedure Priority", "SH", "1"),
+    E::new(0x00401004, "PatientTransportArrangements", "Patient Transport Arrangements", "LO", "1"),
+    E::new(0x00401005, "RequestedProcedureLocation", "Requested Procedure Location", "LO", "1"),
+    E::new(0x00401008, "ConfidentialityCode", "Confidentiality Code", "LO", "1"),
+    E::new(0x00401009, "ReportingPriority", "Reporting Priority", "SH", "1"),
+    E::new(0x0040100A, "ReasonForRequestedProcedureCodeSequence", "Reason for Requested Procedure Code Sequence", "SQ", "1"),
+    E::new(0x00401010, "NamesOfIntendedRecipientsOfResults", "Names of Intended Recipients of Results", "PN", "1-n"),
+    E::new(0x00401011, "IntendedRecipientsOfResultsIdentificationSequence", "Intended Recipients of Results Identification Sequence", "SQ", "1"),
+    E::new(0x00401012, "ReasonForPerformedProcedureCodeSequence", "Reason for Performed Procedure Code Sequence", "SQ", "1"),
+    E::new(0x00401101, "PersonIdentificationCodeSequence", "Person Identification Code Sequence", "SQ", "1"),
+    E::new(0x00401102, "PersonAddress", "Person's Address", "ST", "1"),
+    E::new(0x00401103, "PersonTelephoneNumbers", "Person's Telephone Numbers", "LO", "1-n"),
+    E::new(0x00401104, "PersonTelecomInformation", "Person's Telecom Information", "LT", "1"),
+    E::new(0x00401400, "RequestedProcedureComments", "Requested Procedure Comments", "LT", "1"),
+    E::new(0x00402001, "ReasonForTheImagingServiceRequest", "Reason for the Imaging Service Request", "LO", "1").retired(),
+    E::new(0x00402004, "IssueDateOfImagingServiceRequest", "Issue Date of Imaging Service Request", "DA", "1"),
+    E::new(0x00402005, "IssueTimeOfImagingServiceRequest", "Issue Time of Imaging Service Request", "TM", "1"),
+    E::new(0x00402008, "OrderEnteredBy", "Order Entered By", "PN", "1"),
+    E::new(0x00402009, "OrderEntererLocation", "Order Enterer's Location", "SH", "1"),
+    E::new(0x00402010, "OrderCallbackPhoneNumber", "Order Callback Phone Number", "SH", "1"),
+    E::new(0x00402011, "OrderCallbackTelecomInformation", "Order Callback Telecom Information", "LT", "1"),
+    E::new(0x00402016, "PlacerOrderNumberImagingServiceRequest", "Placer Order Number / Imaging Service Request", "LO", "1"),
+    E::new(0x00402017, "FillerOrderNumberImagingServiceRequest", "Filler Order Number / Imaging Service Request", "LO", "1"),
+    E::new(0x00402400, "ImagingServiceRequestComments", "Imaging Service Request Comments", "LT", "1"),
+    E::new(0x00403001, "ConfidentialityConstraintOnPatientDataDescription", "Confidentiality Constraint on Patient Data Description", "LO", "1"),
+    E::new(0x00408302, "EntranceDoseInmGy", "Entrance Dose in mGy", "DS", "1"),
+    E::new(0x00409092, "ParametricMapFrameTypeSequence", "Parametric Map Frame Type Sequence", "SQ", "1"),
+    E::new(0x00409094, "ReferencedImageRealWorldValueMappingSequence", "Referenced Image Real World Value Mapping Sequence", "SQ", "1"),
+    E::new(0x00409096, "RealWorldValueMappingSequence", "Real World Value Mapping Sequence", "SQ", "1"),
+    E::new(0x00409098, "PixelValueMappingCodeSequence", "Pixel Value Mapping Code Sequence", "SQ", "1"),
+    E::new(0x00409210, "LUTLabel", "LUT Label", "SH", "1"),
+    E::new(0x00409211, "RealWorldValueLastValueMapped", "Real World Value Last Value Mapped", "US or SS", "1"),
+    E::new(0x00409212, "RealWorldValueLUTData", "Real World Value LUT Data", "FD", "1-n"),
+    E::new(0x00409213, "DoubleFloatRealWorldValueLastValueMapped", "Double Float Real World Value Last Value Mapped", "FD", "1"),
+    E::new(0x00409214, "DoubleFloatRealWorldValueFirstValueMapped", "Double Float Real World Value First Value Mapped", "FD", "1"),
+    E::new(0x00409216, "RealWorldValueFirstValueMapped", "Real World Value First Value Mapped", "US or SS", "1"),
+    E::new(0x00409220, "QuantityDefinitionSequence", "Quantity Definition Sequence", "SQ", "1"),
+    E::new(0x00409224, "RealWorldValueIntercept", "Real World Value Intercept", "FD", "1"),
+    E::new(0x00409225, "RealWorldValueSlope", "Real World Value Slope", "FD", "1"),
+    E::new(0x0040A010, "RelationshipType", "Relationship Type", "CS", "1"),
+    E::new(0x0040A027, "VerifyingOrganization", "Verifying Organization", "LO", "1"),
+    E::new(0x0040A030, "VerificationDateTime", "Verification DateTime", "DT", "1"),
+    E::new(0x0040A032, "ObservationDateTime", "Observation DateTime", "DT", "1"),
+    E::new(0x0040A040, "ValueType", "Value Type", "CS", "1"),
+    E::new(0x0040A043, "ConceptNameCodeSequence", "Concept Name Code Sequence", "SQ", "1"),
+    E::new(0x0040A050, "ContinuityOfContent", "Continuity Of Content", "CS", "1"),
+    E::new(0x0040A073, "VerifyingObserverSequence", "Verifying Observer Sequence", "SQ", "1"),
+    E::new(0x0040A075, "VerifyingObserverName", "Verifying Observer Name", "PN", "1"),
+    E::new(0x0040A078, "AuthorObserverSequence", "Author Observer Sequence", "SQ", "1"),
+    E::new(0x0040A07A, "ParticipantSequence", "Participant Sequence", "SQ", "1"),
+    E::new(0x0040A07C, "CustodialOrganizationSequence", "Custodial Organization Sequence", "SQ", "1"),
+    E::new(0x0040A080, "ParticipationType", "Participation Type", "CS", "1"),
+    E::new(0x0040A082, "ParticipationDateTime", "Participation Date Time", "DT", "1"),
+    E::new(0x0040A084, "ObserverType", "Observer Type", "CS", "1"),
+    E::new(0x0040A088, "VerifyingObserverIdentificationCodeSequence", "Verifying Observer Identification Code Sequence", "SQ", "1"),
+    E::new(0x0040A090, "EquivalentCDADocumentSequence", "Equivalent CDA Document Sequence", "SQ", "1").retired(),
+    E::new(0x0040A0B0, "ReferencedWaveformChannels", "Referenced Waveform Channels", "US", "2-2n"),
+    E::new(0x0040A120, "DateTime", "DateTime", "DT", "1"),
+    E::new(0x0040A121, "Date", "Date", "DA", "1"),
+    E::new(0x0040A122, "Time", "Time", "TM", "1"),
+    E::new(0x0040A123, "PersonName", "Person Name", "PN", "1"),
+    E::new(0x0040A124, "UID", "UID", "UI", "1"),
+    E::new(0x0040A130, "TemporalRangeType", "Temporal Range Type", "CS", "1"),
+    E::new(0x0040A132, "ReferencedSamplePositions", "Referenced Sample Positions", "UL", "1-n"),
+    E::new(0x0040A136, "ReferencedFrameNumbers", "Referenced Frame Numbers", "US", "1-n"),
+    E::new(0x0040A138, "ReferencedTimeOffsets", "Referenced Time Offsets", "DS", "1-n"),
+    E::new(0x0040A13A, "ReferencedDateTime", "Referenced Date Time", "DT", "1-n"),
+    E::new(0x0040A160, "TextValue", "Text Value", "UT", "1"),
+    E::new(0x0040A161, "FloatingPointValue", "Floating Point Value", "FD", "1-n"),
+    E::new(0x0040A162, "RationalNumeratorValue", "Rational Numerator Value", "SL", "1-n"),
+    E::new(0x0040A163, "RationalDenominatorValue", "Rational Denominator Value", "UL", "1-n"),
+    E::new(0x0040A168, "ConceptCodeSequence", "Concept Code Sequence", "SQ", "1"),
+    E::new(0x0040A170, "PurposeOfReferenceCodeSequence", "Purpose of Reference Code Sequence", "SQ", "1"),
+    E::new(0x0040A180, "AnnotationGroupNumber", "Annotation Group Number", "US", "1"),
+    E::new(0x0040A195, "ModifierCodeSequence", "Modifier Code Sequence", "SQ", "1"),
+    E::new(0x0040A300, "MeasuredValueSequence", "Measured Value Sequence", "SQ", "1"),
+    E::new(0x0040A301, "NumericValueQualifierCodeSequence", "Numeric Value Qualifier Code Sequence", "SQ", "1"),
+    E::new(0x0040A30A, "NumericValue", "Numeric Value", "DS", "1-n"),
+    E::new(0x0040A360, "PredecessorDocumentsSequence", "Predecessor Documents Sequence", "SQ", "1"),
+    E::new(0x0040A370, "ReferencedRequestSequence", "Referenced Request Sequence", "SQ", "1"),
+    E::new(0x0040A372, "PerformedProcedureCodeSequence", "Performed Procedure Code Sequence", "SQ", "1"),
+    E::new(0x0040A375, "CurrentRequestedProcedureEvidenceSequence", "Current Requested Procedure Evidence Sequence", "SQ", "1"),
+    E::new(0x0040A385, "PertinentOtherEvidenceSequence", "Pertinent Other Evidence Sequence", "SQ", "1"),
+    E::new(0x0040A390, "HL7StructuredDocumentReferenceSequence", "HL7 Structured Document Reference Sequence", "SQ", "1"),
+    E::new(0x0040A491, "CompletionFlag", "Completion Flag", "CS", "1"),
+    E::new(0x0040A493, "VerificationFlag", "Verification Flag", "CS", "1"),
+    E::new(0x0040A496, "PreliminaryFlag", "Preliminary Flag", "CS", "1"),
+    E::new(0x0040A504, "ContentTemplateSequence", "Content Template Sequence", "SQ", "1"),
+    E::new(0x0040A525, "IdenticalDocumentsSequence", "Identical Documents Sequence", "SQ", "1"),
+    E::new(0x0040A730, "ContentSequence", "Content Sequence", "SQ", "1"),
+    E::new(0x0040B020, "WaveformAnnotationSequence", "Waveform Annotation Sequence", "SQ", "1"),
+    E::new(0x0040DB00, "TemplateIdentifier", "Template Identifier", "CS", "1"),
+    E::new(0x0040DB06, "TemplateVersion", "Template Version", "DT", "1").retired(),
+    E::new(0x0040DB07, "TemplateLocalVersion", "Template Local Version", "DT", "1").retired(),
+    E::new(0x0040DB73, "ReferencedContentItemIdentifier", "Referenced Content Item Identifier", "UL", "1-n"),
+    E::new(0x0040E001, "HL7InstanceIdentifier", "HL7 Instance Identifier", "ST", "1"),
+    E::new(0x0040E004, "HL7DocumentEffectiveTime", "HL7 Document Effective Time", "DT", "1"),
+    E::new(0x0040E006, "HL7DocumentTypeCodeSequence", "HL7 Document Type Code Sequence", "SQ", "1"),
+    E::new(0x0040E008, "DocumentClassCodeSequence", "Document Class Code Sequence", "SQ", "1"),
+    E::new(0x0040E010, "RetrieveURI", "Retrieve URI", "UR", "1"),
+    E::new(0x0040E011, "RetrieveLocationUID", "Retrieve Location UID", "UI", "1"),
+    E::new(0x0040E020, "TypeOfInstances", "Type of Instances", "CS", "1"),
+    E::new(0x0040E021, "DICOMRetrievalSequence", "DICOM Retrieval Sequence", "SQ", "1"),
+    E::new(0x0040E022, "DICOMMediaRetrievalSequence", "DICOM Media Retrieval Sequence", "SQ", "1"),
+    E::new(0x0040E023, "WADORetrievalSequence", "WADO Retrieval Sequence", "SQ", "1"),
+    E::new(0x0040E024, "XDSRetrievalSequence", "XDS Retrieval Sequence", "SQ", "1"),
+    E::new(0x0040E025, "WADORSRetrievalSequence", "WADORS Retrieval Sequence", "SQ", "1"),
+    E::new(0x0040E030, "RepositoryUniqueID", "Repository Unique ID", "UI", "1"),
+    E::new(0x0040E031, "HomeCommunityID", "Home Community ID", "UI", "1"),
+    E::new(0x00540011, "NumberOfEnergyWindows", "Number of Energy Windows", "US", "1"),
+    E::new(0x00540012, "EnergyWindowInformationSequence", "Energy Window Information Sequence", "SQ", "1"),
+    E::new(0x00540013, "EnergyWindowRangeSequence", "Energy Window Range Sequence", "SQ", "1"),
+    E::new(0x00540014, "EnergyWindowLowerLimit", "Energy Window Lower Limit", "DS", "1"),
+    E::new(0x00540015, "EnergyWindowUpperLimit", "Energy Window Upper Limit", "DS", "1"),
+    E::new(0x00540016, "RadiopharmaceuticalInformationSequence", "Radiopharmaceutical Information Sequence", "SQ", "1"),
+    E::new(0x00540017, "ResidualSyringeCounts", "Residual Syringe Counts", "IS", "1"),
+    E::new(0x00540018, "EnergyWindowName", "Energy Window Name", "SH", "1"),
+    E::new(0x00540020, "DetectorVector", "Detector Vector", "US", "1-n"),
+    E::new(0x00540021, "NumberOfDetectors", "Number of Detectors", "US", "1"),
+    E::new(0x00540022, "DetectorInformationSequence", "Detector Information Sequence", "SQ", "1"),
+    E::new(0x00540030, "PhaseVector", "Phase Vector", "US", "1-n"),
+    E::new(0x00540031, "NumberOfPhases", "Number of Phases", "US", "1"),
+    E::new(0x00540032, "PhaseInformationSequence", "Phase Information Sequence", "SQ", "1"),
+    E::new(0x00540033, "NumberOfFramesInPhase", "Number of Frames in Phase", "US", "1"),
+    E::new(0x00540036, "PhaseDelay", "Phase Delay", "IS", "1"),
+    E::new(0x00540038, "PauseBetweenFrames", "Pause Between Frames", "IS", "1"),
+    E::new(0x00540039, "PhaseDescription", "Phase Description", "CS", "1"),
+    E::new(0x00540050, "RotationVector", "Rotation Vector", "US", "1-n"),
+    E::new(0x00540051, "NumberOfRotations", "Number of Rotations", "US", "1"),
+    E::new(0x00540052, "RotationInformationSequence", "Rotation Information Sequence", "SQ", "1"),
+    E::new(0x00540053, "NumberOfFramesInRotation", "Number of Frames in Rotation", "US", "1"),
+    E::new(0x00540060, "RRIntervalVector", "R-R Interval Vector", "US", "1-n"),
+    E::new(0x00540061, "NumberOfRRIntervals", "Number of R-R Intervals", "US", "1"),
+    E::new(0x00540062, "GatedInformationSequence", "Gated Information Sequence", "SQ", "1"),
+    E::new(0x00540063, "DataInformationSequence", "Data Information Sequence", "SQ", "1"),
+    E::new(0x00540070, "TimeSlotVector", "Time Slot Vector", "US", "1-n"),
+    E::new(0x00540071, "NumberOfTimeSlots", "Number of Time Slots", "US", "1"),
+    E::new(0x00540072, "TimeSlotInformationSequence", "Time Slot Information Sequence", "SQ", "1"),
+    E::new(0x00540073, "TimeSlotTime", "Time Slot Time", "DS", "1"),
+    E::new(0x00540080, "SliceVector", "Slice Vector", "US", "1-n"),
+    E::new(0x00540081, "NumberOfSlices", "Number of Slices", "US", "1"),
+    E::new(0x00540090, "AngularViewVector", "Angular View Vector", "US", "1-n"),
+    E::new(0x00540100, "TimeSliceVector", "Time Slice Vector", "US", "1-n"),
+    E::new(0x00540101, "NumberOfTimeSlices", "Number of Time Slices", "US", "1"),
+    E::new(0x00540200, "StartAngle", "Start Angle", "DS", "1"),
+    E::new(0x00540202, "TypeOfDetectorMotion", "Type of Detector Motion", "CS", "1"),
+    E::new(0x00540210, "TriggerVector", "Trigger Vector", "IS", "1-n"),
+    E::new(0x00540211, "NumberOfTriggersInPhase", "Number of Triggers in Phase", "US", "1"),
+    E::new(0x00540220, "ViewCodeSequence", "View Code Sequence", "SQ", "1"),
+    E::new(0x00540222, "ViewModifierCodeSequence", "View Modifier Code Sequence", "SQ", "1"),
+    E::new(0x00540300, "RadionuclideCodeSequence", "Radionuclide Code Sequence", "SQ", "1"),
+    E::new(0x00540302, "AdministrationRouteCodeSequence", "Administration Route Code Sequence", "SQ", "1"),
+    E::new(0x00540304, "RadiopharmaceuticalCodeSequence", "Radiopharmaceutical Code Sequence", "SQ", "1"),
+    E::new(0x00540306, "CalibrationDataSequence", "Calibration Data Sequence", "SQ", "1"),
+    E::new(0x00540308, "EnergyWindowNumber", "Energy Window Number", "US", "1"),
+    E::new(0x00540400, "ImageID", "Image ID", "SH", "1"),
+    E::new(0x00540410, "PatientOrientationCodeSequence", "Patient Orientation Code Sequence", "SQ", "1"),
+    E::new(0x00540412, "PatientOrientationModifierCodeSequence", "Patient Orientation Modifier Code Sequence", "SQ", "1"),
+    E::new(0x00540414, "PatientGantryRelationshipCodeSequence", "Patient Gantry Relationship Code Sequence", "SQ", "1"),
+    E::new(0x00540500, "SliceProgressionDirection", "Slice Progression Direction", "CS", "1"),
+    E::new(0x00540501, "ScanProgressionDirection", "Scan Progression Direction", "CS", "1"),
+    E::new(0x00541000, "SeriesType", "Series Type", "CS", "2"),
+    E::new(0x00541001, "Units", "Units", "CS", "1"),
+    E::new(0x00541002, "CountsSource", "Counts Source", "CS", "1"),
+    E::new(0x00541004, "ReprojectionMethod", "Reprojection Method", "CS", "1"),
+    E::new(0x00541006, "SUVType", "SUV Type", "CS", "1"),
+    E::new(0x00541100, "RandomsCorrectionMethod", "Randoms Correction Method", "CS", "1"),
+    E::new(0x00541101, "AttenuationCorrectionMethod", "Attenuation Correction Method", "LO", "1"),
+    E::new(0x00541102, "DecayCorrection", "Decay Correction", "CS", "1"),
+    E::new(0x00541103, "ReconstructionMethod", "Reconstruction Method", "LO", "1"),
+    E::new(0x00541104, "DetectorLinesOfResponseUsed", "Detector Lines of Response Used", "LO", "1"),
+    E::new(0x00541105, "ScatterCorrectionMethod", "Scatter Correction Method", "LO", "1"),
+    E::new(0x00541200, "AxialAcceptance", "Axial Acceptance", "DS", "1"),
+    E::new(0x00541201, "AxialMash", "Axial Mash", "IS", "2"),
+    E::new(0x00541202, "TransverseMash", "Transverse Mash", "IS", "1"),
+    E::new(0x00541203, "DetectorElementSize", "Detector Element Size", "DS", "2"),
+    E::new(0x00541210, "CoincidenceWindowWidth", "Coincidence Window Width", "DS", "1"),
+    E::new(0x00541220, "SecondaryCountsType", "Secondary Counts Type", "CS", "1-n"),
+    E::new(0x00541300, "FrameReferenceTime", "Frame Reference Time", "DS", "1"),
+    E::new(0x00541310, "PrimaryPromptsCountsAccumulated", "Primary (Prompts) Counts Accumulated", "IS", "1"),
+    E::new(0x00541311, "SecondaryCountsAccumulated", "Secondary Counts Accumulated", "IS", "1-n"),
+    E::new(0x00541320, "SliceSensitivityFactor", "Slice Sensitivity Factor", "DS", "1"),
+    E::new(0x00541321, "DecayFactor", "Decay Factor", "DS", "1"),
+    E::new(0x00541322, "DoseCalibrationFactor", "Dose Calibration Factor", "DS", "1"),
+    E::new(0x00541323, "ScatterFractionFactor", "Scatter Fraction Factor", "DS", "1"),
+    E::new(0x00541324, "DeadTimeFactor", "Dead Time Factor", "DS", "1"),
+    E::new(0x00541330, "ImageIndex", "Image Index", "US", "1"),
+    E::new(0x00541400, "CountsIncluded", "Counts Included", "CS", "1-n").retired(),
+    E::new(0x00541401, "DeadTimeCorrectionFlag", "Dead Time Correction Flag", "CS", "1").retired(),
+    E::new(0x00620001, "SegmentationType", "Segmentation Type", "CS", "1"),
+    E::new(0x00620002, "SegmentSequence", "Segment Sequence", "SQ", "1"),
+    E::new(0x00620003, "SegmentedPropertyCategoryCodeSequence", "Segmented Property Category Code Sequence", "SQ", "1"),
+    E::new(0x00620004, "SegmentNumber", "Segment Number", "US", "1"),
+    E::new(0x00620005, "SegmentLabel", "Segment Label", "LO", "1"),
+    E::new(0x00620006, "SegmentDescription", "Segment Description", "ST", "1"),
+    E::new(0x00620008, "SegmentAlgorithmType", "Segment Algorithm Type", "CS", "1"),
+    E::new(0x00620009, "SegmentAlgorithmName", "Segment Algorithm Name", "LO", "1-n"),
+    E::new(0x0062000A, "SegmentIdentificationSequence", "Segment Identification Sequence", "SQ", "1"),
+    E::new(0x0062000B, "ReferencedSegmentNumber", "Referenced Segment Number", "US", "1-n"),
+    E::new(0x0062000C, "RecommendedDisplayGrayscaleValue", "Recommended Display Grayscale Value", "US", "1"),
+    E::new(0x0062000D, "RecommendedDisplayCIELabValue", "Recommended Display CIE Lab Value", "US", "3"),
+    E::new(0x0062000E, "MaximumFractionalValue", "Maximum Fractional Value", "US", "1"),
+    E::new(0x0062000F, "SegmentedPropertyTypeCodeSequence", "Segmented Property Type Code Sequence", "SQ", "1"),
+    E::new(0x00620010, "SegmentationFractionalType", "Segmentation Fractional Type", "CS", "1"),
+    E::new(0x00620011, "SegmentedPropertyTypeModifierCodeSequence", "Segmented Property Type Modifier Code Sequence", "SQ", "1"),
+    E::new(0x00620012, "UsedSegmentsSequence", "Used Segments Sequence", "SQ", "1"),
+    E::new(0x00620013, "SegmentsOverlap", "Segments Overlap", "CS", "1"),
+    E::new(0x00620020, "TrackingID", "Tracking ID", "UT", "1"),
+    E::new(0x00620021, "TrackingUID", "Tracking UID", "UI", "1"),
+    E::new(0x00700001, "GraphicAnnotationSequence", "Graphic Annotation Sequence", "SQ", "1"),
+    E::new(0x00700002, "GraphicLayer", "Graphic Layer", "CS", "1"),
+    E::new(0x00700003, "BoundingBoxAnnotationUnits", "Bounding Box Annotation Units", "CS", "1"),
+    E::new(0x00700004, "AnchorPointAnnotationUnits", "Anchor Point Annotation Units", "CS", "1"),
+    E::new(0x00700005, "GraphicAnnotationUnits", "Graphic Annotation Units", "CS", "1"),
+    E::new(0x00700006, "UnformattedTextValue", "Unformatted Text Value", "ST", "1"),
+    E::new(0x00700008, "TextObjectSequence", "Text Object Sequence", "SQ", "1"),
+    E::new(0x00700009, "GraphicObjectSequence", "Graphic Object Sequence", "SQ", "1"),
+    E::new(0x00700010, "BoundingBoxTopLeftHandCorner", "Bounding Box Top Left Hand Corner", "FL", "2"),
+    E::new(0x00700011, "BoundingBoxBottomRightHandCorner", "Bounding Box Bottom Right Hand Corner", "FL", "2"),
+    E::new(0x00700012, "BoundingBoxTextHorizontalJustification", "Bounding Box Text Horizontal Justification", "CS", "1"),
+    E::new(0x00700014, "AnchorPoint", "Anchor Point", "FL", "2"),
+    E::new(0x00700015, "AnchorPointVisibility", "Anchor Point Visibility", "CS", "1"),
+    E::new(0x00700020, "GraphicDimensions", "Graphic Dimensions", "US", "1"),
+    E::new(0x00700021, "NumberOfGraphicPoints", "Number of Graphic Points", "US", "1"),
+    E::new(0x00700022, "GraphicData", "Graphic Data", "FL", "2-n"),
+    E::new(0x00700023, "GraphicType", "Graphic Type", "CS", "1"),
+    E::new(0x00700024, "GraphicFilled", "Graphic Filled", "CS", "1"),
+    E::new(0x00700041, "ImageHorizontalFlip", "Image Horizontal Flip", "CS", "1"),
+    E::new(0x00700042, "ImageRotation", "Image Rotation", "US", "1"),
+    E::new(0x00700052, "DisplayedAreaTopLeftHandCorner", "Displayed Area Top Left Hand Corner", "SL", "2"),
+    E::new(0x00700053, "DisplayedAreaBottomRightHandCorner", "Displayed Area Bottom Right Hand Corner", "SL", "2"),
+    E::new(0x0070005A, "DisplayedAreaSelectionSequence", "Displayed Area Selection Sequence", "SQ", "1"),
+    E::new(0x00700060, "GraphicLayerSequence", "Graphic Layer Sequence", "SQ", "1"),
+    E::new(0x00700062, "GraphicLayerOrder", "Graphic Layer Order", "IS", "1"),
+    E::new(0x00700066, "GraphicLayerRecommendedDisplayGrayscaleValue", "Graphic Layer Recommended Display Grayscale Value", "US", "1"),
+    E::new(0x00700068, "GraphicLayerDescription", "Graphic Layer Description", "LO", "1"),
+    E::new(0x00700080, "ContentLabel", "Content Label", "CS", "1"),
+    E::new(0x00700081, "ContentDescription", "Content Description", "LO", "1"),
+    E::new(0x00700082, "PresentationCreationDate", "Presentation Creation Date", "DA", "1"),
+    E::new(0x00700083, "PresentationCreationTime", "Presentation Creation Time", "TM", "1"),
+    E::new(0x00700084, "ContentCreatorName", "Content Creator's Name", "PN", "1"),
+    E::new(0x00700086, "ContentCreatorIdentificationCodeSequence", "Content Creator's Identification Code Sequence", "SQ", "1"),
+    E::new(0x00700087, "AlternateContentDescriptionSequence", "Alternate Content Description Sequence", "SQ", "1"),
+    E::new(0x00700100, "PresentationSizeMode", "Presentation Size Mode", "CS", "1"),
+    E::new(0x00700101, "PresentationPixelSpacing", "Presentation Pixel Spacing", "DS", "2"),
+    E::new(0x00700102, "PresentationPixelAspectRatio", "Presentation Pixel Aspect Ratio", "IS", "2"),
+    E::new(0x00700103, "PresentationPixelMagnificationRatio", "Presentation Pixel Magnification Ratio", "FL", "1"),
+    E::new(0x00700207, "GraphicGroupLabel", "Graphic Group Label", "LO", "1"),
+    E::new(0x00700208, "GraphicGroupDescription", "Graphic Group Description", "ST", "1"),
+    E::new(0x00700209, "CompoundGraphicSequence", "Compound Graphic Sequence", "SQ", "1"),
+    E::new(0x00700226, "CompoundGraphicInstanceID", "Compound Graphic Instance ID", "UL", "1"),
+    E::new(0x00700227, "FontName", "Font Name", "LO", "1"),
+    E::new(0x00700228, "FontNameType", "Font Name Type", "CS", "1"),
+    E::new(0x00700229, "CSSFontName", "CSS Font Name", "LO", "1"),
+    E::new(0x00700230, "RotationAngle", "Rotation Angle", "FD", "1"),
+    E::new(0x00700231, "TextStyleSequence", "Text Style Sequence", "SQ", "1"),
+    E::new(0x00700232, "LineStyleSequence", "Line Style Sequence", "SQ", "1"),
+    E::new(0x00700233, "FillStyleSequence", "Fill Style Sequence", "SQ", "1"),
+    E::new(0x00700234, "GraphicGroupSequence", "Graphic Group Sequence", "SQ", "1"),
+    E::new(0x00700241, "TextColorCIELabValue", "Text Color CIE Lab Value", "US", "3"),
+    E::new(0x00700242, "HorizontalAlignment", "Horizontal Alignment", "CS", "1"),
+    E::new(0x00700243, "VerticalAlignment", "Vertical Alignment", "CS", "1"),
+    E::new(0x00700244, "ShadowStyle", "Shadow Style", "CS", "1"),
+    E::new(0x00700245, "ShadowOffsetX", "Shadow Offset X", "FL", "1"),
+    E::new(0x00700246, "ShadowOffsetY", "Shadow Offset Y", "FL", "1"),
+    E::new(0x00700247, "ShadowColorCIELabValue", "Shadow Color CIE Lab Value", "US", "3"),
+    E::new(0x00700248, "Underlined", "Underlined", "CS", "1"),
+    E::new(0x00700249, "Bold", "Bold", "CS", "1"),
+    E::new(0x00700250, "Italic", "Italic", "CS", "1"),
+    E::new(0x00700251, "PatternOnColorCIELabValue", "Pattern on Color CIE Lab Value", "US", "3"),
+    E::new(0x00700252, "PatternOffColorCIELabValue", "Pattern Off Color CIE Lab Value", "US", "3"),
+    E::new(0x00700253, "LineThickness", "Line Thickness", "FL", "1"),
+    E::new(0x00700254, "LineDashingStyle", "Line Dashing Style", "CS", "1"),
+    E::new(0x00700255, "LinePattern", "Line Pattern", "UL", "1"),
+    E::new(0x00700256, "FillPattern", "Fill Pattern", "OB", "1"),
+    E::new(0x00700257, "FillMode", "Fill Mode", "CS", "1"),
+    E::new(0x00700258, "ShadowOpacity", "Shadow Opacity", "FL", "1"),
+    E::new(0x00700261, "GapLength", "Gap Length", "FL", "1"),
+    E::new(0x00700262, "DiameterOfVisibility", "Diameter of Visibility", "FL", "1"),
+    E::new(0x00700273, "RotationPoint", "Rotation Point", "FL", "2"),
+    E::new(0x00700274, "TickAlignment", "Tick Alignment", "CS", "1"),
+    E::new(0x00700278, "ShowTickLabel", "Show Tick Label", "CS", "1"),
+    E::new(0x00700279, "TickLabelAlignment", "Tick Label Alignment", "CS", "1"),
+    E::new(0x00700282, "CompoundGraphicUnits", "Compound Graphic Units", "CS", "1"),
+    E::new(0x00700284, "PatternOnOpacity", "Pattern on Opacity", "FL", "1"),
+    E::new(0x00700285, "PatternOffOpacity", "Pattern Off Opacity", "FL", "1"),
+    E::new(0x00700287, "MajorTicksSequence", "Major Ticks Sequence", "SQ", "1"),
+    E::new(0x00700288, "TickPosition", "Tick Position", "FL", "1"),
+    E::new(0x00700289, "TickLabel", "Tick Label", "SH", "1"),
+    E::new(0x00700294, "CompoundGraphicType", "Compound Graphic Type", "CS", "1"),
+    E::new(0x00700295, "GraphicGroupID", "Graphic Group ID", "UL", "1"),
+    E::new(0x00700306, "ShapeType", "Shape Type", "CS", "1"),
+    E::new(0x00700308, "RegistrationSequence", "Registration Sequence", "SQ", "1"),
+    E::new(0x00700309, "MatrixRegistrationSequence", "Matrix Registration Sequence", "SQ", "1"),
+    E::new(0x0070030A, "MatrixSequence", "Matrix Sequence", "SQ", "1"),
+    E::new(0x0070030C, "FrameOfReferenceTransformationMatrixType", "Frame of Reference Transformation Matrix Type", "CS", "1"),
+    E::new(0x0070030D, "RegistrationTypeCodeSequence", "Registration Type Code Sequence", "SQ", "1"),
+    E::new(0x0070030F, "FiducialDescription", "Fiducial Description", "ST", "1"),
+    E::new(0x00700310, "FiducialIdentifier", "Fiducial Identifier", "SH", "1"),
+    E::new(0x00700311, "FiducialIdentifierCodeSequence", "Fiducial Identifier Code Sequence", "SQ", "1"),
+    E::new(0x00700312, "ContourUncertaintyRadius", "Contour Uncertainty Radius", "FD", "1"),
+    E::new(0x00700314, "UsedFiducialsSequence", "Used Fiducials Sequence", "SQ", "1"),
+    E::new(0x00700318, "GraphicCoordinatesDataSequence", "Graphic Coordinates Data Sequence", "SQ", "1"),
+    E::new(0x0070031A, "FiducialUID", "Fiducial UID", "UI", "1"),
+    E::new(0x0070031C, "FiducialSetSequence", "Fiducial Set Sequence", "SQ", "1"),
+    E::new(0x0070031E, "FiducialSequence", "Fiducial Sequence", "SQ", "1"),
+    E::new(0x00700401, "GraphicLayerRecommendedDisplayCIELabValue", "Graphic Layer Recommended Display CIE Lab Value", "US", "3"),
+    E::new(0x00700402, "BlendingSequence", "Blending Sequence", "SQ", "1"),
+    E::new(0x00700403, "RelativeOpacity", "Relative Opacity", "FL", "1"),
+    E::new(0x00700404, "ReferencedSpatialRegistrationSequence", "Referenced Spatial Registration Sequence", "SQ", "1"),
+    E::new(0x00700405, "BlendingPosition", "Blending Position", "CS", "1"),
+    E::new(0x00880130, "StorageMediaFileSetID", "Storage Media File-set ID", "SH", "1"),
+    E::new(0x00880140, "StorageMediaFileSetUID", "Storage Media File-set UID", "UI", "1"),
+    E::new(0x00880200, "IconImageSequence", "Icon Image Sequence", "SQ", "1"),
+    E::new(0x00880904, "TopicTitle", "Topic Title", "LO", "1").retired(),
+    E::new(0x00880906, "TopicSubject", "Topic Subject", "ST", "1").retired(),
+    E::new(0x00880910, "TopicAuthor", "Topic Author", "LO", "1").retired(),
+    E::new(0x00880912, "TopicKeywords", "Topic Keywords", "LO", "1-32").retired(),
+    E::new(0x20500010, "PresentationLUTSequence", "Presentation LUT Sequence", "SQ", "1"),
+    E::new(0x20500020, "PresentationLUTShape", "Presentation LUT Shape", "CS", "1"),
+    E::new(0x20500500, "ReferencedPresentationLUTSequence", "Referenced Presentation LUT Sequence", "SQ", "1"),
+    E::new(0x30020002, "RTImageLabel", "RT Image Label", "SH", "1"),
+    E::new(0x30020003, "RTImageName", "RT Image Name", "LO", "1"),
+    E::new(0x30020004, "RTImageDescription", "RT Image Description", "ST", "1"),
+    E::new(0x3002000A, "ReportedValuesOrigin", "Reported Values Origin", "CS", "1"),
+    E::new(0x3002000C, "RTImagePlane", "RT Image Plane", "CS", "1"),
+    E::new(0x3002000D, "XRayImageReceptorTranslation", "X-Ray Image Receptor Translation", "DS", "3"),
+    E::new(0x3002000E, "XRayImageReceptorAngle", "X-Ray Image Receptor Angle", "DS", "1"),
+    E::new(0x30020011, "ImagePlanePixelSpacing", "Image Plane Pixel Spacing", "DS", "2"),
+    E::new(0x30020012, "RTImagePosition", "RT Image Position", "DS", "2"),
+    E::new(0x30020020, "RadiationMachineName", "Radiation Machine Name", "SH", "1"),
+    E::new(0x30020022, "RadiationMachineSAD", "Radiation Machine SAD", "DS", "1"),
+    E::new(0x30020026, "RTImageSID", "RT Image SID", "DS", "1"),
+    E::new(0x30020029, "FractionNumber", "Fraction Number", "IS", "1"),
+    E::new(0x30020030, "ExposureSequence", "Exposure Sequence", "SQ", "1"),
+    E::new(0x30020032, "MetersetExposure", "Meterset Exposure", "DS", "1"),
+    E::new(0x30040001, "DVHType", "DVH Type", "CS", "1"),
+    E::new(0x30040002, "DoseUnits", "Dose Units", "CS", "1"),
+    E::new(0x30040004, "DoseType", "Dose Type", "CS", "1"),
+    E::new(0x30040005, "SpatialTransformOfDose", "Spatial Transform of Dose", "CS", "1"),
+    E::new(0x30040006, "DoseComment", "Dose Comment", "LO", "1"),
+    E::new(0x30040008, "NormalizationPoint", "Normalization Point", "DS", "3"),
+    E::new(0x3004000A, "DoseSummationType", "Dose Summation Type", "CS", "1"),
+    E::new(0x3004000C, "GridFrameOffsetVector", "Grid Frame Offset Vector", "DS", "2-n"),
+    E::new(0x3004000E, "DoseGridScaling", "Dose Grid Scaling", "DS", "1"),
+    E::new(0x30040010, "RTDoseROISequence", "RT Dose ROI Sequence", "SQ", "1").retired(),
+    E::new(0x30040012, "DoseValue", "Dose Value", "DS", "1").retired(),
+    E::new(0x30040014, "TissueHeterogeneityCorrection", "Tissue Heterogeneity Correction", "CS", "1-3"),
+    E::new(0x30040040, "DVHNormalizationPoint", "DVH Normalization Point", "DS", "3"),
+    E::new(0x30040042, "DVHNormalizationDoseValue", "DVH Normalization Dose Value", "DS", "1"),
+    E::new(0x30040050, "DVHSequence", "DVH Sequence", "SQ", "1"),
+    E::new(0x30040052, "DVHDoseScaling", "DVH Dose Scaling", "DS", "1"),
+    E::new(0x30040054, "DVHVolumeUnits", "DVH Volume Units", "CS", "1"),
+    E::new(0x30040056, "DVHNumberOfBins", "DVH Number of Bins", "IS", "1"),
+    E::new(0x30040058, "DVHData", "DVH Data", "DS", "2-2n"),
+    E::new(0x30040060, "DVHReferencedROISequence", "DVH Referenced ROI Sequence", "SQ", "1"),
+    E::new(0x30040062, "DVHROIContributionType", "DVHROI Contribution Type", "CS", "1"),
+    E::new(0x30040070, "DVHMinimumDose", "DVH Minimum Dose", "DS", "1"),
+    E::new(0x30040072, "DVHMaximumDose", "DVH Maximum Dose", "DS", "1"),
+    E::new(0x30040074, "DVHMeanDose", "DVH Mean Dose", "DS", "1"),
+    E::new(0x30060002, "StructureSetLabel", "Structure Set Label", "SH", "1"),
+    E::new(0x30060004, "StructureSetName", "Structure Set Name", "LO", "1"),
+    E::new(0x30060006, "StructureSetDescription", "Structure Set Description", "ST", "1"),
+    E::new(0x30060008, "StructureSetDate", "Structure Set Date", "DA", "1"),
+    E::new(0x30060009, "StructureSetTime", "Structure Set Time", "TM", "1"),
+    E::new(0x30060010, "ReferencedFrameOfReferenceSequence", "Referenced Frame of Reference Sequence", "SQ", "1"),
+    E::new(0x30060012, "RTReferencedStudySequence", "RT Referenced Study Sequence", "SQ", "1"),
+    E::new(0x30060014, "RTReferencedSeriesSequence", "RT Referenced Series Sequence", "SQ", "1"),
+    E::new(0x30060016, "ContourImageSequence", "Contour Image Sequence", "SQ", "1"),
+    E::new(0x30060020, "StructureSetROISequence", "Structure Set ROI Sequence", "SQ", "1"),
+    E::new(0x30060022, "ROINumber", "ROI Number", "IS", "1"),
+    E::new(0x30060024, "ReferencedFrameOfReferenceUID", "Referenced Frame of Reference UID", "UI", "1"),
+    E::new(0x30060026, "ROIName", "ROI Name", "LO", "1"),
+    E::new(0x30060028, "ROIDescription", "ROI Description", "ST", "1"),
+    E::new(0x3006002A, "ROIDisplayColor", "ROI Display Color", "IS", "3"),
+    E::new(0x3006002C, "ROIVolume", "ROI Volume", "DS", "1"),
+    E::new(0x30060036, "ROIGenerationAlgorithm", "ROI Generation Algorithm", "CS", "1"),
+    E::new(0x30060038, "ROIGenerationDescription", "ROI Generation Description", "LO", "1"),
+    E::new(0x30060039, "ROIContourSequence", "ROI Contour Sequence", "SQ", "1"),
+    E::new(0x30060040, "ContourSequence", "Contour Sequence", "SQ", "1"),
+    E::new(0x30060042, "ContourGeometricType", "Contour Geometric Type", "CS", "1"),
+    E::new(0x30060044, "ContourSlabThickness", "Contour Slab Thickness", "DS", "1"),
+    E::new(0x30060045, "ContourOffsetVector", "Contour Offset Vector", "DS", "3"),
+    E::new(0x30060046, "NumberOfContourPoints", "Number of Contour Points", "IS", "1"),
+    E::new(0x30060048, "ContourNumber", "Contour Number", "IS", "1"),
+    E::new(0x30060049, "AttachedContours", "Attached Contours", "IS", "1-n"),
+    E::new(0x30060050, "ContourData", "Contour Data", "DS", "3-3n"),
+    E::new(0x30060080, "RTROIObservationsSequence", "RT ROI Observations Sequence", "SQ", "1"),
+    E::new(0x30060082, "ObservationNumber", "Observation Number", "IS", "1"),
+    E::new(0x30060084, "ReferencedROINumber", "Referenced ROI Number", "IS", "1"),
+    E::new(0x30060085, "ROIObservationLabel", "ROI Observation Label", "SH", "1"),
+    E::new(0x30060086, "RTROIIdentificationCodeSequence", "RTROI Identification Code Sequence", "SQ", "1"),
+    E::new(0x30060088, "ROIObservationDescription", "ROI Observation Description", "ST", "1"),
+    E::new(0x300600A4, "RTROIInterpretedType", "RT ROI Interpreted Type", "CS", "1"),
+    E::new(0x300600A6, "ROIInterpreter", "ROI Interpreter", "PN", "1"),
+    E::new(0x300600B0, "ROIPhysicalPropertiesSequence", "ROI Physical Properties Sequence", "SQ", "1"),
+    E::new(0x300600B2, "ROIPhysicalProperty", "ROI Physical Property", "CS", "1"),
+    E::new(0x300600B4, "ROIPhysicalPropertyValue", "ROI Physical Property Value", "DS", "1"),
+    E::new(0x300600C0, "FrameOfReferenceRelationshipSequence", "Frame of Reference Relationship Sequence", "SQ", "1").retired(),
+    E::new(0x300600C2, "RelatedFrameOfReferenceUID", "Related Frame of Reference UID", "UI", "1").retired(),
+    E::new(0x300600C4, "FrameOfReferenceTransformationType", "Frame of Reference Transformation Type", "CS", "1").retired(),
+    E::new(0x300600C6, "FrameOfReferenceTransformationMatrix", "Frame of Reference Transformation Matrix", "DS", "16"),
+    E::new(0x300600C8, "FrameOfReferenceTransformationComment", "Frame of Reference Transformation Comment", "LO", "1"),
+    E::new(0x300A0002, "RTPlanLabel", "RT Plan Label", "SH", "1"),
+    E::new(0x300A0003, "RTPlanName", "RT Plan Name", "LO", "1"),
+    E::new(0x300A0004, "RTPlanDescription", "RT Plan Description", "ST", "1"),
+    E::new(0x300A0006, "RTPlanDate", "RT Plan Date", "DA", "1"),
+    E::new(0x300A0007, "RTPlanTime", "RT Plan Time", "TM", "1"),
+    E::new(0x300A000A, "PlanIntent", "Plan Intent", "CS", "1"),
+    E::new(0x300A000C, "RTPlanGeometry", "RT Plan Geometry", "CS", "1"),
+    E::new(0x300A0010, "DoseReferenceSequence", "Dose Reference Sequence", "SQ", "1"),
+    E::new(0x300A0012, "DoseReferenceNumber", "Dose Reference Number", "IS", "1"),
+    E::new(0x300A0013, "DoseReferenceUID", "Dose Reference UID", "UI", "1"),
+    E::new(0x300A0014, "DoseReferenceStructureType", "Dose Reference Structure Type", "CS", "1"),
+    E::new(0x300A0016, "DoseReferenceDescription", "Dose Reference Description", "LO", "1"),
+    E::new(0x300A0018, "DoseReferencePointCoordinates", "Dose Reference Point Coordinates", "DS", "3"),
+    E::new(0x300A0020, "DoseReferenceType", "Dose Reference Type", "CS", "1"),
+    E::new(0x300A0023, "DeliveryMaximumDose", "Delivery Maximum Dose", "DS", "1"),
+    E::new(0x300A0026, "TargetPrescriptionDose", "Target Prescription Dose", "DS", "1"),
+    E::new(0x300A0040, "ToleranceTableSequence", "Tolerance Table Sequence", "SQ", "1"),
+    E::new(0x300A0070, "FractionGroupSequence", "Fraction Group Sequence", "SQ", "1"),
+    E::new(0x300A0071, "FractionGroupNumber", "Fraction Group Number", "IS", "1"),
+    E::new(0x300A0078, "NumberOfFractionsPlanned", "Number of Fractions Planned", "IS", "1"),
+    E::new(0x300A0080, "NumberOfBeams", "Number of Beams", "IS", "1"),
+    E::new(0x300A0082, "BeamDoseSpecificationPoint", "Beam Dose Specification Point", "DS", "3"),
+    E::new(0x300A0084, "BeamDose", "Beam Dose", "DS", "1"),
+    E::new(0x300A0086, "BeamMeterset", "Beam Meterset", "DS", "1"),
+    E::new(0x300A00A0, "NumberOfBrachyApplicationSetups", "Number of Brachy Application Setups", "IS", "1"),
+    E::new(0x300A00B0, "BeamSequence", "Beam Sequence", "SQ", "1"),
+    E::new(0x300A00B2, "TreatmentMachineName", "Treatment Machine Name", "SH", "1"),
+    E::new(0x300A00B3, "PrimaryDosimeterUnit", "Primary Dosimeter Unit", "CS", "1"),
+    E::new(0x300A00B4, "SourceAxisDistance", "Source Axis Distance", "DS", "1"),
+    E::new(0x300A00B6, "BeamLimitingDeviceSequence", "Beam Limiting Device Sequence", "SQ", "1"),
+    E::new(0x300A00B8, "RTBeamLimitingDeviceType", "RT Beam Limiting Device Type", "CS", "1"),
+    E::new(0x300A00BC, "NumberOfLeafJawPairs", "Number of Leaf/Jaw Pairs", "IS", "1"),
+    E::new(0x300A00BE, "LeafPositionBoundaries", "Leaf Position Boundaries", "DS", "3-n"),
+    E::new(0x300A00C0, "BeamNumber", "Beam Number", "IS", "1"),
+    E::new(0x300A00C2, "BeamName", "Beam Name", "LO", "1"),
+    E::new(0x300A00C3, "BeamDescription", "Beam Description", "ST", "1"),
+    E::new(0x300A00C4, "BeamType", "Beam Type", "CS", "1"),
+    E::new(0x300A00C6, "RadiationType", "Radiation Type", "CS", "1"),
+    E::new(0x300A00CE, "TreatmentDeliveryType", "Treatment Delivery Type", "CS", "1"),
+    E::new(0x300A00D0, "NumberOfWedges", "Number of Wedges", "IS", "1"),
+    E::new(0x300A00E0, "NumberOfCompensators", "Number of Compensators", "IS", "1"),
+    E::new(0x300A00ED, "NumberOfBoli", "Number of Boli", "IS", "1"),
+    E::new(0x300A00F0, "NumberOfBlocks", "Number of Blocks", "IS", "1"),
+    E::new(0x300A010E, "FinalCumulativeMetersetWeight", "Final Cumulative Meterset Weight", "DS", "1"),
+    E::new(0x300A0110, "NumberOfControlPoints", "Number of Control Points", "IS", "1"),
+    E::new(0x300A0111, "ControlPointSequence", "Control Point Sequence", "SQ", "1"),
+    E::new(0x300A0112, "ControlPointIndex", "Control Point Index", "IS", "1"),
+    E::new(0x300A0114, "NominalBeamEnergy", "Nominal Beam Energy", "DS", "1"),
+    E::new(0x300A0115, "DoseRateSet", "Dose Rate Set", "DS", "1"),
+    E::new(0x300A011A, "BeamLimitingDevicePositionSequence", "Beam Limiting Device Position Sequence", "SQ", "1"),
+    E::new(0x300A011C, "LeafJawPositions", "Leaf/Jaw Positions", "DS", "2-2n"),
+    E::new(0x300A011E, "GantryAngle", "Gantry Angle", "DS", "1"),
+    E::new(0x300A011F, "GantryRotationDirection", "Gantry Rotation Direction", "CS", "1"),
+    E::new(0x300A0120, "BeamLimitingDeviceAngle", "Beam Limiting Device Angle", "DS", "1"),
+    E::new(0x300A0121, "BeamLimitingDeviceRotationDirection", "Beam Limiting Device Rotation Direction", "CS", "1"),
+    E::new(0x300A0122, "PatientSupportAngle", "Patient Support Angle", "DS", "1"),
+    E::new(0x300A0123, "PatientSupportRotationDirection", "Patient Support Rotation Direction", "CS", "1"),
+    E::new(0x300A012C, "IsocenterPosition", "Isocenter Position", "DS", "3"),
+    E::new(0x300A0130, "SourceToSurfaceDistance", "Source to Surface Distance", "DS", "1"),
+    E::new(0x300A0134, "CumulativeMetersetWeight", "Cumulative Meterset Weight", "DS", "1"),
+    E::new(0x300A0180, "PatientSetupSequence", "Patient Setup Sequence", "SQ", "1"),
+    E::new(0x300A0182, "PatientSetupNumber", "Patient Setup Number", "IS", "1"),
+    E::new(0x300C0002, "ReferencedRTPlanSequence", "Referenced RT Plan Sequence", "SQ", "1"),
+    E::new(0x300C0004, "ReferencedBeamSequence", "Referenced Beam Sequence", "SQ", "1"),
+    E::new(0x300C0006, "ReferencedBeamNumber", "Referenced Beam Number", "IS", "1"),
+    E::new(0x300C0020, "ReferencedFractionGroupSequence", "Referenced Fraction Group Sequence", "SQ", "1"),
+    E::new(0x300C0022, "ReferencedFractionGroupNumber", "Referenced Fraction Group Number", "IS", "1"),
+    E::new(0x300C0060, "ReferencedStructureSetSequence", "Referenced Structure Set Sequence", "SQ", "1"),
+    E::new(0x300C006A, "ReferencedPatientSetupNumber", "Referenced Patient Setup Number", "IS", "1"),
+    E::new(0x300C0080, "ReferencedDoseSequence", "Referenced Dose Sequence", "SQ", "1"),
+    E::new(0x300E0002, "ApprovalStatus", "Approval Status", "CS", "1"),
+    E::new(0x300E0004, "ReviewDate", "Review Date", "DA", "1"),
+    E::new(0x300E0005, "ReviewTime", "Review Time", "TM", "1"),
+    E::new(0x300E0008, "ReviewerName", "Reviewer Name", "PN", "1"),
+    E::new(0x52009229, "SharedFunctionalGroupsSequence", "Shared Functional Groups Sequence", "SQ", "1"),
+    E::new(0x52009230, "PerFrameFunctionalGroupsSequence", "Per Frame Functional Groups Sequence", "SQ", "1"),
+    E::new(0x54000100, "WaveformSequence", "Waveform Sequence", "SQ", "1"),
+    E::new(0x54000110, "ChannelMinimumValue", "Channel Minimum Value", "OB or OW", "1"),
+    E::new(0x54000112, "ChannelMaximumValue", "Channel Maximum Value", "OB or OW", "1"),
+    E::new(0x54001004, "WaveformBitsAllocated", "Waveform Bits Allocated", "US", "1"),
+    E::new(0x54001006, "WaveformSampleInterpretation", "Waveform Sample Interpretation", "CS", "1"),
+    E::new(0x5400100A, "WaveformPaddingValue", "Waveform Padding Value", "OB or OW", "1"),
+    E::new(0x54001010, "WaveformData", "Waveform Data", "OB or OW", "1"),
+    E::new(0x7FE00001, "ExtendedOffsetTable", "Extended Offset Table", "OV", "1"),
+    E::new(0x7FE00002, "ExtendedOffsetTableLengths", "Extended Offset Table Lengths", "OV", "1"),
+    E::new(0x7FE00008, "FloatPixelData", "Float Pixel Data", "OF", "1"),
+    E::new(0x7FE00009, "DoubleFloatPixelData", "Double Float Pixel Data", "OD", "1"),
+    E::new(0x7FE00010, "PixelData", "Pixel Data", "OB or OW", "1"),
+    E::new(0x7FE00020, "CoefficientsSDVN", "Coefficients SDVN", "OW", "1").retired(),
+    E::new(0x7FE00030, "CoefficientsSDHN", "Coefficients SDHN", "OW", "1").retired(),
+    E::new(0x7FE00040, "CoefficientsSDDN", "Coefficients SDDN", "OW", "1").retired(),
+    E::new(0xFFFAFFFA, "DigitalSignaturesSequence", "Digital Signatures Sequence", "SQ", "1"),
+    E::new(0xFFFCFFFC, "DataSetTrailingPadding", "Data Set Trailing Padding", "OB", "1"),
+];
+
+/// Elements of repeating groups, e.g. (60xx,3000), sorted by tag.
+#[rustfmt::skip]
+pub(super) static REPEATING: &[E] = &[
+    E::new(0x50000005, "CurveDimensions", "Curve Dimensions", "US", "1").repeating(0xFF00FFFF).retired(),
+    E::new(0x50000010, "NumberOfPoints", "Number of Points", "US", "1").repeating(0xFF00FFFF).retired(),
+    E::new(0x50000020, "TypeOfData", "Type of Data", "CS", "1").repeating(0xFF00FFFF).retired(),
+    E::new(0x50000022, "CurveDescription", "Curve Description", "LO", "1").repeating(0xFF00FFFF).retired(),
+    E::new(0x50000030, "AxisUnits", "Axis Units", "SH", "1-n").repeating(0xFF00FFFF).retired(),
+    E::new(0x50000103, "DataValueRepresentation", "Data Value Representation", "US", "1").repeating(0xFF00FFFF).retired(),
+    E::new(0x50000104, "MinimumCoordinateValue", "Minimum Coordinate Value", "US", "1-n").repeating(0xFF00FFFF).retired(),
+    E::new(0x50000105, "MaximumCoordinateValue", "Maximum Coordinate Value", "US", "1-n").repeating(0xFF00FFFF).retired(),
+    E::new(0x50000106, "CurveRange", "Curve Range", "SH", "1-n").repeating(0xFF00FFFF).retired(),
+    E::new(0x50000110, "CurveDataDescriptor", "Curve Data Descriptor", "US", "1-n").repeating(0xFF00FFFF).retired(),
+    E::new(0x50000112, "CoordinateStartValue", "Coordinate Start Value", "US", "1-n").repeating(0xFF00FFFF).retired(),
+    E::new(0x50000114, "CoordinateStepValue", "Coordinate Step Value", "US", "1-n").repeating(0xFF00FFFF).retired(),
+    E::new(0x50001001, "CurveActivationLayer", "Curve Activation Layer", "CS", "1").repeating(0xFF00FFFF).retired(),
+    E::new(0x50002000, "AudioType", "Audio Type", "US", "1").repeating(0xFF00FFFF).retired(),
+    E::new(0x50002002, "AudioSampleFormat", "Audio Sample Format", "US", "1").repeating(0xFF00FFFF).retired(),
+    E::new(0x50002004, "NumberOfChannels", "Number of Channels", "US", "1").repeating(0xFF00FFFF).retired(),
+    E::new(0x50002006, "NumberOfSamples", "Number of Samples", "UL", "1").repeating(0xFF00FFFF).retired(),
+    E::new(0x50002008, "SampleRate", "Sample Rate", "UL", "1").repeating(0xFF00FFFF).retired(),
+    E::new(0x5000200A, "TotalTime", "Total Time", "UL", "1").repeating(0xFF00FFFF).retired(),
+    E::new(0x5000200C, "AudioSampleData", "Audio Sample Data", "OW or OB", "1").repeating(0xFF00FFFF).retired(),
+    E::new(0x5000200E, "AudioComments", "Audio Comments", "LT", "1").repeating(0xFF00FFFF).retired(),
+    E::new(0x50002500, "CurveLabel", "Curve Label", "LO", "1").repeating(0xFF00FFFF).retired(),
+    E::new(0x50002600, "CurveReferencedOverlaySequence", "Curve Referenced Overlay Sequence", "SQ", "1").repeating(0xFF00FFFF).retired(),
+    E::new(0x50002610, "CurveReferencedOverlayGroup", "Curve Referenced Overlay Group", "US", "1").repeating(0xFF00FFFF).retired(),
+    E::new(0x50003000, "CurveData", "Curve Data", "OB or OW", "1").repeating(0xFF00FFFF).retired(),
+    E::new(0x60000010, "OverlayRows", "Overlay Rows", "US", "1").repeating(0xFF00FFFF),
+    E::new(0x60000011, "OverlayColumns", "Overlay Columns", "US", "1").repeating(0xFF00FFFF),
+    E::new(0x60000012, "OverlayPlanes", "Overlay Planes", "US", "1").repeating(0xFF00FFFF).retired(),
+    E::new(0x60000015, "NumberOfFramesInOverlay", "Number of Frames in Overlay", "IS", "1").repeating(0xFF00FFFF),
+    E::new(0x60000022, "OverlayDescription", "Overlay Description", "LO", "1").repeating(0xFF00FFFF),
+    E::new(0x60000040, "OverlayType", "Overlay Type", "CS", "1").repeating(0xFF00FFFF),
+    E::new(0x60000045, "OverlaySubtype", "Overlay Subtype", "LO", "1").repeating(0xFF00FFFF),
+    E::new(0x60000050, "OverlayOrigin", "Overlay Origin", "SS", "2").repeating(0xFF00FFFF),
+    E::new(0x60000051, "ImageFrameOrigin", "Image Frame Origin", "US", "1").repeating(0xFF00FFFF),
+    E::new(0x60000052, "OverlayPlaneOrigin", "Overlay Plane Origin", "US", "1").repeating(0xFF00FFFF).retired(),
+    E::new(0x60000060, "OverlayCompressionCode", "Overlay Compression Code", "CS", "1").repeating(0xFF00FFFF).retired(),
+    E::new(0x60000100, "OverlayBitsAllocated", "Overlay Bits Allocated", "US", "1").repeating(0xFF00FFFF),
+    E::new(0x60000102, "OverlayBitPosition", "Overlay Bit Position", "US", "1").repeating(0xFF00FFFF),
+    E::new(0x60000110, "OverlayFormat", "Overlay Format", "CS", "1").repeating(0xFF00FFFF).retired(),
+    E::new(0x60000200, "OverlayLocation", "Overlay Location", "US", "1").repeating(0xFF00FFFF).retired(),
+    E::new(0x60001001, "OverlayActivationLayer", "Overlay Activation Layer", "CS", "1").repeating(0xFF00FFFF),
+    E::new(0x60001100, "OverlayDescriptorGray", "Overlay Descriptor Gray", "US", "1").repeating(0xFF00FFFF).retired(),
+    E::new(0x60001301, "ROIArea", "ROI Area", "IS", "1").repeating(0xFF00FFFF),
+    E::new(0x60001302, "ROIMean", "ROI Mean", "DS", "1").repeating(0xFF00FFFF),
+    E::new(0x60001303, "ROIStandardDeviation", "ROI Standard Deviation", "DS", "1").repeating(0xFF00FFFF),
+    E::new(0x60001500, "OverlayLabel", "Overlay Label", "LO", "1").repeating(0xFF00FFFF),
+    E::new(0x60003000, "OverlayData", "Overlay Data", "OB or OW", "1").repeating(0xFF00FFFF),
+    E::new(0x60004000, "OverlayComments", "Overlay Comments", "LT", "1").repeating(0xFF00FFFF).retired(),
+    E::new(0x7F000010, "VariablePixelData", "Variable Pixel Data", "OB or OW", "1").repeating(0xFF00FFFF).retired(),
+];
+
+/// Keywords sorted for binary search, with the tag they stand for.
+#[rustfmt::skip]
+pub(super) static KEYWORDS: &[(&str, u32)] = &[
+    ("AccessionNumber", 0x00080050),
+    ("AcquiredImageAreaDoseProduct", 0x00189473),
+    ("AcquisitionContextDescription", 0x00400556),
+    ("AcquisitionContextSequence", 0x00400555),
+    ("AcquisitionContrast", 0x00089209),
+    ("AcquisitionDate", 0x00080022),
+    ("AcquisitionDateTime", 0x0008002A),
+    ("AcquisitionDeviceProcessingCode", 0x00181401),
+    ("AcquisitionDeviceProcessingDescription", 0x00181400),
+    ("AcquisitionDuration", 0x00189073),
+    ("AcquisitionIndex", 0x00209518),
+    ("AcquisitionMatrix", 0x00181310),
+    ("AcquisitionNumber", 0x00200012),
+    ("AcquisitionProtocolDescription", 0x00189424),
+    ("AcquisitionProtocolName", 0x00189423),
+    ("AcquisitionStartCondition", 0x00180073),
+    ("AcquisitionStartConditionData", 0x00180074),
+    ("AcquisitionTerminationCondition", 0x00180071),
+    ("AcquisitionTerminationConditionData", 0x00180075),
+    ("AcquisitionTime", 0x00080032),
+    ("AcquisitionTimeSynchronized", 0x00181800),
+    ("AcquisitionType", 0x00189302),
+    ("AcquisitionsInSeries", 0x00201001),
+    ("AcquisitionsInStudy", 0x00201004),
+    ("ActualCardiacTriggerDelayTime", 0x00209252),
+    ("ActualCardiacTriggerTimePriorToRPeak", 0x00209155),
+    ("ActualFrameDuration", 0x00181242),
+    ("ActualRespiratoryTriggerDelayTime", 0x00209257),
+    ("AdditionalDrugSequence", 0x0018002A),
+    ("AdditionalPatientHistory", 0x001021B0),
+    ("AdministrationRouteCodeSequence", 0x00540302),
+    ("AdmissionID", 0x00380010),
+    ("AdmittingDate", 0x00380020),
+    ("AdmittingDiagnosesCodeSequence", 0x00081084),
+    ("AdmittingDiagnosesDescription", 0x00081080),
+    ("AdmittingTime", 0x00380021),
+    ("AlgorithmDescription", 0x00189528),
+    ("AlgorithmType", 0x00189527),
+    ("AliasedDataType", 0x0018980B),
+    ("Allergies", 0x00102110),
+    ("AlphaLUTTransferFunction", 0x00281410),
+    ("AlphaPaletteColorLookupTableData", 0x00281204),
+    ("AlphaPaletteColorLookupTableDescriptor", 0x00281104),
+    ("AlternateContainerIdentifierSequence", 0x00400515),
+    ("AlternateContentDescriptionSequence", 0x00700087),
+    ("AlternateRepresentationSequence", 0x00083001),
+    ("AnatomicRegionModifierSequence", 0x00082220),
+    ("AnatomicRegionSequence", 0x00082218),
+    ("AnatomicRegionsInStudyCodeSequence", 0x00080063),
+    ("AnatomicStructure", 0x00082208),
+    ("AnatomicStructureSpaceOrRegionSequence", 0x00082229),
+    ("AnatomicalOrientationType", 0x00102210),
+    ("AnchorPoint", 0x00700014),
+    ("AnchorPointAnnotationUnits", 0x00700004),
+    ("AnchorPointVisibility", 0x00700015),
+    ("AngularStep", 0x00181144),
+    ("AngularViewVector", 0x00540090),
+    ("AnnotationGroupNumber", 0x0040A180),
+    ("AnodeTargetMaterial", 0x00181191),
+    ("ApexPosition", 0x00209308),
+    ("ApplicableFrameRange", 0x00286102),
+    ("ApplicableSafetyStandardAgency", 0x00189174),
+    ("ApplicableSafetyStandardDescription", 0x00189175),
+    ("ApplicationManufacturer", 0x00189526),
+    ("ApplicationName", 0x00189524),
+    ("ApplicationVersion", 0x00189525),
+    ("ApprovalStatus", 0x300E0002),
+    ("ArterialSpinLabelingContrast", 0x00189250),
+    ("AssigningAgencyOrDepartmentCodeSequence", 0x0040003A),
+    ("AssigningFacilitySequence", 0x00400036),
+    ("AssigningJurisdictionCodeSequence", 0x00400039),
+    ("AttachedContours", 0x30060049),
+    ("AttenuationCorrected", 0x00189759),
+    ("AttenuationCorrectionMethod", 0x00541101),
+    ("AttenuationCorrectionSource", 0x00189738),
+    ("AttenuationCorrectionTemporalRelationship", 0x00189770),
+    ("AudioComments", 0x5000200E),
+    ("AudioSampleData", 0x5000200C),
+    ("AudioSampleFormat", 0x50002002),
+    ("AudioType", 0x50002000),
+    ("AuthorObserverSequence", 0x0040A078),
+    ("AveragePulseWidth", 0x00181154),
+    ("AxialAcceptance", 0x00541200),
+    ("AxialDetectorDimension", 0x00189727),
+    ("AxialMash", 0x00541201),
+    ("AxisUnits", 0x50000030),
+    ("BaselineCorrection", 0x00189067),
+    ("BeamAngle", 0x00189449),
+    ("BeamDescription", 0x300A00C3),
+    ("BeamDose", 0x300A0084),
+    ("BeamDoseSpecificationPoint", 0x300A0082),
+    ("BeamLimitingDeviceAngle", 0x300A0120),
+    ("BeamLimitingDevicePositionSequence", 0x300A011A),
+    ("BeamLimitingDeviceRotationDirection", 0x300A0121),
+    ("BeamLimitingDeviceSequence", 0x300A00B6),
+    ("BeamMeterset", 0x300A0086),
+    ("BeamName", 0x300A00C2),
+    ("BeamNumber", 0x300A00C0),
+    ("BeamSequence", 0x300A00B0),
+    ("BeamType", 0x300A00C4),
+    ("BeatRejectionFlag", 0x00181080),
+    ("BiPlaneAcquisitionSequence", 0x00285000),
+    ("BillingItemSequence", 0x00400296),
+    ("BillingProcedureStepSequence", 0x00400320),
+    ("BillingSuppliesAndDevicesSequence", 0x00400324),
+    ("BitsAllocated", 0x00280100),
+    ("BitsMappedToColorLookupTable", 0x00281403),
+    ("BitsStored", 0x00280101),
+    ("BlendingLUT1Sequence", 0x00281404),
+    ("BlendingLUT1TransferFunction", 0x00281405),
+    ("BlendingLUT2Sequence", 0x0028140C),
+    ("BlendingLUT2TransferFunction", 0x0028140D),
+    ("BlendingLookupTableData", 0x00281408),
+    ("BlendingLookupTableDescriptor", 0x00281407),
+    ("BlendingPosition", 0x00700405),
+    ("BlendingSequence", 0x00700402),
+    ("BlendingWeightConstant", 0x00281406),
+    ("BlockIdentifyingInformationStatus", 0x00080303),
+    ("BloodSignalNulling", 0x00189022),
+    ("BluePaletteColorLookupTableData", 0x00281203),
+    ("BluePaletteColorLookupTableDescriptor", 0x00281103),
+    ("BodyPartExamined", 0x00180015),
+    ("BodyPartThickness", 0x001811A0),
+    ("Bold", 0x00700249),
+    ("BoneThermalIndex", 0x00185024),
+    ("BoundingBoxAnnotationUnits", 0x00700003),
+    ("BoundingBoxBottomRightHandCorner", 0x00700011),
+    ("BoundingBoxTextHorizontalJustification", 0x00700012),
+    ("BoundingBoxTopLeftHandCorner", 0x00700010),
+    ("BranchOfService", 0x00101081),
+    ("BreastImplantPresent", 0x00281300),
+    ("BreedRegistrationNumber", 0x00102295),
+    ("BreedRegistrationSequence", 0x00102294),
+    ("BreedRegistryCodeSequence", 0x00102296),
+    ("BulkMotionCompensationTechnique", 0x00189172),
+    ("BulkMotionSignalSource", 0x00189173),
+    ("BulkMotionStatus", 0x00189166),
+    ("BurnedInAnnotation", 0x00280301),
+    ("CArmPositionerTabletopRelationship", 0x00189474),
+    ("CSSFontName", 0x00700229),
+    ("CTAcquisitionDetailsSequence", 0x00189304),
+    ("CTAcquisitionTypeSequence", 0x00189301),
+    ("CTAdditionalXRaySourceSequence", 0x00189360),
+    ("CTDIPhantomTypeCodeSequence", 0x00189346),
+    ("CTDIvol", 0x00189345),
+    ("CTExposureSequence", 0x00189321),
+    ("CTGeometrySequence", 0x00189312),
+    ("CTImageFrameTypeSequence", 0x00189329),
+    ("CTPositionSequence", 0x00189326),
+    ("CTReconstructionSequence", 0x00189314),
+    ("CTTableDynamicsSequence", 0x00189308),
+    ("CTXRayDetailsSequence", 0x00189325),
+    ("CalciumScoringMassFactorDevice", 0x00189352),
+    ("CalciumScoringMassFactorPatient", 0x00189351),
+    ("CalculatedAnatomyThickness", 0x00189452),
+    ("CalculatedFrameList", 0x00081162),
+    ("CalibrationDataSequence", 0x00540306),
+    ("CalibrationSequence", 0x00189455),
+    ("CardiacBeatRejectionTechnique", 0x00189169),
+    ("CardiacCyclePosition", 0x00189236),
+    ("CardiacFramingType", 0x00181064),
+    ("CardiacRRIntervalSpecified", 0x00189070),
+    ("CardiacSignalSource", 0x00189085),
+    ("CardiacSynchronizationSequence", 0x00189118),
+    ("CardiacSynchronizationTechnique", 0x00189037),
+    ("CassetteID", 0x00181007),
+    ("CassetteOrientation", 0x00181402),
+    ("CassetteSize", 0x00181403),
+    ("CenterOfCircularCollimator", 0x00181710),
+    ("CenterOfCircularExposureControlSensingRegion", 0x00189440),
+    ("CenterOfCircularShutter", 0x00181610),
+    ("CenterOfRotationOffset", 0x00181145),
+    ("ChannelBaseline", 0x003A0213),
+    ("ChannelDefinitionSequence", 0x003A0200),
+    ("ChannelDerivationDescription", 0x003A020C),
+    ("ChannelLabel", 0x003A0203),
+    ("ChannelMaximumValue", 0x54000112),
+    ("ChannelMinimumValue", 0x54000110),
+    ("ChannelOffset", 0x003A0218),
+    ("ChannelSampleSkew", 0x003A0215),
+    ("ChannelSensitivity", 0x003A0210),
+    ("ChannelSensitivityCorrectionFactor", 0x003A0212),
+    ("ChannelSensitivityUnitsSequence", 0x003A0211),
+    ("ChannelSourceModifiersSequence", 0x003A0209),
+    ("ChannelSourceSequence", 0x003A0208),
+    ("ChannelStatus", 0x003A0205),
+    ("ChannelTimeSkew", 0x003A0214),
+    ("ChemicalShiftMaximumIntegrationLimitInHz", 0x00189196),
+    ("ChemicalShiftMaximumIntegrationLimitInppm", 0x00189296),
+    ("ChemicalShiftMinimumIntegrationLimitInHz", 0x00189195),
+    ("ChemicalShiftMinimumIntegrationLimitInppm", 0x00189295),
+    ("ChemicalShiftReference", 0x00189053),
+    ("ChemicalShiftSequence", 0x00189084),
+    ("CineRate", 0x00180040),
+    ("ClinicalTrialCoordinatingCenterName", 0x00120060),
+    ("ClinicalTrialProtocolEthicsCommitteeApprovalNumber", 0x00120082),
+    ("ClinicalTrialProtocolEthicsCommitteeName", 0x00120081),
+    ("ClinicalTrialProtocolID", 0x00120020),
+    ("ClinicalTrialProtocolName", 0x00120021),
+    ("ClinicalTrialSeriesDescription", 0x00120072),
+    ("ClinicalTrialSeriesID", 0x00120071),
+    ("ClinicalTrialSiteID", 0x00120030),
+    ("ClinicalTrialSiteName", 0x00120031),
+    ("ClinicalTrialSponsorName", 0x00120010),
+    ("ClinicalTrialSubjectID", 0x00120040),
+    ("ClinicalTrialSubjectReadingID", 0x00120042),
+    ("ClinicalTrialTimePointDescription", 0x00120051),
+    ("ClinicalTrialTimePointID", 0x00120050),
+    ("CodeMeaning", 0x00080104),
+    ("CodeValue", 0x00080100),
+    ("CodingSchemeDesignator", 0x00080102),
+    ("CodingSchemeExternalID", 0x00080114),
+    ("CodingSchemeIdentificationSequence", 0x00080110),
+    ("CodingSchemeName", 0x00080115),
+    ("CodingSchemeRegistry", 0x00080112),
+    ("CodingSchemeResponsibleOrganization", 0x00080116),
+    ("CodingSchemeUID", 0x0008010C),
+    ("CodingSchemeVersion", 0x00080103),
+    ("CoefficientsSDDN", 0x7FE00040),
+    ("CoefficientsSDHN", 0x7FE00030),
+    ("CoefficientsSDVN", 0x7FE00020),
+    ("CoincidenceWindowWidth", 0x00541210),
+    ("CollimatorGridName", 0x00181180),
+    ("CollimatorLeftVerticalEdge", 0x00181702),
+    ("CollimatorLowerHorizontalEdge", 0x00181708),
+    ("CollimatorRightVerticalEdge", 0x00181704),
+    ("CollimatorShape", 0x00181700),
+    ("CollimatorShapeSequence", 0x00189407),
+    ("CollimatorType", 0x00181181),
+    ("CollimatorUpperHorizontalEdge", 0x00181706),
+    ("ColorSpace", 0x00282002),
+    ("ColumnAngulation", 0x00181450),
+    ("ColumnAngulationPatient", 0x00189447),
+    ("Columns", 0x00280011),
+    ("CommentsOnRadiationDose", 0x00400310),
+    ("CommentsOnThePerformedProcedureStep", 0x00400280),
+    ("CommentsOnTheScheduledProcedureStep", 0x00400400),
+    ("CompletionFlag", 0x0040A491),
+    ("ComplexImageComponent", 0x00089208),
+    ("CompoundGraphicInstanceID", 0x00700226),
+    ("CompoundGraphicSequence", 0x00700209),
+    ("CompoundGraphicType", 0x00700294),
+    ("CompoundGraphicUnits", 0x00700282),
+    ("CompressionCode", 0x00280060),
+    ("CompressionForce", 0x001811A2),
+    ("CompressionRecognitionCode", 0x0028005F),
+    ("ConcatenationUID", 0x00209161),
+    ("ConceptCodeSequence", 0x0040A168),
+    ("ConceptNameCodeSequence", 0x0040A043),
+    ("ConfidentialityCode", 0x00401008),
+    ("ConfidentialityConstraintOnPatientDataDescription", 0x00403001),
+    ("ConsentForClinicalTrialUseSequence", 0x00120083),
+    ("ConsentForDistributionFlag", 0x00120085),
+    ("ConstantVolumeFlag", 0x00189333),
+    ("ContainerComponentSequence", 0x00400520),
+    ("ContainerDescription", 0x0040051A),
+    ("ContainerIdentifier", 0x00400512),
+    ("ContainerTypeCodeSequence", 0x00400518),
+    ("ContentCreatorIdentificationCodeSequence", 0x00700086),
+    ("ContentCreatorName", 0x00700084),
+    ("ContentDate", 0x00080023),
+    ("ContentDescription", 0x00700081),
+    ("ContentItemModifierSequence", 0x00400441),
+    ("ContentLabel", 0x00700080),
+    ("ContentQualification", 0x00189004),
+    ("ContentSequence", 0x0040A730),
+    ("ContentTemplateSequence", 0x0040A504),
+    ("ContentTime", 0x00080033),
+    ("ContextGroupExtensionCreatorUID", 0x0008010D),
+    ("ContextGroupExtensionFlag", 0x0008010B),
+    ("ContextGroupIdentificationSequence", 0x00080123),
+    ("ContextGroupLocalVersion", 0x00080107),
+    ("ContextGroupVersion", 0x00080106),
+    ("ContextIdentifier", 0x0008010F),
+    ("ContextUID", 0x00080117),
+    ("ContinuityOfContent", 0x0040A050),
+    ("ContourData", 0x30060050),
+    ("ContourGeometricType", 0x30060042),
+    ("ContourImageSequence", 0x30060016),
+    ("ContourNumber", 0x30060048),
+    ("ContourOffsetVector", 0x30060045),
+    ("ContourSequence", 0x30060040),
+    ("ContourSlabThickness", 0x30060044),
+    ("ContourUncertaintyRadius", 0x00700312),
+    ("ContrastAdministrationProfileSequence", 0x00189340),
+    ("ContrastBolusAdministrationRouteSequence", 0x00180014),
+    ("ContrastBolusAgent", 0x00180010),
+    ("ContrastBolusAgentAdministered", 0x00189342),
+    ("ContrastBolusAgentDetected", 0x00189343),
+    ("ContrastBolusAgentNumber", 0x00189337),
+    ("ContrastBolusAgentPhase", 0x00189344),
+    ("ContrastBolusAgentSequence", 0x00180012),
+    ("ContrastBolusIngredient", 0x00181048),
+    ("ContrastBolusIngredientCodeSequence", 0x00189338),
+    ("ContrastBolusIngredientConcentration", 0x00181049),
+    ("ContrastBolusIngredientOpaque", 0x00189425),
+    ("ContrastBolusRoute", 0x00181040),
+    ("ContrastBolusStartTime", 0x00181042),
+    ("ContrastBolusStopTime", 0x00181043),
+    ("ContrastBolusTotalDose", 0x00181044),
+    ("ContrastBolusUsageSequence", 0x00189341),
+    ("ContrastBolusVolume", 0x00181041),
+    ("ContrastFlowDuration", 0x00181047),
+    ("ContrastFlowRate", 0x00181046),
+    ("ContrastFrameAveraging", 0x00286112),
+    ("ContributingEquipmentSequence", 0x0018A001),
+    ("ContributingSOPInstancesReferenceSequence", 0x00209529),
+    ("ContributingSourcesSequence", 0x00189506),
+    ("ContributionDateTime", 0x0018A002),
+    ("ContributionDescription", 0x0018A003),
+    ("ControlPointIndex", 0x300A0112),
+    ("ControlPointSequence", 0x300A0111),
+    ("ConversionSourceAttributesSequence", 0x00209172),
+    ("ConversionType", 0x00080064),
+    ("ConvolutionKernel", 0x00181210),
+    ("ConvolutionKernelGroup", 0x00189316),
+    ("CoordinateStartValue", 0x50000112),
+    ("CoordinateStepValue", 0x50000114),
+    ("CorrectedImage", 0x00280051),
+    ("CountLossNormalizationCorrected", 0x00189764),
+    ("CountRate", 0x00181243),
+    ("CountryOfResidence", 0x00102150),
+    ("CountsAccumulated", 0x00180070),
+    ("CountsIncluded", 0x00541400),
+    ("CountsSource", 0x00541002),
+    ("CoverageOfKSpace", 0x00189094),
+    ("CranialThermalIndex", 0x00185026),
+    ("CreatorVersionUID", 0x00089123),
+    ("CumulativeMetersetWeight", 0x300A0134),
+    ("CurrentPatientLocation", 0x00380300),
+    ("CurrentRequestedProcedureEvidenceSequence", 0x0040A375),
+    ("CurveActivationLayer", 0x50001001),
+    ("CurveData", 0x50003000),
+    ("CurveDataDescriptor", 0x50000110),
+    ("CurveDate", 0x00080025),
+    ("CurveDescription", 0x50000022),
+    ("CurveDimensions", 0x50000005),
+    ("CurveLabel", 0x50002500),
+    ("CurveNumber", 0x00200024),
+    ("CurveRange", 0x50000106),
+    ("CurveReferencedOverlayGroup", 0x50002610),
+    ("CurveReferencedOverlaySequence", 0x50002600),
+    ("CurveTime", 0x00080035),
+    ("CustodialOrganizationSequence", 0x0040A07C),
+    ("DICOMMediaRetrievalSequence", 0x0040E022),
+    ("DICOMRetrievalSequence", 0x0040E021),
+    ("DVHData", 0x30040058),
+    ("DVHDoseScaling", 0x30040052),
+    ("DVHMaximumDose", 0x30040072),
+    ("DVHMeanDose", 0x30040074),
+    ("DVHMinimumDose", 0x30040070),
+    ("DVHNormalizationDoseValue", 0x30040042),
+    ("DVHNormalizationPoint", 0x30040040),
+    ("DVHNumberOfBins", 0x30040056),
+    ("DVHROIContributionType", 0x30040062),
+    ("DVHReferencedROISequence", 0x30040060),
+    ("DVHSequence", 0x30040050),
+    ("DVHType", 0x30040001),
+    ("DVHVolumeUnits", 0x30040054),
+    ("DataCollectionCenterPatient", 0x00189313),
+    ("DataCollectionDiameter", 0x00180090),
+    ("DataFrameAssignmentSequence", 0x00281401),
+    ("DataInformationSequence", 0x00540063),
+    ("DataPathAssignment", 0x00281402),
+    ("DataPathID", 0x0028140E),
+    ("DataPointColumns", 0x00289002),
+    ("DataPointRows", 0x00289001),
+    ("DataRepresentation", 0x00289108),
+    ("DataSetSubtype", 0x00080041),
+    ("DataSetTrailingPadding", 0xFFFCFFFC),
+    ("DataSetType", 0x00080040),
+    ("DataType", 0x00189808),
+    ("DataValueRepresentation", 0x50000103),
+    ("Date", 0x0040A121),
+    ("DateOfLastCalibration", 0x00181200),
+    ("DateOfLastDetectorCalibration", 0x0018700C),
+    ("DateOfSecondaryCapture", 0x00181012),
+    ("DateTime", 0x0040A120),
+    ("DeadTimeCorrected", 0x00189761),
+    ("DeadTimeCorrectionFlag", 0x00541401),
+    ("DeadTimeFactor", 0x00541324),
+    ("DecayCorrected", 0x00189758),
+    ("DecayCorrection", 0x00541102),
+    ("DecayCorrectionDateTime", 0x00189701),
+    ("DecayFactor", 0x00541321),
+    ("DecoupledNucleus", 0x00189060),
+    ("Decoupling", 0x00189059),
+    ("DecouplingChemicalShiftReference", 0x00189063),
+    ("DecouplingFrequency", 0x00189061),
+    ("DecouplingMethod", 0x00189062),
+    ("DefinitionSourceSequence", 0x00081156),
+    ("DeidentificationAction", 0x00080307),
+    ("DeidentificationActionSequence", 0x00080305),
+    ("DeidentificationMethod", 0x00120063),
+    ("DeidentificationMethodCodeSequence", 0x00120064),
+    ("DeliveryMaximumDose", 0x300A0023),
+    ("DepthOfScanField", 0x00185050),
+    ("DepthsOfFocus", 0x00189801),
+    ("DerivationCodeSequence", 0x00089215),
+    ("DerivationDescription", 0x00082111),
+    ("DerivationImageSequence", 0x00089124),
+    ("DetectorActivationOffsetFromExposure", 0x00187016),
+    ("DetectorActiveDimensions", 0x00187026),
+    ("DetectorActiveOrigin", 0x00187028),
+    ("DetectorActiveShape", 0x00187024),
+    ("DetectorActiveTime", 0x00187014),
+    ("DetectorBinning", 0x0018701A),
+    ("DetectorConditionsNominalFlag", 0x00187000),
+    ("DetectorConfiguration", 0x00187005),
+    ("DetectorDescription", 0x00187006),
+    ("DetectorElementPhysicalSize", 0x00187020),
+    ("DetectorElementSize", 0x00541203),
+    ("DetectorElementSpacing", 0x00187022),
+    ("DetectorGeometry", 0x00189725),
+    ("DetectorID", 0x0018700A),
+    ("DetectorInformationSequence", 0x00540022),
+    ("DetectorLinesOfResponseUsed", 0x00541104),
+    ("DetectorManufacturerModelName", 0x0018702B),
+    ("DetectorManufacturerName", 0x0018702A),
+    ("DetectorMode", 0x00187008),
+    ("DetectorNormalizationCorrection", 0x00189768),
+    ("DetectorPrimaryAngle", 0x00181530),
+    ("DetectorSecondaryAngle", 0x00181531),
+    ("DetectorTemperature", 0x00187001),
+    ("DetectorTimeSinceLastExposure", 0x00187012),
+    ("DetectorType", 0x00187004),
+    ("DetectorVector", 0x00540020),
+    ("DeviationIndex", 0x00181413),
+    ("DeviceSerialNumber", 0x00181000),
+    ("DiameterOfVisibility", 0x00700262),
+    ("DiffusionAnisotropyType", 0x00189147),
+    ("DiffusionBMatrixSequence", 0x00189601),
+    ("DiffusionBValue", 0x00189087),
+    ("DiffusionBValueXX", 0x00189602),
+    ("DiffusionBValueXY", 0x00189603),
+    ("DiffusionBValueXZ", 0x00189604),
+    ("DiffusionBValueYY", 0x00189605),
+    ("DiffusionBValueYZ", 0x00189606),
+    ("DiffusionBValueZZ", 0x00189607),
+    ("DiffusionDirectionality", 0x00189075),
+    ("DiffusionGradientDirectionSequence", 0x00189076),
+    ("DiffusionGradientOrientation", 0x00189089),
+    ("DigitalImageFormatAcquired", 0x00181023),
+    ("DigitalSignaturesSequence", 0xFFFAFFFA),
+    ("DigitizingDeviceTransportDirection", 0x00182020),
+    ("DimensionDescriptionLabel", 0x00209421),
+    ("DimensionIndexPointer", 0x00209165),
+    ("DimensionIndexPrivateCreator", 0x00209213),
+    ("DimensionIndexSequence", 0x00209222),
+    ("DimensionIndexValues", 0x00209157),
+    ("DimensionOrganizationSequence", 0x00209221),
+    ("DimensionOrganizationType", 0x00209311),
+    ("DimensionOrganizationUID", 0x00209164),
+    ("DirectoryRecordSequence", 0x00041220),
+    ("DirectoryRecordType", 0x00041430),
+    ("DisplayFilterPercentage", 0x00289411),
+    ("DisplayWindowLabelVector", 0x00182006),
+    ("DisplayedAreaBottomRightHandCorner", 0x00700053),
+    ("DisplayedAreaSelectionSequence", 0x0070005A),
+    ("DisplayedAreaTopLeftHandCorner", 0x00700052),
+    ("DistanceObjectToTableTop", 0x00189403),
+    ("DistanceReceptorPlaneToDetectorHousing", 0x00189426),
+    ("DistanceSourceToDataCollectionCenter", 0x00189335),
+    ("DistanceSourceToDetector", 0x00181110),
+    ("DistanceSourceToEntrance", 0x00400306),
+    ("DistanceSourceToIsocenter", 0x00189402),
+    ("DistanceSourceToPatient", 0x00181111),
+    ("DistanceSourceToSupport", 0x00400307),
+    ("DistributionType", 0x00120084),
+    ("DocumentClassCodeSequence", 0x0040E008),
+    ("DopplerCorrectionAngle", 0x00186034),
+    ("DoseCalibrationFactor", 0x00541322),
+    ("DoseComment", 0x30040006),
+    ("DoseGridScaling", 0x3004000E),
+    ("DoseRateSet", 0x300A0115),
+    ("DoseReferenceDescription", 0x300A0016),
+    ("DoseReferenceNumber", 0x300A0012),
+    ("DoseReferencePointCoordinates", 0x300A0018),
+    ("DoseReferenceSequence", 0x300A0010),
+    ("DoseReferenceStructureType", 0x300A0014),
+    ("DoseReferenceType", 0x300A0020),
+    ("DoseReferenceUID", 0x300A0013),
+    ("DoseSummationType", 0x3004000A),
+    ("DoseType", 0x30040004),
+    ("DoseUnits", 0x30040002),
+    ("DoseValue", 0x30040012),
+    ("DoubleFloatPixelData", 0x7FE00009),
+    ("DoubleFloatPixelPaddingRangeLimit", 0x00280125),
+    ("DoubleFloatPixelPaddingValue", 0x00280123),
+    ("DoubleFloatRealWorldValueFirstValueMapped", 0x00409214),
+    ("DoubleFloatRealWorldValueLastValueMapped", 0x00409213),
+    ("EchoNumbers", 0x00180086),
+    ("EchoPeakPosition", 0x00189298),
+    ("EchoPlanarPulseSequence", 0x00189018),
+    ("EchoPulseSequence", 0x00189008),
+    ("EchoTime", 0x00180081),
+    ("EchoTrainLength", 0x00180091),
+    ("EffectiveDuration", 0x00180072),
+    ("EffectiveEchoTime", 0x00189082),
+    ("EndAcquisitionDateTime", 0x00189517),
+    ("EndingRespiratoryAmplitude", 0x00209248),
+    ("EndingRespiratoryPhase", 0x00209249),
+    ("EnergyWeightingFactor", 0x00189353),
+    ("EnergyWindowInformationSequence", 0x00540012),
+    ("EnergyWindowLowerLimit", 0x00540014),
+    ("EnergyWindowName", 0x00540018),
+    ("EnergyWindowNumber", 0x00540308),
+    ("EnergyWindowRangeSequence", 0x00540013),
+    ("EnergyWindowUpperLimit", 0x00540015),
+    ("EnhancedPaletteColorLookupTableSequence", 0x0028140B),
+    ("EntranceDose", 0x00400302),
+    ("EntranceDoseInmGy", 0x00408302),
+    ("EquipmentAdministratorSequence", 0x00287000),
+    ("EquipmentCoordinateSystemIdentification", 0x00289537),
+    ("EquivalentCDADocumentSequence", 0x0040A090),
+    ("EquivalentCodeSequence", 0x00080121),
+    ("EstimatedDoseSaving", 0x00189324),
+    ("EthnicGroup", 0x00102160),
+    ("EventCodeSequence", 0x00082135),
+    ("EventElapsedTimes", 0x00082130),
+    ("EventTimeOffset", 0x00082134),
+    ("EventTimerNames", 0x00082132),
+    ("EventTimerSequence", 0x00082133),
+    ("ExaminedBodyThickness", 0x00109431),
+    ("ExcludedIntervalsSequence", 0x00189803),
+    ("ExclusionDuration", 0x00189805),
+    ("ExclusionStartDateTime", 0x00189804),
+    ("ExposedArea", 0x00400303),
+    ("Exposure", 0x00181152),
+    ("ExposureControlMode", 0x00187060),
+    ("ExposureControlModeDescription", 0x00187062),
+    ("ExposureControlSensingRegionLeftVerticalEdge", 0x00189436),
+    ("ExposureControlSensingRegionLowerHorizontalEdge", 0x00189439),
+    ("ExposureControlSensingRegionRightVerticalEdge", 0x00189437),
+    ("ExposureControlSensingRegionShape", 0x00189435),
+    ("ExposureControlSensingRegionUpperHorizontalEdge", 0x00189438),
+    ("ExposureControlSensingRegionsSequence", 0x00189434),
+    ("ExposureDoseSequence", 0x0040030E),
+    ("ExposureIndex", 0x00181411),
+    ("ExposureInmAs", 0x00189332),
+    ("ExposureInuAs", 0x00181153),
+    ("ExposureModulationType", 0x00189323),
+    ("ExposureSequence", 0x30020030),
+    ("ExposureStatus", 0x00187064),
+    ("ExposureTime", 0x00181150),
+    ("ExposureTimeInms", 0x00189328),
+    ("ExposureTimeInuS", 0x00188150),
+    ("ExposuresOnDetectorSinceLastCalibration", 0x00187010),
+    ("ExposuresOnDetectorSinceManufactured", 0x00187011),
+    ("ExposuresOnPlate", 0x00181404),
+    ("ExtendedOffsetTable", 0x7FE00001),
+    ("ExtendedOffsetTableLengths", 0x7FE00002),
+    ("FailedSOPInstanceUIDList", 0x00080058),
+    ("FailedSOPSequence", 0x00081198),
+    ("FailureReason", 0x00081197),
+    ("FiducialDescription", 0x0070030F),
+    ("FiducialIdentifier", 0x00700310),
+    ("FiducialIdentifierCodeSequence", 0x00700311),
+    ("FiducialSequence", 0x0070031E),
+    ("FiducialSetSequence", 0x0070031C),
+    ("FiducialUID", 0x0070031A),
+    ("FieldOfViewDescription", 0x00189433),
+    ("FieldOfViewDimensions", 0x00181149),
+    ("FieldOfViewDimensionsInFloat", 0x00189461),
+    ("FieldOfViewHorizontalFlip", 0x00187034),
+    ("FieldOfViewOrigin", 0x00187030),
+    ("FieldOfViewRotation", 0x00187032),
+    ("FieldOfViewSequence", 0x00189432),
+    ("FieldOfViewShape", 0x00181147),
+    ("FileMetaInformationGroupLength", 0x00020000),
+    ("FileMetaInformationVersion", 0x00020001),
+    ("FileSetConsistencyFlag", 0x00041212),
+    ("FileSetDescriptorFileID", 0x00041141),
+    ("FileSetID", 0x00041130),
+    ("FillMode", 0x00700257),
+    ("FillPattern", 0x00700256),
+    ("FillStyleSequence", 0x00700233),
+    ("FillerOrderNumberImagingServiceRequest", 0x00402017),
+    ("FilmConsumptionSequence", 0x00400321),
+    ("FilterBeamPathLengthMaximum", 0x00187058),
+    ("FilterBeamPathLengthMinimum", 0x00187056),
+    ("FilterHighFrequency", 0x003A0221),
+    ("FilterLowFrequency", 0x003A0220),
+    ("FilterMaterial", 0x00187050),
+    ("FilterThicknessMaximum", 0x00187054),
+    ("FilterThicknessMinimum", 0x00187052),
+    ("FilterType", 0x00181160),
+    ("FinalCumulativeMetersetWeight", 0x300A010E),
+    ("FirstOrderPhaseCorrection", 0x00189198),
+    ("FlipAngle", 0x00181314),
+    ("FloatPixelData", 0x7FE00008),
+    ("FloatPixelPaddingRangeLimit", 0x00280124),
+    ("FloatPixelPaddingValue", 0x00280122),
+    ("FloatingPointValue", 0x0040A161),
+    ("FlowCompensation", 0x00189010),
+    ("FlowCompensationDirection", 0x00189183),
+    ("FluoroscopyFlag", 0x00189334),
+    ("FocalDistance", 0x00181182),
+    ("FocalSpots", 0x00181190),
+    ("FocusDepth", 0x00185012),
+    ("FontName", 0x00700227),
+    ("FontNameType", 0x00700228),
+    ("FractionGroupNumber", 0x300A0071),
+    ("FractionGroupSequence", 0x300A0070),
+    ("FractionNumber", 0x30020029),
+    ("FrameAcquisitionDateTime", 0x00189074),
+    ("FrameAcquisitionDuration", 0x00189220),
+    ("FrameAcquisitionNumber", 0x00209156),
+    ("FrameAcquisitionSequence", 0x00189417),
+    ("FrameAnatomySequence", 0x00209071),
+    ("FrameComments", 0x00209158),
+    ("FrameContentSequence", 0x00209111),
+    ("FrameDelay", 0x00181066),
+    ("FrameDetectorParametersSequence", 0x00189451),
+    ("FrameDimensionPointer", 0x0028000A),
+    ("FrameDisplaySequence", 0x00089458),
+    ("FrameDisplayShutterSequence", 0x00189472),
+    ("FrameExtractionSequence", 0x00081164),
+    ("FrameIncrementPointer", 0x00280009),
+    ("FrameLabel", 0x00209453),
+    ("FrameLabelVector", 0x00182002),
+    ("FrameNumbersOfInterest", 0x00286020),
+    ("FrameOfInterestDescription", 0x00286022),
+    ("FrameOfInterestType", 0x00286023),
+    ("FrameOfReferenceRelationshipSequence", 0x300600C0),
+    ("FrameOfReferenceTransformationComment", 0x300600C8),
+    ("FrameOfReferenceTransformationMatrix", 0x300600C6),
+    ("FrameOfReferenceTransformationMatrixType", 0x0070030C),
+    ("FrameOfReferenceTransformationType", 0x300600C4),
+    ("FrameOfReferenceUID", 0x00200052),
+    ("FramePixelDataPropertiesSequence", 0x00289443),
+    ("FramePixelShiftSequence", 0x00289415),
+    ("FramePrimaryAngleVector", 0x00182003),
+    ("FrameReferenceDateTime", 0x00189151),
+    ("FrameReferenceTime", 0x00541300),
+    ("FrameSecondaryAngleVector", 0x00182004),
+    ("FrameTime", 0x00181063),
+    ("FrameTimeVector", 0x00181065),
+    ("FrameType", 0x00089007),
+    ("FrameVOILUTSequence", 0x00289132),
+    ("FrequencyCorrection", 0x00189101),
+    ("FunctionalGroupPointer", 0x00209167),
+    ("FunctionalGroupPrivateCreator", 0x00209238),
+    ("GantryAngle", 0x300A011E),
+    ("GantryDetectorSlew", 0x00181121),
+    ("GantryDetectorTilt", 0x00181120),
+    ("GantryID", 0x00181008),
+    ("GantryMotionCorrected", 0x00189762),
+    ("GantryRotationDirection", 0x300A011F),
+    ("GapLength", 0x00700261),
+    ("GatedInformationSequence", 0x00540062),
+    ("GeneratorID", 0x00181005),
+    ("GeneratorPower", 0x00181170),
+    ("GeometricMaximumDistortion", 0x00289445),
+    ("GeometricalProperties", 0x00289444),
+    ("GeometryOfKSpaceTraversal", 0x00189032),
+    ("GradientEchoTrainLength", 0x00189241),
+    ("GradientOutput", 0x00189182),
+    ("GradientOutputType", 0x00189180),
+    ("GraphicAnnotationSequence", 0x00700001),
+    ("GraphicAnnotationUnits", 0x00700005),
+    ("GraphicCoordinatesDataSequence", 0x00700318),
+    ("GraphicData", 0x00700022),
+    ("GraphicDimensions", 0x00700020),
+    ("GraphicFilled", 0x00700024),
+    ("GraphicGroupDescription", 0x00700208),
+    ("GraphicGroupID", 0x00700295),
+    ("GraphicGroupLabel", 0x00700207),
+    ("GraphicGroupSequence", 0x00700234),
+    ("GraphicLayer", 0x00700002),
+    ("GraphicLayerDescription", 0x00700068),
+    ("GraphicLayerOrder", 0x00700062),
+    ("GraphicLayerRecommendedDisplayCIELabValue", 0x00700401),
+    ("GraphicLayerRecommendedDisplayGrayscaleValue", 0x00700066),
+    ("GraphicLayerSequence", 0x00700060),
+    ("GraphicObjectSequence", 0x00700009),
+    ("GraphicType", 0x00700023),
+    ("GrayLookupTableData", 0x00281200),
+    ("GrayLookupTableDescriptor", 0x00281100),
+    ("GrayScale", 0x00281080),
+    ("GreenPaletteColorLookupTableData", 0x00281202),
+    ("GreenPaletteColorLookupTableDescriptor", 0x00281102),
+    ("Grid", 0x00181166),
+    ("GridAbsorbingMaterial", 0x00187040),
+    ("GridAspectRatio", 0x00187046),
+    ("GridFocalDistance", 0x0018704C),
+    ("GridFrameOffsetVector", 0x3004000C),
+    ("GridPeriod", 0x00187048),
+    ("GridPitch", 0x00187044),
+    ("GridSpacingMaterial", 0x00187041),
+    ("GridThickness", 0x00187042),
+    ("HL7DocumentEffectiveTime", 0x0040E004),
+    ("HL7DocumentTypeCodeSequence", 0x0040E006),
+    ("HL7InstanceIdentifier", 0x0040E001),
+    ("HL7StructuredDocumentReferenceSequence", 0x0040A390),
+    ("HalfValueLayer", 0x00400314),
+    ("HeartRate", 0x00181088),
+    ("HighBit", 0x00280102),
+    ("HighRRValue", 0x00181082),
+    ("HomeCommunityID", 0x0040E031),
+    ("HorizontalAlignment", 0x00700242),
+    ("ICCProfile", 0x00282000),
+    ("IVUSAcquisition", 0x00183100),
+    ("IVUSGatedRate", 0x00183102),
+    ("IVUSPullbackRate", 0x00183101),
+    ("IVUSPullbackStartFrameNumber", 0x00183103),
+    ("IVUSPullbackStopFrameNumber", 0x00183104),
+    ("IconImageSequence", 0x00880200),
+    ("IdenticalDocumentsSequence", 0x0040A525),
+    ("IdentifierTypeCode", 0x00400035),
+    ("IdentifyingComments", 0x00084000),
+    ("IdentifyingPrivateElements", 0x00080306),
+    ("ImageAndFluoroscopyAreaDoseProduct", 0x0018115E),
+    ("ImageCenterPointCoordinatesSequence", 0x0040071A),
+    ("ImageComments", 0x00204000),
+    ("ImageDataTypeSequence", 0x00189807),
+    ("ImageDimensions", 0x00280005),
+    ("ImageFilter", 0x00189320),
+    ("ImageFormat", 0x00280040),
+    ("ImageFrameOrigin", 0x60000051),
+    ("ImageGeometryType", 0x00200070),
+    ("ImageHorizontalFlip", 0x00700041),
+    ("ImageID", 0x00540400),
+    ("ImageIndex", 0x00541330),
+    ("ImageLaterality", 0x00200062),
+    ("ImageLocation", 0x00280200),
+    ("ImageOrientation", 0x00200035),
+    ("ImageOrientationPatient", 0x00200037),
+    ("ImageOrientationVolume", 0x00209302),
+    ("ImagePlanePixelSpacing", 0x30020011),
+    ("ImagePosition", 0x00200030),
+    ("ImagePositionPatient", 0x00200032),
+    ("ImagePositionVolume", 0x00209301),
+    ("ImagePresentationComments", 0x00284000),
+    ("ImageProcessingApplied", 0x00289446),
+    ("ImageRotation", 0x00700042),
+    ("ImageToEquipmentMappingMatrix", 0x00289520),
+    ("ImageTriggerDelay", 0x00181067),
+    ("ImageType", 0x00080008),
+    ("ImagedNucleus", 0x00180085),
+    ("ImagerPixelSpacing", 0x00181164),
+    ("ImagesInAcquisition", 0x00201002),
+    ("ImagesInSeries", 0x00201003),
+    ("ImagesInStudy", 0x00201005),
+    ("ImagingFrequency", 0x00180084),
+    ("ImagingServiceRequestComments", 0x00402400),
+    ("ImplementationClassUID", 0x00020012),
+    ("ImplementationVersionName", 0x00020013),
+    ("InConcatenationNumber", 0x00209162),
+    ("InConcatenationTotalNumber", 0x00209163),
+    ("InPlanePhaseEncodingDirection", 0x00181312),
+    ("InStackPositionNumber", 0x00209057),
+    ("InstanceAvailability", 0x00080056),
+    ("InstanceCoercionDateTime", 0x00080015),
+    ("InstanceCreationDate", 0x00080012),
+    ("InstanceCreationTime", 0x00080013),
+    ("InstanceCreatorUID", 0x00080014),
+    ("InstanceNumber", 0x00200013),
+    ("InstitutionAddress", 0x00080081),
+    ("InstitutionCodeSequence", 0x00080082),
+    ("InstitutionName", 0x00080080),
+    ("InstitutionalDepartmentName", 0x00081040),
+    ("InsurancePlanIdentification", 0x00101050),
+    ("IntendedRecipientsOfResultsIdentificationSequence", 0x00401011),
+    ("IntensifierActiveDimensions", 0x00189428),
+    ("IntensifierActiveShape", 0x00189427),
+    ("IntensifierSize", 0x00181162),
+    ("IntervalsAcquired", 0x00181083),
+    ("IntervalsRejected", 0x00181084),
+    ("InterventionDrugCodeSequence", 0x00180029),
+    ("InterventionDrugDose", 0x00180028),
+    ("InterventionDrugInformationSequence", 0x00180026),
+    ("InterventionDrugName", 0x00180034),
+    ("InterventionDrugStartTime", 0x00180035),
+    ("InterventionDrugStopTime", 0x00180027),
+    ("InversionRecovery", 0x00189009),
+    ("InversionTime", 0x00180082),
+    ("InversionTimes", 0x00189079),
+    ("IrradiationEventIdentificationSequence", 0x00189477),
+    ("IrradiationEventUID", 0x00083010),
+    ("IsocenterPosition", 0x300A012C),
+    ("IsocenterReferenceSystemSequence", 0x00189462),
+    ("IssueDateOfImagingServiceRequest", 0x00402004),
+    ("IssueTimeOfImagingServiceRequest", 0x00402005),
+    ("IssuerOfAccessionNumberSequence", 0x00080051),
+    ("IssuerOfAdmissionID", 0x00380011),
+    ("IssuerOfAdmissionIDSequence", 0x00380014),
+    ("IssuerOfPatientID", 0x00100021),
+    ("IssuerOfPatientIDQualifiersSequence", 0x00100024),
+    ("IssuerOfTheContainerIdentifierSequence", 0x00400513),
+    ("IssuerOfTheSpecimenIdentifierSequence", 0x00400562),
+    ("Italic", 0x00700250),
+    ("ItemNumber", 0x00200019),
+    ("IterativeReconstructionMethod", 0x00189769),
+    ("KSpaceFiltering", 0x00189064),
+    ("KVP", 0x00180060),
+    ("LUTData", 0x00283006),
+    ("LUTDescriptor", 0x00283002),
+    ("LUTExplanation", 0x00283003),
+    ("LUTFrameRange", 0x00289507),
+    ("LUTFunction", 0x00289474),
+    ("LUTLabel", 0x00409210),
+    ("LUTNumber", 0x00200026),
+    ("LanguageCodeSequence", 0x00080006),
+    ("LargePaletteColorLookupTableUID", 0x00281214),
+    ("LargeRedPaletteColorLookupTableDescriptor", 0x00281111),
+    ("LargestImagePixelValue", 0x00280107),
+    ("LargestImagePixelValueInPlane", 0x00280111),
+    ("LargestMonochromePixelValue", 0x00289099),
+    ("LargestPixelValueInSeries", 0x00280109),
+    ("LargestValidPixelValue", 0x00280105),
+    ("LastMenstrualDate", 0x001021D0),
+    ("Laterality", 0x00200060),
+    ("LeafJawPositions", 0x300A011C),
+    ("LeafPositionBoundaries", 0x300A00BE),
+    ("LengthToEnd", 0x00080001),
+    ("LesionNumber", 0x00183105),
+    ("LineDashingStyle", 0x00700254),
+    ("LinePattern", 0x00700255),
+    ("LineStyleSequence", 0x00700232),
+    ("LineThickness", 0x00700253),
+    ("LocalNamespaceEntityID", 0x00400031),
+    ("Location", 0x00200050),
+    ("LongCodeValue", 0x00080119),
+    ("LongitudinalTemporalInformationModified", 0x00280303),
+    ("LossyImageCompression", 0x00282110),
+    ("LossyImageCompressionMethod", 0x00282114),
+    ("LossyImageCompressionRatio", 0x00282112),
+    ("LossyImageCompressionRetired", 0x00082110),
+    ("LowRRValue", 0x00181081),
+    ("MRAcquisitionFrequencyEncodingSteps", 0x00189058),
+    ("MRAcquisitionPhaseEncodingStepsInPlane", 0x00189231),
+    ("MRAcquisitionPhaseEncodingStepsOutOfPlane", 0x00189232),
+    ("MRAcquisitionType", 0x00180023),
+    ("MRArterialSpinLabelingSequence", 0x00189251),
+    ("MRAveragesSequence", 0x00189119),
+    ("MRDRDirectoryRecordOffset", 0x00041504),
+    ("MRDiffusionSequence", 0x00189117),
+    ("MREchoSequence", 0x00189114),
+    ("MRFOVGeometrySequence", 0x00189125),
+    ("MRImageFrameTypeSequence", 0x00189226),
+    ("MRImagingModifierSequence", 0x00189006),
+    ("MRMetaboliteMapSequence", 0x00189152),
+    ("MRModifierSequence", 0x00189115),
+    ("MRReceiveCoilSequence", 0x00189042),
+    ("MRSpatialSaturationSequence", 0x00189107),
+    ("MRSpectroscopyAcquisitionType", 0x00189200),
+    ("MRSpectroscopyFOVGeometrySequence", 0x00189103),
+    ("MRSpectroscopyFrameTypeSequence", 0x00189227),
+    ("MRTimingAndRelatedParametersSequence", 0x00189112),
+    ("MRTransmitCoilSequence", 0x00189049),
+    ("MRVelocityEncodingSequence", 0x00189197),
+    ("MagneticFieldStrength", 0x00180087),
+    ("MagnetizationTransfer", 0x00189020),
+    ("MajorTicksSequence", 0x00700287),
+    ("ManipulatedImage", 0x00280050),
+    ("Manufacturer", 0x00080070),
+    ("ManufacturerModelName", 0x00081090),
+    ("MappingResource", 0x00080105),
+    ("MappingResourceIdentificationSequence", 0x00080124),
+    ("MappingResourceName", 0x00080122),
+    ("MappingResourceUID", 0x00080118),
+    ("MaskFrameNumbers", 0x00286110),
+    ("MaskOperation", 0x00286101),
+    ("MaskOperationExplanation", 0x00286190),
+    ("MaskPointers", 0x00286030),
+    ("MaskSelectionMode", 0x00289454),
+    ("MaskSubPixelShift", 0x00286114),
+    ("MaskSubtractionSequence", 0x00286100),
+    ("MaskVisibilityPercentage", 0x00289478),
+    ("MaskingImage", 0x00200080),
+    ("MatrixRegistrationSequence", 0x00700309),
+    ("MatrixSequence", 0x0070030A),
+    ("MaximumCoordinateValue", 0x50000105),
+    ("MaximumFractionalValue", 0x0062000E),
+    ("MaximumStoredValueMapped", 0x00281232),
+    ("MeasuredAPDimension", 0x00101023),
+    ("MeasuredLateralDimension", 0x00101024),
+    ("MeasuredValueSequence", 0x0040A300),
+    ("MeasurementUnitsCodeSequence", 0x004008EA),
+    ("MeasuringUnitsSequence", 0x00400295),
+    ("MechanicalIndex", 0x00185022),
+    ("MediaStorageSOPClassUID", 0x00020002),
+    ("MediaStorageSOPInstanceUID", 0x00020003),
+    ("MedicalAlerts", 0x00102000),
+    ("MedicalRecordLocator", 0x00101090),
+    ("MetaboliteMapCodeSequence", 0x00189083),
+    ("MetaboliteMapDescription", 0x00189080),
+    ("MetersetExposure", 0x30020032),
+    ("MidSlabPosition", 0x00189106),
+    ("MilitaryRank", 0x00101080),
+    ("MinimumCoordinateValue", 0x50000104),
+    ("MinimumStoredValueMapped", 0x00281231),
+    ("ModalitiesInStudy", 0x00080061),
+    ("Modality", 0x00080060),
+    ("ModalityLUTSequence", 0x00283000),
+    ("ModalityLUTType", 0x00283004),
+    ("ModifierCodeSequence", 0x0040A195),
+    ("MultiCoilConfiguration", 0x00189046),
+    ("MultiCoilDefinitionSequence", 0x00189045),
+    ("MultiCoilElementName", 0x00189047),
+    ("MultiCoilElementUsed", 0x00189048),
+    ("MultiFramePresentationSequence", 0x00289505),
+    ("MultiFrameSourceSOPInstanceUID", 0x00081167),
+    ("MultiPlanarExcitation", 0x00189012),
+    ("MultipleSpinEcho", 0x00189011),
+    ("MultiplexGroupLabel", 0x003A0020),
+    ("MultiplexGroupTimeOffset", 0x00181068),
+    ("NTPSourceAddress", 0x00181803),
+    ("NameOfPhysiciansReadingStudy", 0x00081060),
+    ("NamesOfIntendedRecipientsOfResults", 0x00401010),
+    ("NetworkID", 0x00081000),
+    ("NominalBeamEnergy", 0x300A0114),
+    ("NominalCardiacTriggerDelayTime", 0x00209153),
+    ("NominalCardiacTriggerTimePriorToRPeak", 0x00209154),
+    ("NominalInterval", 0x00181062),
+    ("NominalPercentageOfCardiacPhase", 0x00209241),
+    ("NominalPercentageOfRespiratoryPhase", 0x00209245),
+    ("NominalRespiratoryTriggerDelayTime", 0x00209255),
+    ("NominalScannedPixelSpacing", 0x00182010),
+    ("NonUniformRadialSamplingCorrected", 0x00189766),
+    ("NonidentifyingPrivateElements", 0x00080304),
+    ("NormalizationPoint", 0x30040008),
+    ("NotchFilterBandwidth", 0x003A0223),
+    ("NotchFilterFrequency", 0x003A0222),
+    ("NuclearMedicineSeriesType", 0x00080042),
+    ("NumberOfAverages", 0x00180083),
+    ("NumberOfBeams", 0x300A0080),
+    ("NumberOfBlocks", 0x300A00F0),
+    ("NumberOfBoli", 0x300A00ED),
+    ("NumberOfBrachyApplicationSetups", 0x300A00A0),
+    ("NumberOfChannels", 0x50002004),
+    ("NumberOfCompensators", 0x300A00E0),
+    ("NumberOfContourPoints", 0x30060046),
+    ("NumberOfControlPoints", 0x300A0110),
+    ("NumberOfDetectors", 0x00540021),
+    ("NumberOfEnergyWindows", 0x00540011),
+    ("NumberOfEventTimers", 0x00082129),
+    ("NumberOfFractionsPlanned", 0x300A0078),
+    ("NumberOfFrames", 0x00280008),
+    ("NumberOfFramesInOverlay", 0x60000015),
+    ("NumberOfFramesInPhase", 0x00540033),
+    ("NumberOfFramesInRotation", 0x00540053),
+    ("NumberOfGraphicPoints", 0x00700021),
+    ("NumberOfIterations", 0x00189739),
+    ("NumberOfKSpaceTrajectories", 0x00189093),
+    ("NumberOfLeafJawPairs", 0x300A00BC),
+    ("NumberOfPatientRelatedInstances", 0x00201204),
+    ("NumberOfPatientRelatedSeries", 0x00201202),
+    ("NumberOfPatientRelatedStudies", 0x00201200),
+    ("NumberOfPhaseEncodingSteps", 0x00180089),
+    ("NumberOfPhases", 0x00540031),
+    ("NumberOfPoints", 0x50000010),
+    ("NumberOfRRIntervals", 0x00540061),
+    ("NumberOfReferences", 0x00041600),
+    ("NumberOfRotations", 0x00540051),
+    ("NumberOfSamples", 0x50002006),
+    ("NumberOfSeriesRelatedInstances", 0x00201209),
+    ("NumberOfSlices", 0x00540081),
+    ("NumberOfStages", 0x00082124),
+    ("NumberOfStudyRelatedInstances", 0x00201208),
+    ("NumberOfStudyRelatedSeries", 0x00201206),
+    ("NumberOfSubsets", 0x00189740),
+    ("NumberOfTemporalPositions", 0x00200105),
+    ("NumberOfTimeSlices", 0x00540101),
+    ("NumberOfTimeSlots", 0x00540071),
+    ("NumberOfTomosynthesisSourceImages", 0x00181495),
+    ("NumberOfTriggersInPhase", 0x00540211),
+    ("NumberOfViewsInStage", 0x0008212A),
+    ("NumberOfWaveformChannels", 0x003A0005),
+    ("NumberOfWaveformSamples", 0x003A0010),
+    ("NumberOfWedges", 0x300A00D0),
+    ("NumberOfZeroFills", 0x00189066),
+    ("NumericValue", 0x0040A30A),
+    ("NumericValueQualifierCodeSequence", 0x0040A301),
+    ("ObjectPixelSpacingInCenterOfBeam", 0x00189404),
+    ("ObjectThicknessSequence", 0x00189456),
+    ("ObservationDateTime", 0x0040A032),
+    ("ObservationNumber", 0x30060082),
+    ("ObserverType", 0x0040A084),
+    ("Occupation", 0x00102180),
+    ("OffsetOfReferencedLowerLevelDirectoryEntity", 0x00041420),
+    ("OffsetOfTheFirstDirectoryRecordOfTheRootDirectoryEntity", 0x00041200),
+    ("OffsetOfTheLastDirectoryRecordOfTheRootDirectoryEntity", 0x00041202),
+    ("OffsetOfTheNextDirectoryRecord", 0x00041400),
+    ("OperatingMode", 0x00189178),
+    ("OperatingModeSequence", 0x00189176),
+    ("OperatingModeType", 0x00189177),
+    ("OperatorIdentificationSequence", 0x00081072),
+    ("OperatorsName", 0x00081070),
+    ("OrderCallbackPhoneNumber", 0x00402010),
+    ("OrderCallbackTelecomInformation", 0x00402011),
+    ("OrderEnteredBy", 0x00402008),
+    ("OrderEntererLocation", 0x00402009),
+    ("OrderFillerIdentifierSequence", 0x00400027),
+    ("OrderPlacerIdentifierSequence", 0x00400026),
+    ("OrganDose", 0x00400316),
+    ("OrganExposed", 0x00400318),
+    ("OriginalSpecializedSOPClassUID", 0x0008001B),
+    ("OtherFailuresSequence", 0x0008119A),
+    ("OtherPatientIDs", 0x00101000),
+    ("OtherPatientIDsSequence", 0x00101002),
+    ("OtherPatientNames", 0x00101001),
+    ("OtherStudyNumbers", 0x00201070),
+    ("OutputPower", 0x00185000),
+    ("OverlayActivationLayer", 0x60001001),
+    ("OverlayBitPosition", 0x60000102),
+    ("OverlayBitsAllocated", 0x60000100),
+    ("OverlayColumns", 0x60000011),
+    ("OverlayComments", 0x60004000),
+    ("OverlayCompressionCode", 0x60000060),
+    ("OverlayData", 0x60003000),
+    ("OverlayDate", 0x00080024),
+    ("OverlayDescription", 0x60000022),
+    ("OverlayDescriptorGray", 0x60001100),
+    ("OverlayFormat", 0x60000110),
+    ("OverlayLabel", 0x60001500),
+    ("OverlayLocation", 0x60000200),
+    ("OverlayNumber", 0x00200022),
+    ("OverlayOrigin", 0x60000050),
+    ("OverlayPlaneOrigin", 0x60000052),
+    ("OverlayPlanes", 0x60000012),
+    ("OverlayRows", 0x60000010),
+    ("OverlaySubtype", 0x60000045),
+    ("OverlayTime", 0x00080034),
+    ("OverlayType", 0x60000040),
+    ("OversamplingPhase", 0x00189029),
+    ("PETDetectorMotionDetailsSequence", 0x00189733),
+    ("PETFrameAcquisitionSequence", 0x00189732),
+    ("PETFrameCorrectionFactorsSequence", 0x00189736),
+    ("PETFrameTypeSequence", 0x00189751),
+    ("PETPositionSequence", 0x00189735),
+    ("PETReconstructionSequence", 0x00189749),
+    ("PETTableDynamicsSequence", 0x00189734),
+    ("PVCRejection", 0x00181085),
+    ("PageNumberVector", 0x00182001),
+    ("PaletteColorLookupTableUID", 0x00281199),
+    ("ParallelAcquisition", 0x00189077),
+    ("ParallelAcquisitionTechnique", 0x00189078),
+    ("ParallelReductionFactorInPlane", 0x00189069),
+    ("ParallelReductionFactorInPlaneRetired", 0x00189096),
+    ("ParallelReductionFactorOutOfPlane", 0x00189155),
+    ("ParallelReductionFactorSecondInPlane", 0x00189168),
+    ("ParametricMapFrameTypeSequence", 0x00409092),
+    ("PartialFourier", 0x00189081),
+    ("PartialFourierDirection", 0x00189036),
+    ("PartialView", 0x00281350),
+    ("PartialViewCodeSequence", 0x00281352),
+    ("PartialViewDescription", 0x00281351),
+    ("ParticipantSequence", 0x0040A07A),
+    ("ParticipationDateTime", 0x0040A082),
+    ("ParticipationType", 0x0040A080),
+    ("PatientAddress", 0x00101040),
+    ("PatientAge", 0x00101010),
+    ("PatientAlternativeCalendar", 0x00100035),
+    ("PatientBirthDate", 0x00100030),
+    ("PatientBirthDateInAlternativeCalendar", 0x00100033),
+    ("PatientBirthName", 0x00101005),
+    ("PatientBirthTime", 0x00100032),
+    ("PatientBodyMassIndex", 0x00101022),
+    ("PatientBreedCodeSequence", 0x00102293),
+    ("PatientBreedDescription", 0x00102292),
+    ("PatientClinicalTrialParticipationSequence", 0x00380502),
+    ("PatientComments", 0x00104000),
+    ("PatientDeathDateInAlternativeCalendar", 0x00100034),
+    ("PatientFrameOfReferenceSource", 0x0020930C),
+    ("PatientGantryRelationshipCodeSequence", 0x00540414),
+    ("PatientID", 0x00100020),
+    ("PatientIdentityRemoved", 0x00120062),
+    ("PatientInstitutionResidence", 0x00380400),
+    ("PatientInsurancePlanCodeSequence", 0x00100050),
+    ("PatientMotherBirthName", 0x00101060),
+    ("PatientMotionCorrected", 0x00189763),
+    ("PatientName", 0x00100010),
+    ("PatientOrientation", 0x00200020),
+    ("PatientOrientationCodeSequence", 0x00540410),
+    ("PatientOrientationInFrameSequence", 0x00209450),
+    ("PatientOrientationModifierCodeSequence", 0x00540412),
+    ("PatientPhysiologicalStateCodeSequence", 0x00189772),
+    ("PatientPhysiologicalStateSequence", 0x00189771),
+    ("PatientPosition", 0x00185100),
+    ("PatientPrimaryLanguageCodeSequence", 0x00100101),
+    ("PatientPrimaryLanguageModifierCodeSequence", 0x00100102),
+    ("PatientReligiousPreference", 0x001021F0),
+    ("PatientSetupNumber", 0x300A0182),
+    ("PatientSetupSequence", 0x300A0180),
+    ("PatientSex", 0x00100040),
+    ("PatientSexNeutered", 0x00102203),
+    ("PatientSize", 0x00101020),
+    ("PatientSizeCodeSequence", 0x00101021),
+    ("PatientSpeciesCodeSequence", 0x00102202),
+    ("PatientSpeciesDescription", 0x00102201),
+    ("PatientState", 0x00380500),
+    ("PatientSupportAngle", 0x300A0122),
+    ("PatientSupportRotationDirection", 0x300A0123),
+    ("PatientTelecomInformation", 0x00102155),
+    ("PatientTelephoneNumbers", 0x00102154),
+    ("PatientTransportArrangements", 0x00401004),
+    ("PatientWeight", 0x00101030),
+    ("PatternOffColorCIELabValue", 0x00700252),
+    ("PatternOffOpacity", 0x00700285),
+    ("PatternOnColorCIELabValue", 0x00700251),
+    ("PatternOnOpacity", 0x00700284),
+    ("PauseBetweenFrames", 0x00540038),
+    ("PerFrameFunctionalGroupsSequence", 0x52009230),
+    ("PerProjectionAcquisitionSequence", 0x00189538),
+    ("PercentPhaseFieldOfView", 0x00180094),
+    ("PercentSampling", 0x00180093),
+    ("PerformedLocation", 0x00400243),
+    ("PerformedProcedureCodeSequence", 0x0040A372),
+    ("PerformedProcedureStepDescription", 0x00400254),
+    ("PerformedProcedureStepDiscontinuationReasonCodeSequence", 0x00400281),
+    ("PerformedProcedureStepEndDate", 0x00400250),
+    ("PerformedProcedureStepEndTime", 0x00400251),
+    ("PerformedProcedureStepID", 0x00400253),
+    ("PerformedProcedureStepStartDate", 0x00400244),
+    ("PerformedProcedureStepStartTime", 0x00400245),
+    ("PerformedProcedureStepStatus", 0x00400252),
+    ("PerformedProcedureTypeDescription", 0x00400255),
+    ("PerformedProtocolCodeSequence", 0x00400260),
+    ("PerformedSeriesSequence", 0x00400340),
+    ("PerformedStationAETitle", 0x00400241),
+    ("PerformedStationName", 0x00400242),
+    ("PerformingPhysicianIdentificationSequence", 0x00081052),
+    ("PerformingPhysicianName", 0x00081050),
+    ("PersonAddress", 0x00401102),
+    ("PersonIdentificationCodeSequence", 0x00401101),
+    ("PersonName", 0x0040A123),
+    ("PersonTelecomInformation", 0x00401104),
+    ("PersonTelephoneNumbers", 0x00401103),
+    ("PertinentDocumentsSequence", 0x00380100),
+    ("PertinentOtherEvidenceSequence", 0x0040A385),
+    ("PhaseContrast", 0x00189014),
+    ("PhaseDelay", 0x00540036),
+    ("PhaseDescription", 0x00540039),
+    ("PhaseInformationSequence", 0x00540032),
+    ("PhaseVector", 0x00540030),
+    ("PhosphorType", 0x00181261),
+    ("PhotometricInterpretation", 0x00280004),
+    ("PhototimerSetting", 0x00187065),
+    ("PhysicalDeltaX", 0x0018602C),
+    ("PhysicalDeltaY", 0x0018602E),
+    ("PhysicalDetectorSize", 0x00189429),
+    ("PhysicalUnitsXDirection", 0x00186024),
+    ("PhysicalUnitsYDirection", 0x00186026),
+    ("PhysiciansOfRecord", 0x00081048),
+    ("PhysiciansOfRecordIdentificationSequence", 0x00081049),
+    ("PhysiciansReadingStudyIdentificationSequence", 0x00081062),
+    ("PixelAspectRatio", 0x00280034),
+    ("PixelBandwidth", 0x00180095),
+    ("PixelData", 0x7FE00010),
+    ("PixelDataAreaOriginRelativeToFOV", 0x00187036),
+    ("PixelDataAreaRotationAngleRelativeToFOV", 0x00187038),
+    ("PixelIntensityRelationship", 0x00281040),
+    ("PixelIntensityRelationshipLUTSequence", 0x00289422),
+    ("PixelIntensityRelationshipSign", 0x00281041),
+    ("PixelMeasuresSequence", 0x00289110),
+    ("PixelPaddingRangeLimit", 0x00280121),
+    ("PixelPaddingValue", 0x00280120),
+    ("PixelPresentation", 0x00089205),
+    ("PixelRepresentation", 0x00280103),
+    ("PixelShiftFrameRange", 0x00289506),
+    ("PixelShiftSequence", 0x00289501),
+    ("PixelSpacing", 0x00280030),
+    ("PixelSpacingCalibrationDescription", 0x00280A04),
+    ("PixelSpacingCalibrationType", 0x00280A02),
+    ("PixelValueMappingCodeSequence", 0x00409098),
+    ("PixelValueTransformationSequence", 0x00289145),
+    ("PlacerOrderNumberImagingServiceRequest", 0x00402016),
+    ("PlanIntent", 0x300A000A),
+    ("PlanarConfiguration", 0x00280006),
+    ("PlaneIdentification", 0x00189457),
+    ("PlaneOrientationSequence", 0x00209116),
+    ("PlaneOrientationVolumeSequence", 0x0020930F),
+    ("PlanePositionSequence", 0x00209113),
+    ("PlanePositionVolumeSequence", 0x0020930E),
+    ("Planes", 0x00280012),
+    ("PlanesInAcquisition", 0x00189410),
+    ("PlateID", 0x00181004),
+    ("PlateType", 0x00181260),
+    ("PositionMeasuringDeviceUsed", 0x0018980C),
+    ("PositionOfIsocenterProjection", 0x00189430),
+    ("PositionReferenceIndicator", 0x00201040),
+    ("PositionerIsocenterDetectorRotationAngle", 0x00189465),
+    ("PositionerIsocenterPrimaryAngle", 0x00189463),
+    ("PositionerIsocenterSecondaryAngle", 0x00189464),
+    ("PositionerMotion", 0x00181500),
+    ("PositionerPositionSequence", 0x00189405),
+    ("PositionerPrimaryAngle", 0x00181510),
+    ("PositionerPrimaryAngleIncrement", 0x00181520),
+    ("PositionerSecondaryAngle", 0x00181511),
+    ("PositionerSecondaryAngleIncrement", 0x00181521),
+    ("PositionerType", 0x00181508),
+    ("PostprocessingFunction", 0x00185021),
+    ("PreMedication", 0x00400012),
+    ("PredecessorDocumentsSequence", 0x0040A360),
+    ("PregnancyStatus", 0x001021C0),
+    ("PreliminaryFlag", 0x0040A496),
+    ("PresentationCreationDate", 0x00700082),
+    ("PresentationCreationTime", 0x00700083),
+    ("PresentationIntentType", 0x00080068),
+    ("PresentationLUTSequence", 0x20500010),
+    ("PresentationLUTShape", 0x20500020),
+    ("PresentationPixelAspectRatio", 0x00700102),
+    ("PresentationPixelMagnificationRatio", 0x00700103),
+    ("PresentationPixelSpacing", 0x00700101),
+    ("PresentationSizeMode", 0x00700100),
+    ("PrimaryAnatomicStructureModifierSequence", 0x00082230),
+    ("PrimaryAnatomicStructureSequence", 0x00082228),
+    ("PrimaryDosimeterUnit", 0x300A00B3),
+    ("PrimaryPositionerIncrement", 0x00189514),
+    ("PrimaryPositionerScanArc", 0x00189508),
+    ("PrimaryPositionerScanStartAngle", 0x00189510),
+    ("PrimaryPromptsCountsAccumulated", 0x00541310),
+    ("PrivateCreatorReference", 0x00080302),
+    ("PrivateDataElementCharacteristicsSequence", 0x00080300),
+    ("PrivateGroupReference", 0x00080301),
+    ("PrivateInformation", 0x00020102),
+    ("PrivateInformationCreatorUID", 0x00020100),
+    ("PrivateRecordUID", 0x00041432),
+    ("ProcedureCodeSequence", 0x00081032),
+    ("ProcessingFunction", 0x00185020),
+    ("ProjectionEponymousNameCodeSequence", 0x00185104),
+    ("ProjectionPixelCalibrationSequence", 0x00189401),
+    ("ProtocolContextSequence", 0x00400440),
+    ("ProtocolName", 0x00181030),
+    ("PulseRepetitionFrequency", 0x00186032),
+    ("PulseSequenceName", 0x00189005),
+    ("PurposeOfReferenceCodeSequence", 0x0040A170),
+    ("QuadratureReceiveCoil", 0x00189044),
+    ("QualityControlImage", 0x00280300),
+    ("QualityControlSubject", 0x00100200),
+    ("QualityControlSubjectTypeCodeSequence", 0x00100201),
+    ("Quantity", 0x00400294),
+    ("QuantityDefinitionSequence", 0x00409220),
+    ("QuantitySequence", 0x00400293),
+    ("QueryRetrieveLevel", 0x00080052),
+    ("QueryRetrieveView", 0x00080053),
+    ("RFEchoTrainLength", 0x00189240),
+    ("RGBLUTTransferFunction", 0x0028140F),
+    ("ROIArea", 0x60001301),
+    ("ROIContourSequence", 0x30060039),
+    ("ROIDescription", 0x30060028),
+    ("ROIDisplayColor", 0x3006002A),
+    ("ROIGenerationAlgorithm", 0x30060036),
+    ("ROIGenerationDescription", 0x30060038),
+    ("ROIInterpreter", 0x300600A6),
+    ("ROIMean", 0x60001302),
+    ("ROIName", 0x30060026),
+    ("ROINumber", 0x30060022),
+    ("ROIObservationDescription", 0x30060088),
+    ("ROIObservationLabel", 0x30060085),
+    ("ROIPhysicalPropertiesSequence", 0x300600B0),
+    ("ROIPhysicalProperty", 0x300600B2),
+    ("ROIPhysicalPropertyValue", 0x300600B4),
+    ("ROIStandardDeviation", 0x60001303),
+    ("ROIVolume", 0x3006002C),
+    ("RRIntervalTimeNominal", 0x00209251),
+    ("RRIntervalVector", 0x00540060),
+    ("RTBeamLimitingDeviceType", 0x300A00B8),
+    ("RTDoseROISequence", 0x30040010),
+    ("RTImageDescription", 0x30020004),
+    ("RTImageLabel", 0x30020002),
+    ("RTImageName", 0x30020003),
+    ("RTImagePlane", 0x3002000C),
+    ("RTImagePosition", 0x30020012),
+    ("RTImageSID", 0x30020026),
+    ("RTPlanDate", 0x300A0006),
+    ("RTPlanDescription", 0x300A0004),
+    ("RTPlanGeometry", 0x300A000C),
+    ("RTPlanLabel", 0x300A0002),
+    ("RTPlanName", 0x300A0003),
+    ("RTPlanTime", 0x300A0007),
+    ("RTROIIdentificationCodeSequence", 0x30060086),
+    ("RTROIInterpretedType", 0x300600A4),
+    ("RTROIObservationsSequence", 0x30060080),
+    ("RTReferencedSeriesSequence", 0x30060014),
+    ("RTReferencedStudySequence", 0x30060012),
+    ("RWavePointer", 0x00286040),
+    ("RadialPosition", 0x00181142),
+    ("RadiationMachineName", 0x30020020),
+    ("RadiationMachineSAD", 0x30020022),
+    ("RadiationMode", 0x0018115A),
+    ("RadiationSetting", 0x00181155),
+    ("RadiationType", 0x300A00C6),
+    ("Radionuclide", 0x00180030),
+    ("RadionuclideCodeSequence", 0x00540300),
+    ("RadionuclideHalfLife", 0x00181075),
+    ("RadionuclidePositronFraction", 0x00181076),
+    ("RadionuclideTotalDose", 0x00181074),
+    ("Radiopharmaceutical", 0x00180031),
+    ("RadiopharmaceuticalAgentNumber", 0x00189729),
+    ("RadiopharmaceuticalCodeSequence", 0x00540304),
+    ("RadiopharmaceuticalInformationSequence", 0x00540016),
+    ("RadiopharmaceuticalRoute", 0x00181070),
+    ("RadiopharmaceuticalSpecificActivity", 0x00181077),
+    ("RadiopharmaceuticalStartDateTime", 0x00181078),
+    ("RadiopharmaceuticalStartTime", 0x00181072),
+    ("RadiopharmaceuticalStopDateTime", 0x00181079),
+    ("RadiopharmaceuticalStopTime", 0x00181073),
+    ("RadiopharmaceuticalUsageSequence", 0x00189737),
+    ("RadiopharmaceuticalVolume", 0x00181071),
+    ("RadiusOfCircularCollimator", 0x00181712),
+    ("RadiusOfCircularExposureControlSensingRegion", 0x00189441),
+    ("RadiusOfCircularShutter", 0x00181612),
+    ("RandomsCorrected", 0x00189765),
+    ("RandomsCorrectionMethod", 0x00541100),
+    ("RationalDenominatorValue", 0x0040A163),
+    ("RationalNumeratorValue", 0x0040A162),
+    ("RealWorldValueFirstValueMapped", 0x00409216),
+    ("RealWorldValueIntercept", 0x00409224),
+    ("RealWorldValueLUTData", 0x00409212),
+    ("RealWorldValueLastValueMapped", 0x00409211),
+    ("RealWorldValueMappingSequence", 0x00409096),
+    ("RealWorldValueSlope", 0x00409225),
+    ("ReasonForPerformedProcedureCodeSequence", 0x00401012),
+    ("ReasonForRequestedProcedureCodeSequence", 0x0040100A),
+    ("ReasonForStudy", 0x00321030),
+    ("ReasonForTheImagingServiceRequest", 0x00402001),
+    ("ReasonForTheRequestedProcedure", 0x00401002),
+    ("ReasonForVisit", 0x00321066),
+    ("ReasonForVisitCodeSequence", 0x00321067),
+    ("ReceiveCoilManufacturerName", 0x00189041),
+    ("ReceiveCoilName", 0x00181250),
+    ("ReceiveCoilType", 0x00189043),
+    ("ReceivingApplicationEntityTitle", 0x00020018),
+    ("ReceivingPresentationAddress", 0x00020028),
+    ("RecognitionCode", 0x00080010),
+    ("RecognizableVisualFeatures", 0x00280302),
+    ("RecommendedDisplayCIELabValue", 0x0062000D),
+    ("RecommendedDisplayFrameRate", 0x00082144),
+    ("RecommendedDisplayFrameRateInFloat", 0x00089459),
+    ("RecommendedDisplayGrayscaleValue", 0x0062000C),
+    ("RecommendedViewingMode", 0x00281090),
+    ("ReconstructionAlgorithm", 0x00189315),
+    ("ReconstructionAngle", 0x00189319),
+    ("ReconstructionDescription", 0x00189531),
+    ("ReconstructionDiameter", 0x00181100),
+    ("ReconstructionFieldOfView", 0x00189317),
+    ("ReconstructionIndex", 0x00209536),
+    ("ReconstructionMethod", 0x00541103),
+    ("ReconstructionPixelSpacing", 0x00189322),
+    ("ReconstructionTargetCenterPatient", 0x00189318),
+    ("ReconstructionType", 0x00189756),
+    ("RecordInUseFlag", 0x00041410),
+    ("RectificationType", 0x00181156),
+    ("RectilinearPhaseEncodeReordering", 0x00189034),
+    ("RedPaletteColorLookupTableData", 0x00281201),
+    ("RedPaletteColorLookupTableDescriptor", 0x00281101),
+    ("Reference", 0x00201020),
+    ("ReferencePixelPhysicalValueX", 0x00186028),
+    ("ReferencePixelPhysicalValueY", 0x0018602A),
+    ("ReferencePixelX0", 0x00186020),
+    ("ReferencePixelY0", 0x00186022),
+    ("ReferencedBeamNumber", 0x300C0006),
+    ("ReferencedBeamSequence", 0x300C0004),
+    ("ReferencedColorPaletteInstanceUID", 0x00280304),
+    ("ReferencedContentItemIdentifier", 0x0040DB73),
+    ("ReferencedCurveSequence", 0x00081145),
+    ("ReferencedDateTime", 0x0040A13A),
+    ("ReferencedDoseSequence", 0x300C0080),
+    ("ReferencedFileID", 0x00041500),
+    ("ReferencedFractionGroupNumber", 0x300C0022),
+    ("ReferencedFractionGroupSequence", 0x300C0020),
+    ("ReferencedFrameNumber", 0x00081160),
+    ("ReferencedFrameNumbers", 0x0040A136),
+    ("ReferencedFrameOfReferenceSequence", 0x30060010),
+    ("ReferencedFrameOfReferenceUID", 0x30060024),
+    ("ReferencedImageEvidenceSequence", 0x00089092),
+    ("ReferencedImageRealWorldValueMappingSequence", 0x00409094),
+    ("ReferencedImageSequence", 0x00081140),
+    ("ReferencedInstanceSequence", 0x0008114A),
+    ("ReferencedOtherPlaneSequence", 0x00089410),
+    ("ReferencedOverlaySequence", 0x00081130),
+    ("ReferencedPatientAliasSequence", 0x00380004),
+    ("ReferencedPatientPhotoSequence", 0x00101100),
+    ("ReferencedPatientSequence", 0x00081120),
+    ("ReferencedPatientSetupNumber", 0x300C006A),
+    ("ReferencedPerformedProcedureStepSequence", 0x00081111),
+    ("ReferencedPresentationLUTSequence", 0x20500500),
+    ("ReferencedPresentationStateSequence", 0x00089237),
+    ("ReferencedProcedureStepSequence", 0x00400330),
+    ("ReferencedROINumber", 0x30060084),
+    ("ReferencedRTPlanSequence", 0x300C0002),
+    ("ReferencedRawDataSequence", 0x00089121),
+    ("ReferencedRealWorldValueMappingInstanceSequence", 0x0008114B),
+    ("ReferencedRelatedGeneralSOPClassUIDInFile", 0x0004151A),
+    ("ReferencedRequestSequence", 0x0040A370),
+    ("ReferencedResultsSequence", 0x00081100),
+    ("ReferencedSOPClassUID", 0x00081150),
+    ("ReferencedSOPClassUIDInFile", 0x00041510),
+    ("ReferencedSOPInstanceUID", 0x00081155),
+    ("ReferencedSOPInstanceUIDInFile", 0x00041511),
+    ("ReferencedSOPSequence", 0x00081199),
+    ("ReferencedSamplePositions", 0x0040A132),
+    ("ReferencedSegmentNumber", 0x0062000B),
+    ("ReferencedSeriesSequence", 0x00081115),
+    ("ReferencedSpatialRegistrationSequence", 0x00700404),
+    ("ReferencedStereometricInstanceSequence", 0x00081134),
+    ("ReferencedStructureSetSequence", 0x300C0060),
+    ("ReferencedStudySequence", 0x00081110),
+    ("ReferencedTimeOffsets", 0x0040A138),
+    ("ReferencedTransferSyntaxUIDInFile", 0x00041512),
+    ("ReferencedVisitSequence", 0x00081125),
+    ("ReferencedWaveformChannels", 0x0040A0B0),
+    ("ReferringPhysicianAddress", 0x00080092),
+    ("ReferringPhysicianIdentificationSequence", 0x00080096),
+    ("ReferringPhysicianName", 0x00080090),
+    ("ReferringPhysicianTelephoneNumbers", 0x00080094),
+    ("RegionDataType", 0x00186014),
+    ("RegionFlags", 0x00186016),
+    ("RegionLocationMaxX1", 0x0018601C),
+    ("RegionLocationMaxY1", 0x0018601E),
+    ("RegionLocationMinX0", 0x00186018),
+    ("RegionLocationMinY0", 0x0018601A),
+    ("RegionOfResidence", 0x00102152),
+    ("RegionPixelShiftSequence", 0x00289502),
+    ("RegionSpatialFormat", 0x00186012),
+    ("RegistrationSequence", 0x00700308),
+    ("RegistrationTypeCodeSequence", 0x0070030D),
+    ("RelatedFrameOfReferenceUID", 0x300600C2),
+    ("RelatedGeneralSOPClassUID", 0x0008001A),
+    ("RelatedSeriesSequence", 0x00081250),
+    ("RelationshipType", 0x0040A010),
+    ("RelativeOpacity", 0x00700403),
+    ("RelativeXRayExposure", 0x00181405),
+    ("RepetitionTime", 0x00180080),
+    ("ReportNumber", 0x002000AA),
+    ("ReportedValuesOrigin", 0x3002000A),
+    ("ReportingPriority", 0x00401009),
+    ("RepositoryUniqueID", 0x0040E030),
+    ("RepresentativeFrameNumber", 0x00286010),
+    ("ReprojectionMethod", 0x00541004),
+    ("RequestAttributesSequence", 0x00400275),
+    ("RequestedContrastAgent", 0x00321070),
+    ("RequestedProcedureCodeSequence", 0x00321064),
+    ("RequestedProcedureComments", 0x00401400),
+    ("RequestedProcedureDescription", 0x00321060),
+    ("RequestedProcedureID", 0x00401001),
+    ("RequestedProcedureLocation", 0x00401005),
+    ("RequestedProcedurePriority", 0x00401003),
+    ("RequestingPhysician", 0x00321032),
+    ("RequestingPhysicianIdentificationSequence", 0x00321031),
+    ("RequestingService", 0x00321033),
+    ("RequestingServiceCodeSequence", 0x00321034),
+    ("RescaleIntercept", 0x00281052),
+    ("RescaleSlope", 0x00281053),
+    ("RescaleType", 0x00281054),
+    ("ResidualSyringeCounts", 0x00540017),
+    ("ResonantNucleus", 0x00189100),
+    ("RespiratoryCyclePosition", 0x00189214),
+    ("RespiratoryIntervalTime", 0x00209254),
+    ("RespiratoryMotionCompensationTechnique", 0x00189170),
+    ("RespiratoryMotionCompensationTechniqueDescription", 0x00189185),
+    ("RespiratorySignalSource", 0x00189171),
+    ("RespiratorySignalSourceID", 0x00189186),
+    ("RespiratorySynchronizationSequence", 0x00209253),
+    ("RespiratoryTriggerDelayThreshold", 0x00209256),
+    ("RespiratoryTriggerType", 0x00209250),
+    ("ResponsibleOrganization", 0x00102299),
+    ("ResponsiblePerson", 0x00102297),
+    ("ResponsiblePersonRole", 0x00102298),
+    ("RetrieveAETitle", 0x00080054),
+    ("RetrieveLocationUID", 0x0040E011),
+    ("RetrieveURI", 0x0040E010),
+    ("RetrieveURL", 0x00081190),
+    ("ReviewDate", 0x300E0004),
+    ("ReviewTime", 0x300E0005),
+    ("ReviewerName", 0x300E0008),
+    ("RevolutionTime", 0x00189305),
+    ("RotationAngle", 0x00700230),
+    ("RotationDirection", 0x00181140),
+    ("RotationInformationSequence", 0x00540052),
+    ("RotationOfScannedFilm", 0x00182030),
+    ("RotationPoint", 0x00700273),
+    ("RotationVector", 0x00540050),
+    ("RouteOfAdmissions", 0x00380016),
+    ("Rows", 0x00280010),
+    ("SAR", 0x00181316),
+    ("SOPClassUID", 0x00080016),
+    ("SOPClassesInStudy", 0x00080062),
+    ("SOPClassesSupported", 0x0008115A),
+    ("SOPInstanceUID", 0x00080018),
+    ("SOPInstanceUIDOfConcatenationSource", 0x00200242),
+    ("SUVType", 0x00541006),
+    ("SampleRate", 0x50002008),
+    ("SamplesPerPixel", 0x00280002),
+    ("SamplesPerPixelUsed", 0x00280003),
+    ("SamplingFrequency", 0x003A001A),
+    ("SaturationRecovery", 0x00189024),
+    ("ScanArc", 0x00181143),
+    ("ScanLength", 0x00181302),
+    ("ScanOptions", 0x00180022),
+    ("ScanProgressionDirection", 0x00540501),
+    ("ScanVelocity", 0x00181300),
+    ("ScanningSequence", 0x00180020),
+    ("ScatterCorrected", 0x00189760),
+    ("ScatterCorrectionMethod", 0x00541105),
+    ("ScatterFractionFactor", 0x00541323),
+    ("ScheduledPerformingPhysicianIdentificationSequence", 0x0040000B),
+    ("ScheduledPerformingPhysicianName", 0x00400006),
+    ("ScheduledProcedureStepDescription", 0x00400007),
+    ("ScheduledProcedureStepEndDate", 0x00400004),
+    ("ScheduledProcedureStepEndTime", 0x00400005),
+    ("ScheduledProcedureStepID", 0x00400009),
+    ("ScheduledProcedureStepLocation", 0x00400011),
+    ("ScheduledProcedureStepSequence", 0x00400100),
+    ("ScheduledProcedureStepStartDate", 0x00400002),
+    ("ScheduledProcedureStepStartTime", 0x00400003),
+    ("ScheduledProcedureStepStatus", 0x00400020),
+    ("ScheduledProtocolCodeSequence", 0x00400008),
+    ("ScheduledSpecimenSequence", 0x00400500),
+    ("ScheduledStationAETitle", 0x00400001),
+    ("ScheduledStationName", 0x00400010),
+    ("ScheduledStepAttributesSequence", 0x00400270),
+    ("ScheduledStudyLocation", 0x00321020),
+    ("ScheduledStudyLocationAETitle", 0x00321021),
+    ("ScheduledStudyStartDate", 0x00321000),
+    ("ScheduledStudyStartTime", 0x00321001),
+    ("ScheduledStudyStopDate", 0x00321010),
+    ("ScheduledStudyStopTime", 0x00321011),
+    ("SecondaryCaptureDeviceID", 0x00181010),
+    ("SecondaryCaptureDeviceManufacturer", 0x00181016),
+    ("SecondaryCaptureDeviceManufacturerModelName", 0x00181018),
+    ("SecondaryCaptureDeviceSoftwareVersions", 0x00181019),
+    ("SecondaryCountsAccumulated", 0x00541311),
+    ("SecondaryCountsType", 0x00541220),
+    ("SecondaryPositionerIncrement", 0x00189515),
+    ("SecondaryPositionerScanArc", 0x00189509),
+    ("SecondaryPositionerScanStartAngle", 0x00189511),
+    ("SegmentAlgorithmName", 0x00620009),
+    ("SegmentAlgorithmType", 0x00620008),
+    ("SegmentDescription", 0x00620006),
+    ("SegmentIdentificationSequence", 0x0062000A),
+    ("SegmentLabel", 0x00620005),
+    ("SegmentNumber", 0x00620004),
+    ("SegmentSequence", 0x00620002),
+    ("SegmentationFractionalType", 0x00620010),
+    ("SegmentationType", 0x00620001),
+    ("SegmentedAlphaPaletteColorLookupTableData", 0x00281224),
+    ("SegmentedBluePaletteColorLookupTableData", 0x00281223),
+    ("SegmentedGreenPaletteColorLookupTableData", 0x00281222),
+    ("SegmentedKSpaceTraversal", 0x00189033),
+    ("SegmentedPropertyCategoryCodeSequence", 0x00620003),
+    ("SegmentedPropertyTypeCodeSequence", 0x0062000F),
+    ("SegmentedPropertyTypeModifierCodeSequence", 0x00620011),
+    ("SegmentedRedPaletteColorLookupTableData", 0x00281221),
+    ("SegmentsOverlap", 0x00620013),
+    ("SendingApplicationEntityTitle", 0x00020017),
+    ("SendingPresentationAddress", 0x00020027),
+    ("Sensitivity", 0x00186000),
+    ("SensitivityCalibrated", 0x00189767),
+    ("SequenceName", 0x00180024),
+    ("SequenceOfUltrasoundRegions", 0x00186011),
+    ("SequenceVariant", 0x00180021),
+    ("SeriesDate", 0x00080021),
+    ("SeriesDescription", 0x0008103E),
+    ("SeriesInStudy", 0x00201000),
+    ("SeriesInstanceUID", 0x0020000E),
+    ("SeriesNumber", 0x00200011),
+    ("SeriesTime", 0x00080031),
+    ("SeriesType", 0x00541000),
+    ("ServiceEpisodeDescription", 0x00380062),
+    ("ServiceEpisodeID", 0x00380060),
+    ("ShadowColorCIELabValue", 0x00700247),
+    ("ShadowOffsetX", 0x00700245),
+    ("ShadowOffsetY", 0x00700246),
+    ("ShadowOpacity", 0x00700258),
+    ("ShadowStyle", 0x00700244),
+    ("ShapeType", 0x00700306),
+    ("SharedFunctionalGroupsSequence", 0x52009229),
+    ("ShowTickLabel", 0x00700278),
+    ("ShutterLeftVerticalEdge", 0x00181602),
+    ("ShutterLowerHorizontalEdge", 0x00181608),
+    ("ShutterOverlayGroup", 0x00181623),
+    ("ShutterPresentationColorCIELabValue", 0x00181624),
+    ("ShutterPresentationValue", 0x00181622),
+    ("ShutterRightVerticalEdge", 0x00181604),
+    ("ShutterShape", 0x00181600),
+    ("ShutterUpperHorizontalEdge", 0x00181606),
+    ("SignalDomainColumns", 0x00289003),
+    ("SimpleFrameList", 0x00081161),
+    ("SingleCollimationWidth", 0x00189306),
+    ("SkipBeats", 0x00181086),
+    ("SkipFrameRangeFlag", 0x00089460),
+    ("SlabOrientation", 0x00189105),
+    ("SlabThickness", 0x00189104),
+    ("SliceLocation", 0x00201041),
+    ("SliceLocationVector", 0x00182005),
+    ("SliceProgressionDirection", 0x00540500),
+    ("SliceSensitivityFactor", 0x00541320),
+    ("SliceThickness", 0x00180050),
+    ("SliceVector", 0x00540080),
+    ("SlideIdentifier", 0x004006FA),
+    ("SmallestImagePixelValue", 0x00280106),
+    ("SmallestImagePixelValueInPlane", 0x00280110),
+    ("SmallestPixelValueInSeries", 0x00280108),
+    ("SmallestValidPixelValue", 0x00280104),
+    ("SmokingStatus", 0x001021A0),
+    ("SoftTissueFocusThermalIndex", 0x00185028),
+    ("SoftTissueSurfaceThermalIndex", 0x00185029),
+    ("SoftTissueThermalIndex", 0x00185027),
+    ("SoftwareVersions", 0x00181020),
+    ("SourceApplicationEntityTitle", 0x00020016),
+    ("SourceAxisDistance", 0x300A00B4),
+    ("SourceImageEvidenceSequence", 0x00089154),
+    ("SourceImageSequence", 0x00082112),
+    ("SourcePresentationAddress", 0x00020026),
+    ("SourceToSurfaceDistance", 0x300A0130),
+    ("SourceWaveformSequence", 0x003A020A),
+    ("SpacingBetweenSlices", 0x00180088),
+    ("SpatialLocationsPreserved", 0x0028135A),
+    ("SpatialPresaturation", 0x00189027),
+    ("SpatialResolution", 0x00181050),
+    ("SpatialTransformOfDose", 0x30040005),
+    ("SpecialNeeds", 0x00380050),
+    ("SpecificAbsorptionRateDefinition", 0x00189179),
+    ("SpecificAbsorptionRateSequence", 0x00189239),
+    ("SpecificAbsorptionRateValue", 0x00189181),
+    ("SpecificCharacterSet", 0x00080005),
+    ("SpecificCharacterSetOfFileSetDescriptorFile", 0x00041142),
+    ("SpecimenDescriptionSequence", 0x00400560),
+    ("SpecimenDetailedDescription", 0x00400602),
+    ("SpecimenIdentifier", 0x00400551),
+    ("SpecimenLocalizationContentItemSequence", 0x00400620),
+    ("SpecimenPreparationSequence", 0x00400610),
+    ("SpecimenPreparationStepContentItemSequence", 0x00400612),
+    ("SpecimenShortDescription", 0x00400600),
+    ("SpecimenTypeCodeSequence", 0x0040059A),
+    ("SpecimenUID", 0x00400554),
+    ("SpectralWidth", 0x00189052),
+    ("SpectrallySelectedExcitation", 0x00189026),
+    ("SpectrallySelectedSuppression", 0x00189025),
+    ("SpectroscopyAcquisitionDataColumns", 0x00189127),
+    ("SpectroscopyAcquisitionOutOfPlanePhaseSteps", 0x00189159),
+    ("SpectroscopyAcquisitionPhaseColumns", 0x00189234),
+    ("SpectroscopyAcquisitionPhaseRows", 0x00189095),
+    ("SpiralPitchFactor", 0x00189311),
+    ("Spoiling", 0x00189016),
+    ("StackID", 0x00209056),
+    ("StageCodeSequence", 0x0040000A),
+    ("StageName", 0x00082120),
+    ("StageNumber", 0x00082122),
+    ("StartAcquisitionDateTime", 0x00189516),
+    ("StartAngle", 0x00540200),
+    ("StartCardiacTriggerCountThreshold", 0x00189717),
+    ("StartDensityThreshold", 0x00189715),
+    ("StartRelativeDensityDifferenceThreshold", 0x00189716),
+    ("StartRespiratoryTriggerCountThreshold", 0x00189718),
+    ("StartTrim", 0x00082142),
+    ("StartingRespiratoryAmplitude", 0x00209246),
+    ("StartingRespiratoryPhase", 0x00209247),
+    ("StationAETitle", 0x00080055),
+    ("StationName", 0x00081010),
+    ("SteadyStatePulseSequence", 0x00189017),
+    ("SteeringAngle", 0x00186036),
+    ("StopTrim", 0x00082143),
+    ("StorageMediaFileSetID", 0x00880130),
+    ("StorageMediaFileSetUID", 0x00880140),
+    ("StoredValueColorRangeSequence", 0x00281230),
+    ("StrainDescription", 0x00100212),
+    ("StructureSetDate", 0x30060008),
+    ("StructureSetDescription", 0x30060006),
+    ("StructureSetLabel", 0x30060002),
+    ("StructureSetName", 0x30060004),
+    ("StructureSetROISequence", 0x30060020),
+    ("StructureSetTime", 0x30060009),
+    ("StudiesContainingOtherReferencedInstancesSequence", 0x00081200),
+    ("StudyArrivalDate", 0x00321040),
+    ("StudyArrivalTime", 0x00321041),
+    ("StudyComments", 0x00324000),
+    ("StudyCompletionDate", 0x00321050),
+    ("StudyCompletionTime", 0x00321051),
+    ("StudyComponentStatusID", 0x00321055),
+    ("StudyDate", 0x00080020),
+    ("StudyDescription", 0x00081030),
+    ("StudyID", 0x00200010),
+    ("StudyIDIssuer", 0x00320012),
+    ("StudyInstanceUID", 0x0020000D),
+    ("StudyPriorityID", 0x0032000C),
+    ("StudyReadDate", 0x00320034),
+    ("StudyReadTime", 0x00320035),
+    ("StudyStatusID", 0x0032000A),
+    ("StudyTime", 0x00080030),
+    ("StudyVerifiedDate", 0x00320032),
+    ("StudyVerifiedTime", 0x00320033),
+    ("SubtractionItemID", 0x00289416),
+    ("SynchronizationChannel", 0x0018106C),
+    ("SynchronizationFrameOfReferenceUID", 0x00200200),
+    ("SynchronizationTrigger", 0x0018106A),
+    ("T2Preparation", 0x00189021),
+    ("TIDOffset", 0x00286120),
+    ("TableAngle", 0x00181138),
+    ("TableCradleTiltAngle", 0x00189471),
+    ("TableFeedPerRotation", 0x00189310),
+    ("TableFrameOfReferenceUID", 0x00209313),
+    ("TableHeadTiltAngle", 0x00189470),
+    ("TableHeight", 0x00181130),
+    ("TableHorizontalRotationAngle", 0x00189469),
+    ("TableLateralIncrement", 0x00181136),
+    ("TableLongitudinalIncrement", 0x00181137),
+    ("TableMotion", 0x00181134),
+    ("TablePosition", 0x00189327),
+    ("TablePositionSequence", 0x00189406),
+    ("TableSpeed", 0x00189309),
+    ("TableTraverse", 0x00181131),
+    ("TableType", 0x0018113A),
+    ("TableVerticalIncrement", 0x00181135),
+    ("TableXPositionToIsocenter", 0x00189466),
+    ("TableYPositionToIsocenter", 0x00189467),
+    ("TableZPositionToIsocenter", 0x00189468),
+    ("TagAngleFirstAxis", 0x00189019),
+    ("TagAngleSecondAxis", 0x00189219),
+    ("TagSpacingFirstDimension", 0x00189030),
+    ("TagSpacingSecondDimension", 0x00189218),
+    ("TagThickness", 0x00189035),
+    ("Tagging", 0x00189028),
+    ("TaggingDelay", 0x00189184),
+    ("TargetExposureIndex", 0x00181412),
+    ("TargetPrescriptionDose", 0x300A0026),
+    ("TemplateIdentifier", 0x0040DB00),
+    ("TemplateLocalVersion", 0x0040DB07),
+    ("TemplateVersion", 0x0040DB06),
+    ("TemporalPositionIdentifier", 0x00200100),
+    ("TemporalPositionIndex", 0x00209128),
+    ("TemporalPositionSequence", 0x00209310),
+    ("TemporalPositionTimeOffset", 0x0020930D),
+    ("TemporalRangeType", 0x0040A130),
+    ("TemporalResolution", 0x00200110),
+    ("TerminationCardiacTriggerCountThreshold", 0x00189723),
+    ("TerminationCountsThreshold", 0x00189719),
+    ("TerminationDensityThreshold", 0x00189720),
+    ("TerminationRelativeDensityThreshold", 0x00189721),
+    ("TerminationRespiratoryTriggerCountThreshold", 0x00189724),
+    ("TerminationTimeThreshold", 0x00189722),
+    ("TextColorCIELabValue", 0x00700241),
+    ("TextObjectSequence", 0x00700008),
+    ("TextStyleSequence", 0x00700231),
+    ("TextValue", 0x0040A160),
+    ("TickAlignment", 0x00700274),
+    ("TickLabel", 0x00700289),
+    ("TickLabelAlignment", 0x00700279),
+    ("TickPosition", 0x00700288),
+    ("Time", 0x0040A122),
+    ("TimeDistributionProtocol", 0x00181802),
+    ("TimeDomainFiltering", 0x00189065),
+    ("TimeOfFlightContrast", 0x00189015),
+    ("TimeOfFlightInformationUsed", 0x00189755),
+    ("TimeOfLastCalibration", 0x00181201),
+    ("TimeOfLastDetectorCalibration", 0x0018700E),
+    ("TimeOfSecondaryCapture", 0x00181014),
+    ("TimeRange", 0x00081163),
+    ("TimeSliceVector", 0x00540100),
+    ("TimeSlotInformationSequence", 0x00540072),
+    ("TimeSlotTime", 0x00540073),
+    ("TimeSlotVector", 0x00540070),
+    ("TimeSource", 0x00181801),
+    ("TimezoneOffsetFromUTC", 0x00080201),
+    ("TissueHeterogeneityCorrection", 0x30040014),
+    ("ToleranceTableSequence", 0x300A0040),
+    ("TomoAngle", 0x00181470),
+    ("TomoClass", 0x00181491),
+    ("TomoLayerHeight", 0x00181460),
+    ("TomoTime", 0x00181480),
+    ("TomoType", 0x00181490),
+    ("TopicAuthor", 0x00880910),
+    ("TopicKeywords", 0x00880912),
+    ("TopicSubject", 0x00880906),
+    ("TopicTitle", 0x00880904),
+    ("TotalCollimationWidth", 0x00189307),
+    ("TotalNumberOfExposures", 0x00400301),
+    ("TotalTime", 0x5000200A),
+    ("TotalTimeOfFluoroscopy", 0x00400300),
+    ("TrackingID", 0x00620020),
+    ("TrackingUID", 0x00620021),
+    ("TransactionUID", 0x00081195),
+    ("TransducerApplicationCodeSequence", 0x0018980F),
+    ("TransducerBeamSteeringCodeSequence", 0x0018980E),
+    ("TransducerData", 0x00185010),
+    ("TransducerFrequency", 0x00186030),
+    ("TransducerGeometryCodeSequence", 0x0018980D),
+    ("TransducerOrientation", 0x00082204),
+    ("TransducerPosition", 0x00082200),
+    ("TransducerScanPatternCodeSequence", 0x00189809),
+    ("TransducerType", 0x00186031),
+    ("TransferSyntaxUID", 0x00020010),
+    ("TransmitCoilManufacturerName", 0x00189050),
+    ("TransmitCoilName", 0x00181251),
+    ("TransmitCoilType", 0x00189051),
+    ("TransmitterFrequency", 0x00189098),
+    ("TransverseDetectorSeparation", 0x00189726),
+    ("TransverseMash", 0x00541202),
+    ("TreatmentDeliveryType", 0x300A00CE),
+    ("TreatmentMachineName", 0x300A00B2),
+    ("TriggerSamplePosition", 0x0018106E),
+    ("TriggerSourceOrType", 0x00181061),
+    ("TriggerTime", 0x00181060),
+    ("TriggerTimeOffset", 0x00181069),
+    ("TriggerVector", 0x00540210),
+    ("TriggerWindow", 0x00181094),
+    ("TubeAngle", 0x00189303),
+    ("TypeOfData", 0x50000020),
+    ("TypeOfDetectorMotion", 0x00540202),
+    ("TypeOfFilters", 0x00181161),
+    ("TypeOfInstances", 0x0040E020),
+    ("TypeOfPatientID", 0x00100022),
+    ("UID", 0x0040A124),
+    ("URNCodeValue", 0x00080120),
+    ("USImageDescriptionSequence", 0x00189806),
+    ("UltrasoundAcquisitionGeometry", 0x00209307),
+    ("UltrasoundColorDataPresent", 0x00280014),
+    ("UnassignedPerFrameConvertedAttributesSequence", 0x00209171),
+    ("UnassignedSharedConvertedAttributesSequence", 0x00209170),
+    ("Underlined", 0x00700248),
+    ("UnformattedTextValue", 0x00700006),
+    ("Units", 0x00541001),
+    ("UniversalEntityID", 0x00400032),
+    ("UniversalEntityIDType", 0x00400033),
+    ("UsedFiducialsSequence", 0x00700314),
+    ("UsedSegmentsSequence", 0x00620012),
+    ("VOILUTFunction", 0x00281056),
+    ("VOILUTSequence", 0x00283010),
+    ("ValueType", 0x0040A040),
+    ("VariableFlipAngleFlag", 0x00181315),
+    ("VariablePixelData", 0x7F000010),
+    ("VelocityEncodingAcquisitionSequence", 0x00189092),
+    ("VelocityEncodingDirection", 0x00189090),
+    ("VelocityEncodingMaximumValue", 0x00189217),
+    ("VelocityEncodingMinimumValue", 0x00189091),
+    ("VerificationDateTime", 0x0040A030),
+    ("VerificationFlag", 0x0040A493),
+    ("VerifyingObserverIdentificationCodeSequence", 0x0040A088),
+    ("VerifyingObserverName", 0x0040A075),
+    ("VerifyingObserverSequence", 0x0040A073),
+    ("VerifyingOrganization", 0x0040A027),
+    ("VerticalAlignment", 0x00700243),
+    ("VerticesOfThePolygonalCollimator", 0x00181720),
+    ("VerticesOfThePolygonalExposureControlSensingRegion", 0x00189442),
+    ("VerticesOfThePolygonalShutter", 0x00181620),
+    ("VerticesOfTheRegion", 0x00289503),
+    ("VideoImageFormatAcquired", 0x00181022),
+    ("ViewCodeSequence", 0x00540220),
+    ("ViewModifierCodeSequence", 0x00540222),
+    ("ViewName", 0x00082127),
+    ("ViewNumber", 0x00082128),
+    ("ViewPosition", 0x00185101),
+    ("VisitComments", 0x00384000),
+    ("VisitStatusID", 0x00380008),
+    ("VolumeBasedCalculationTechnique", 0x00089207),
+    ("VolumeFrameOfReferenceUID", 0x00209312),
+    ("VolumeLocalizationSequence", 0x00189126),
+    ("VolumeLocalizationTechnique", 0x00189054),
+    ("VolumeToTableMappingMatrix", 0x0020930A),
+    ("VolumeToTransducerMappingMatrix", 0x00209309),
+    ("VolumeToTransducerRelationship", 0x0020930B),
+    ("VolumetricProperties", 0x00089206),
+    ("WADORSRetrievalSequence", 0x0040E025),
+    ("WADORetrievalSequence", 0x0040E023),
+    ("WarningReason", 0x00081196),
+    ("WaterReferenceAcquisition", 0x00189297),
+    ("WaterReferencedPhaseCorrection", 0x00189199),
+    ("WaveformAnnotationSequence", 0x0040B020),
+    ("WaveformBitsAllocated", 0x54001004),
+    ("WaveformBitsStored", 0x003A021A),
+    ("WaveformChannelNumber", 0x003A0202),
+    ("WaveformData", 0x54001010),
+    ("WaveformOriginality", 0x003A0004),
+    ("WaveformPaddingValue", 0x5400100A),
+    ("WaveformSampleInterpretation", 0x54001006),
+    ("WaveformSequence", 0x54000100),
+    ("WholeBodyTechnique", 0x00181301),
+    ("WholeSlideMicroscopyImageFrameTypeSequence", 0x00400710),
+    ("WindowCenter", 0x00281050),
+    ("WindowCenterWidthExplanation", 0x00281055),
+    ("WindowWidth", 0x00281051),
+    ("XAXRFFrameCharacteristicsSequence", 0x00189412),
+    ("XDSRetrievalSequence", 0x0040E024),
+    ("XFocusCenter", 0x00181183),
+    ("XOffsetInSlideCoordinateSystem", 0x0040072A),
+    ("XRay3DAcquisitionSequence", 0x00189507),
+    ("XRay3DFrameTypeSequence", 0x00189504),
+    ("XRay3DReconstructionSequence", 0x00189530),
+    ("XRayGeometrySequence", 0x00189476),
+    ("XRayImageReceptorAngle", 0x3002000E),
+    ("XRayImageReceptorTranslation", 0x3002000D),
+    ("XRayOutput", 0x00400312),
+    ("XRayReceptorType", 0x00189420),
+    ("XRayTubeCurrent", 0x00181151),
+    ("XRayTubeCurrentInmA", 0x00189330),
+    ("XRayTubeCurrentInuA", 0x00188151),
+    ("YFocusCenter", 0x00181184),
+    ("YOffsetInSlideCoordinateSystem", 0x0040073A),
+    ("ZOffsetInSlideCoordinateSystem", 0x0040074A),
+    ("ZoomCenter", 0x00280032),
+    ("ZoomFactor", 0x00280031),
+    ("dBdt", 0x00181318),
+];
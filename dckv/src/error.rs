@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::DCKVFormat;
+
 #[derive(Error, Debug)]
 pub enum DCKVError {
     #[error("Invalid VR.")]
@@ -8,12 +10,72 @@ pub enum DCKVError {
     #[error("Invalid SQ tag.")]
     InvalidSQTag,
 
+    #[error("Invalid SQ length.")]
+    InvalidSQLength,
+
     #[error("Invalid SQ item length.")]
     InvalidSQItemLength,
-    
+
+    #[error("SQ truncated before its end.")]
+    TruncatedSQ,
+
+    #[error("Invalid pixel data fragment tag.")]
+    InvalidFragmentTag,
+
+    #[error("Too many pixel data fragments.")]
+    TooManyFragments,
+
+    #[error("Sequences nested over [{0}] levels.")]
+    TooManyNestedLevels(usize),
+
+    #[error("Invalid tag path [{0}].")]
+    InvalidTagPath(String),
+
+    #[error("Invalid key [{0}].")]
+    InvalidKey(String),
+
+    #[error("Invalid DCKV header.")]
+    InvalidDCKVHeader,
+
+    #[error("Unsupported DCKV version [{0}].")]
+    UnsupportedDCKVVersion(u16),
+
+    #[error("Invalid DCKV record.")]
+    InvalidDCKVRecord,
+
+    #[error("Unexpected DCKV format [{0:?}].")]
+    UnexpectedDCKVFormat(DCKVFormat),
+
+    #[error("Deferred values must be fetched before being used.")]
+    DeferredValue,
+
+    #[error("Invalid value length [{0}].")]
+    InvalidValueLength(usize),
+
+    #[error("Invalid number [{0}].")]
+    InvalidNumber(String),
+
+    #[error("Missing bulk data URI of [{0}].")]
+    MissingBulkDataURI(String),
+
+    #[error("Invalid date or time [{0}].")]
+    InvalidDateTime(String),
+
+    #[error("Invalid person name [{0}].")]
+    InvalidPersonName(String),
+
+    #[error("Unsupported character set [{0}].")]
+    UnsupportedCharacterSet(String),
+
     #[error("Unsupported VR [{0}].")]
     UnsupportedVR(String),
 
+    #[error("Undefined length of a [{0}] value.")]
+    UndefinedValueLength(String),
+
+    #[error("Unsupported transfer syntax [{0}].")]
+    UnsupportedTransferSyntax(String),
+
     #[error("{0}")]
     IOError(#[from] std::io::Error),
 
@@ -1,3 +1,8 @@
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+use crate::TagPath;
+
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 enum FilterMode {
     #[default]
@@ -6,14 +11,172 @@ enum FilterMode {
     Blacklist,
 }
 
+/// Selects the elements the parser appends. Filtered out values are
+/// skipped without being read, and so are whole sequences.
+///
+/// Tag and range rules match an element at any level, but a whitelist
+/// only looks into the sequences it keeps: for a nested element to be
+/// kept, its sequences must be whitelisted as well. Sequence rules match a
+/// sequence together with everything nested in it.
+///
+/// Path rules match an element at the position described by a
+/// [`TagPath`] only, a sequence together with everything nested in it, and
+/// a whitelist looks into the sequences they may match within.
+///
+/// ```
+/// use dckv::Filter;
+///
+/// // Everything but pixel data and the 0x0009 private group.
+/// let filter = Filter::blacklist().tag(0x7FE00010).group(0x0009);
+///
+/// // Everything but the CSA headers, whichever block Siemens reserved.
+/// let filter = Filter::blacklist()
+///     .private(0x0029, "SIEMENS CSA HEADER", 0x10)
+///     .private(0x0029, "SIEMENS CSA HEADER", 0x20);
+///
+/// // Concept names and code values of the SR content tree.
+/// let filter = Filter::whitelist()
+///     .path("(0040,A730)[*].(0040,A043)".parse().unwrap())
+///     .path("(0040,A730).**.(0008,0100)".parse().unwrap());
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct Filter {
     mode: FilterMode,
+    tags: HashSet<u32>,
+    ranges: Vec<RangeInclusive<u32>>,
+    sequences: HashSet<u32>,
+    paths: Vec<TagPath>,
+    privates: Vec<(u16, String, u8)>,
 }
 
 impl Filter {
+    /// Keeps every element.
     #[inline]
     pub fn none() -> Self {
         Self::default()
     }
+
+    /// Keeps only the elements matched by the rules.
+    #[inline]
+    pub fn whitelist() -> Self {
+        Self {
+            mode: FilterMode::Whitelist,
+            ..Self::default()
+        }
+    }
+
+    /// Keeps every element but the ones matched by the rules.
+    #[inline]
+    pub fn blacklist() -> Self {
+        Self {
+            mode: FilterMode::Blacklist,
+            ..Self::default()
+        }
+    }
+
+    /// Matches the element `tag`, e.g. `0x00100010`.
+    #[inline]
+    pub fn tag(mut self, tag: u32) -> Self {
+        self.tags.insert(tag);
+        self
+    }
+
+    #[inline]
+    pub fn tags<I>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = u32>,
+    {
+        self.tags.extend(tags);
+        self
+    }
+
+    /// Matches the elements whose tag is within `range`.
+    #[inline]
+    pub fn range(mut self, range: RangeInclusive<u32>) -> Self {
+        self.ranges.push(range);
+        self
+    }
+
+    /// Matches every element of `group`.
+    #[inline]
+    pub fn group(self, group: u16) -> Self {
+        let first = (group as u32) << 16;
+        self.range(first..=first + 0xFFFF)
+    }
+
+    /// Matches the sequence `tag` and its whole subtree.
+    #[inline]
+    pub fn sequence(mut self, tag: u32) -> Self {
+        self.sequences.insert(tag);
+        self
+    }
+
+    /// Matches the private data elements of `group` at `offset` within the
+    /// block reserved by `creator`, whichever block it is, e.g. the
+    /// (0029,xx10) CSA Image Header Info of `SIEMENS CSA HEADER`.
+    #[inline]
+    pub fn private(mut self, group: u16, creator: &str, offset: u8) -> Self {
+        self.privates.push((group, creator.to_string(), offset));
+        self
+    }
+
+    /// Matches the elements at the position described by `path`.
+    #[inline]
+    pub fn path(mut self, path: TagPath) -> Self {
+        self.paths.push(path);
+        self
+    }
+
+    #[inline]
+    fn matches(&self, parents: &[u64], tag: u32, creator: Option<&str>) -> bool {
+        self.tags.contains(&tag)
+            || self.sequences.contains(&tag)
+            || self.ranges.iter().any(|range| range.contains(&tag))
+            || self.paths.iter().any(|path| path.matches(parents, tag))
+            || creator.is_some_and(|creator| {
+                self.privates
+                    .iter()
+                    .any(|(group, private_creator, offset)| {
+                        (tag >> 16) as u16 == *group
+                            && private_creator == creator
+                            && tag as u8 == *offset
+                    })
+            })
+    }
+
+    /// Whether the element `tag`, nested in the sequence items `parents`
+    /// (key blocks holding the sequence tag in their high 4 bytes and the
+    /// item number in their low 4 bytes), has to be skipped. `creator` is
+    /// the one reserving the block of a private data element.
+    #[inline]
+    pub(crate) fn skips(
+        &self,
+        parents: &[u64],
+        tag: u32,
+        creator: Option<&str>,
+        is_sequence: bool,
+    ) -> bool {
+        match self.mode {
+            FilterMode::None => false,
+            FilterMode::Whitelist => {
+                let in_sequence = parents.iter().enumerate().any(|(level, &parent)| {
+                    let sequence = (parent >> 32) as u32;
+                    self.sequences.contains(&sequence)
+                        || self
+                            .paths
+                            .iter()
+                            .any(|path| path.matches(&parents[..level], sequence))
+                });
+
+                let within_path = is_sequence
+                    && self
+                        .paths
+                        .iter()
+                        .any(|path| path.may_match_within(parents, tag));
+
+                !in_sequence && !within_path && !self.matches(parents, tag, creator)
+            }
+            FilterMode::Blacklist => self.matches(parents, tag, creator),
+        }
+    }
 }
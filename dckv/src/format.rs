@@ -0,0 +1,330 @@
+//! Binary DCKV file format.
+//!
+//! A file is a header followed by records, until the end of the file.
+//! Numbers are little endian.
+//!
+//! | Bytes | Header field                                         |
+//! |-------|------------------------------------------------------|
+//! | 4     | Magic, `DCKV`                                        |
+//! | 2     | Version, [`DCKV_VERSION`]                            |
+//! | 2     | Format, 0 for DCKV, 1 for eDCKV (series and instance |
+//! |       | prefix)                                              |
+//!
+//! | Bytes        | Record field  |
+//! |--------------|---------------|
+//! | 4            | Key length    |
+//! | 4            | Value length  |
+//! | key length   | Key           |
+//! | value length | Value         |
+//!
+//! Records keep the order they were written in: maps write them in key
+//! order, which is the DICOM order of their elements. Keys and values are
+//! read as their bytes come rather than allocated from their lengths, so
+//! that lengths over the rest of the file fail once it ends.
+
+use std::io::{Read, Write};
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+
+use crate::{DCKVError, EKey, Key, KeyRef, Result, Value};
+
+const MAGIC: &[u8; 4] = b"DCKV";
+
+/// Version of the format written, and the only one read.
+pub const DCKV_VERSION: u16 = 1;
+
+/// Layout of the keys of a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DCKVFormat {
+    /// Keys of a single instance.
+    Dckv,
+    /// Keys prefixed by the series and instance they belong to.
+    Edckv,
+}
+
+impl DCKVFormat {
+    #[inline]
+    fn code(&self) -> u16 {
+        match self {
+            Self::Dckv => 0,
+            Self::Edckv => 1,
+        }
+    }
+
+    #[inline]
+    fn from_code(code: u16) -> Option<Self> {
+        match code {
+            0 => Some(Self::Dckv),
+            1 => Some(Self::Edckv),
+            _ => None,
+        }
+    }
+}
+
+/// Writes keys and values to a DCKV file, one record at a time.
+///
+/// ```
+/// use dckv::{DCKVFormat, DCKVReader, DCKVWriter, KVMap};
+///
+/// let kvmap = KVMap::new();
+///
+/// let mut writer = DCKVWriter::new(vec![], DCKVFormat::Dckv).unwrap();
+/// kvmap.write_dckv(&mut writer).unwrap();
+/// let bytes = writer.finish().unwrap();
+///
+/// let reader = DCKVReader::new(bytes.as_slice()).unwrap();
+/// assert_eq!(KVMap::read_dckv(reader).unwrap(), kvmap);
+/// ```
+#[derive(Debug)]
+pub struct DCKVWriter<W> {
+    writer: W,
+    format: DCKVFormat,
+}
+
+impl<W> DCKVWriter<W>
+where
+    W: Write,
+{
+    /// Writes the header of a `format` file.
+    pub fn new(mut writer: W, format: DCKVFormat) -> Result<Self> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&DCKV_VERSION.to_le_bytes())?;
+        writer.write_all(&format.code().to_le_bytes())?;
+
+        Ok(Self { writer, format })
+    }
+
+    #[inline]
+    pub fn format(&self) -> DCKVFormat {
+        self.format
+    }
+
+    /// Writes the record of `key` and its value.
+    pub fn write(&mut self, key: KeyRef<'_>, value: &[u8]) -> Result<()> {
+        let key_length =
+            u32::try_from(key.bytes().len()).map_err(|_| DCKVError::InvalidDCKVRecord)?;
+        let value_length = u32::try_from(value.len()).map_err(|_| DCKVError::InvalidDCKVRecord)?;
+
+        self.writer.write_all(&key_length.to_le_bytes())?;
+        self.writer.write_all(&value_length.to_le_bytes())?;
+        self.writer.write_all(key.bytes())?;
+        self.writer.write_all(value)?;
+
+        Ok(())
+    }
+
+    /// Flushes the file, returning the underlying writer.
+    pub fn finish(mut self) -> Result<W> {
+        self.writer.flush()?;
+
+        Ok(self.writer)
+    }
+}
+
+/// Key of the records of a file: a [`Key`] in DCKV files, an [`EKey`] in
+/// eDCKV ones.
+pub trait DCKVKey: sealed::Sealed + Sized {
+    const FORMAT: DCKVFormat;
+
+    #[doc(hidden)]
+    fn from_record(bytes: Vec<u8>) -> Result<Self>;
+}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for crate::Key {}
+    impl Sealed for crate::EKey {}
+}
+
+impl DCKVKey for Key {
+    const FORMAT: DCKVFormat = DCKVFormat::Dckv;
+
+    #[inline]
+    fn from_record(bytes: Vec<u8>) -> Result<Self> {
+        match !bytes.is_empty() && bytes.len().is_multiple_of(8) {
+            true => Ok(Key { bytes }),
+            false => Err(DCKVError::InvalidDCKVRecord),
+        }
+    }
+}
+
+impl DCKVKey for EKey {
+    const FORMAT: DCKVFormat = DCKVFormat::Edckv;
+
+    #[inline]
+    fn from_record(bytes: Vec<u8>) -> Result<Self> {
+        match bytes.len() >= 16 && bytes.len().is_multiple_of(8) {
+            true => Ok(EKey::from_bytes(bytes)),
+            false => Err(DCKVError::InvalidDCKVRecord),
+        }
+    }
+}
+
+/// Reads the keys and values of a DCKV file, iterating over its records.
+/// The records of a DCKV file have [`Key`]s, the ones of an eDCKV file,
+/// read with [`DCKVReader::edckv`], [`EKey`]s: reading a file of the other
+/// format fails. Iterating ends after the first error.
+///
+/// ```
+/// use dckv::{DCKVError, DCKVFormat, DCKVReader, DCKVWriter};
+///
+/// let writer = DCKVWriter::new(vec![], DCKVFormat::Edckv).unwrap();
+/// let bytes = writer.finish().unwrap();
+///
+/// assert!(DCKVReader::edckv(bytes.as_slice()).is_ok());
+/// assert!(matches!(
+///     DCKVReader::new(bytes.as_slice()),
+///     Err(DCKVError::UnexpectedDCKVFormat(DCKVFormat::Edckv))
+/// ));
+/// ```
+#[derive(Debug)]
+pub struct DCKVReader<R, K = Key> {
+    reader: R,
+    format: DCKVFormat,
+    /// An error was returned.
+    failed: bool,
+    key: PhantomData<K>,
+}
+
+impl<R> DCKVReader<R>
+where
+    R: Read,
+{
+    /// Reads the header of a DCKV file.
+    #[inline]
+    pub fn new(reader: R) -> Result<Self> {
+        Self::open(reader)
+    }
+}
+
+impl<R> DCKVReader<R, EKey>
+where
+    R: Read,
+{
+    /// Reads the header of an eDCKV file.
+    #[inline]
+    pub fn edckv(reader: R) -> Result<Self> {
+        Self::open(reader)
+    }
+}
+
+impl<R, K> DCKVReader<R, K>
+where
+    R: Read,
+    K: DCKVKey,
+{
+    /// Reads the header of a file of `K` keys.
+    fn open(mut reader: R) -> Result<Self> {
+        let mut header = [0u8; 8];
+        reader.read_exact(&mut header)?;
+
+        if &header[..4] != MAGIC {
+            return Err(DCKVError::InvalidDCKVHeader);
+        }
+
+        let version = u16::from_le_bytes([header[4], header[5]]);
+        if version != DCKV_VERSION {
+            return Err(DCKVError::UnsupportedDCKVVersion(version));
+        }
+
+        let format = DCKVFormat::from_code(u16::from_le_bytes([header[6], header[7]]))
+            .ok_or(DCKVError::InvalidDCKVHeader)?;
+        if format != K::FORMAT {
+            return Err(DCKVError::UnexpectedDCKVFormat(format));
+        }
+
+        Ok(Self {
+            reader,
+            format,
+            failed: false,
+            key: PhantomData,
+        })
+    }
+
+    #[inline]
+    pub fn format(&self) -> DCKVFormat {
+        self.format
+    }
+
+    /// Reads the next record, `None` at the end of the file.
+    fn read_record(&mut self) -> Result<Option<(K, Value)>> {
+        let mut lengths = [0u8; 8];
+
+        // The file may only end between records.
+        let read = read_up_to(&mut self.reader, &mut lengths)?;
+        match read {
+            0 => return Ok(None),
+            8 => {}
+            _ => return Err(DCKVError::InvalidDCKVRecord),
+        }
+
+        let key_length = u32::from_le_bytes([lengths[0], lengths[1], lengths[2], lengths[3]]);
+        let value_length = u32::from_le_bytes([lengths[4], lengths[5], lengths[6], lengths[7]]);
+
+        let key = K::from_record(read_exactly(&mut self.reader, key_length)?)?;
+        let value = Value::new(read_exactly(&mut self.reader, value_length)?);
+
+        Ok(Some((key, value)))
+    }
+}
+
+impl<R, K> Iterator for DCKVReader<R, K>
+where
+    R: Read,
+    K: DCKVKey,
+{
+    type Item = Result<(K, Value)>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        let record = self.read_record().transpose();
+        self.failed = matches!(record, Some(Err(_)));
+        record
+    }
+}
+
+impl<R, K> FusedIterator for DCKVReader<R, K>
+where
+    R: Read,
+    K: DCKVKey,
+{
+}
+
+/// Fills `buf` as much as the stream allows, returning the number of bytes
+/// read.
+fn read_up_to<R>(reader: &mut R, buf: &mut [u8]) -> Result<usize>
+where
+    R: Read,
+{
+    let mut read = 0;
+    while read < buf.len() {
+        match reader.read(&mut buf[read..]) {
+            Ok(0) => break,
+            Ok(n) => read += n,
+            Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(err) => return Err(err.into()),
+        }
+    }
+
+    Ok(read)
+}
+
+/// Reads `length` bytes, growing the buffer as they come rather than
+/// trusting the length of a record.
+fn read_exactly<R>(reader: &mut R, length: u32) -> Result<Vec<u8>>
+where
+    R: Read,
+{
+    let mut bytes = vec![];
+    reader.take(length as u64).read_to_end(&mut bytes)?;
+
+    match bytes.len() == length as usize {
+        true => Ok(bytes),
+        false => Err(DCKVError::InvalidDCKVRecord),
+    }
+}
@@ -0,0 +1,108 @@
+//! Classification of keys. The last block of a key is an element, or one of
+//! the markers the parsers emit around sequences and items, which have no
+//! VR of their own: [`Key::vr`] reads `++` for item starts, `__` for item
+//! ends, and the `SQ` markers have no VR bytes.
+
+use crate::{block, EKey, Key, KeyRef};
+
+/// Kind of a key, given by its last block.
+///
+/// ```
+/// use dckv::{Key, KeyKind};
+///
+/// let kind = |text: &str| text.parse::<Key>().unwrap().kind();
+/// assert_eq!(kind("ContentSequence"), KeyKind::SequenceStart);
+/// assert_eq!(kind("ContentSequence[1].start"), KeyKind::ItemStart);
+/// assert_eq!(kind("ContentSequence[1].ValueType"), KeyKind::Element);
+/// assert_eq!(kind("ContentSequence[1].end"), KeyKind::ItemEnd);
+/// assert_eq!(kind("ContentSequence:SQ.end"), KeyKind::SequenceEnd);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyKind {
+    /// Element holding a value, or a fragment of an encapsulated one.
+    Element,
+    /// Sequence, before its first item.
+    SequenceStart,
+    /// Item, before its first element.
+    ItemStart,
+    /// Item, after its last element.
+    ItemEnd,
+    /// Sequence, after its last item.
+    SequenceEnd,
+}
+
+impl<'k> KeyRef<'k> {
+    #[inline]
+    pub fn kind(&self) -> KeyKind {
+        let last = self.blocks().next_back().unwrap_or_default();
+
+        match last {
+            block::ITEM_START => KeyKind::ItemStart,
+            block::ITEM_END => KeyKind::ItemEnd,
+            _ => match (last >> 16) as u16 {
+                0x0000 => KeyKind::SequenceStart,
+                0xFFFF => KeyKind::SequenceEnd,
+                _ => KeyKind::Element,
+            },
+        }
+    }
+
+    /// Number, starting at 1, of the item holding the key, `None` at the
+    /// top level.
+    #[inline]
+    pub fn item_number(&self) -> Option<u32> {
+        self.parents().last().map(|(_, number)| number)
+    }
+
+    /// Sequence tags and item numbers of the items holding the key, from
+    /// the top level, as taken by [`Key::nested`].
+    ///
+    /// ```
+    /// use dckv::Key;
+    ///
+    /// let key: Key = "0040A730[2].0040A043[1].00080100:SH".parse().unwrap();
+    /// let parents = key.parents().collect::<Vec<_>>();
+    /// assert_eq!(parents, [(0x0040A730, 2), (0x0040A043, 1)]);
+    /// assert_eq!(key.item_number(), Some(1));
+    /// assert_eq!(Key::nested(&parents, 0x0008, 0x0100, "SH").unwrap(), key);
+    /// ```
+    #[inline]
+    pub fn parents(&self) -> impl Iterator<Item = (u32, u32)> + 'k {
+        let level = self.level();
+
+        self.blocks()
+            .take(level)
+            .map(|block| ((block >> 32) as u32, block as u32))
+    }
+
+    #[inline]
+    pub(crate) fn blocks(&self) -> impl DoubleEndedIterator<Item = u64> + 'k {
+        self.bytes()
+            .chunks_exact(8)
+            .map(|chunk| u64::from_be_bytes(chunk.try_into().unwrap_or_default()))
+    }
+}
+
+impl Key {
+    #[inline]
+    pub fn kind(&self) -> KeyKind {
+        self.as_key_ref().kind()
+    }
+
+    #[inline]
+    pub fn item_number(&self) -> Option<u32> {
+        self.as_key_ref().item_number()
+    }
+
+    #[inline]
+    pub fn parents(&self) -> impl Iterator<Item = (u32, u32)> + '_ {
+        self.as_key_ref().parents()
+    }
+}
+
+impl EKey {
+    #[inline]
+    pub fn kind(&self) -> KeyKind {
+        self.key().kind()
+    }
+}
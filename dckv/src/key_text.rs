@@ -0,0 +1,274 @@
+//! Textual form of keys, one segment per level separated by dots, from the
+//! top level down to the element:
+//!
+//! | Segment             | Level                                          |
+//! |---------------------|------------------------------------------------|
+//! | `GGGGEEEE[n]`       | item `n` of a sequence, above the last level   |
+//! | `GGGGEEEE:VR`       | element, e.g. `00100010:PN`                    |
+//! | `GGGGEEEE:VR#n`     | fragment `n` of an encapsulated value          |
+//! | `GGGGEEEE:SQ`       | sequence start                                 |
+//! | `GGGGEEEE:SQ.end`   | sequence end                                   |
+//! | `GGGGEEEE[n].start` | item start, and `.end` its end                 |
+//!
+//! When parsing, tags may be keywords, e.g. `ContentSequence`, the VR of an
+//! element defaults to the one of the data dictionary, and the item number
+//! of a sequence to 1. Item numbers range from 1 to 0xFFFF, as fragment
+//! numbers do.
+
+use serde::de::{Deserialize, Deserializer, Error as _};
+use serde::ser::{Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{
+    block, dictionary, vr, DCKVError, Dictionary, EKey, Key, KeyKind, KeyRef, PrivateDictionary,
+    Result,
+};
+
+impl Key {
+    /// Key of the top-level element (`group`,`element`), e.g. `PN`. Sequences,
+    /// of VR `SQ`, have the key of their start.
+    ///
+    /// ```
+    /// use dckv::Key;
+    ///
+    /// let key = Key::new(0x0010, 0x0010, "PN").unwrap();
+    /// assert_eq!(key.to_string(), "00100010:PN");
+    /// ```
+    #[inline]
+    pub fn new(group: u16, element: u16, vr: &str) -> Result<Self> {
+        Self::nested(&[], group, element, vr)
+    }
+
+    /// Key of the element (`group`,`element`) nested in the sequence items
+    /// `path`, as pairs of sequence tag and item number, starting at 1,
+    /// from the top level.
+    ///
+    /// ```
+    /// use dckv::Key;
+    ///
+    /// let key = Key::nested(&[(0x0040A730, 2), (0x0040A043, 1)], 0x0008, 0x0100, "SH").unwrap();
+    /// assert_eq!(key.to_string(), "0040A730[2].0040A043[1].00080100:SH");
+    /// assert_eq!(key, "ContentSequence[2].ConceptNameCodeSequence.CodeValue".parse().unwrap());
+    /// ```
+    pub fn nested(path: &[(u32, u32)], group: u16, element: u16, vr: &str) -> Result<Self> {
+        let tag = ((group as u32) << 16) + element as u32;
+        let last = match vr_code(vr)? {
+            vr::SQ => block::sequence(tag),
+            vr => block::element(tag, vr),
+        };
+
+        Ok(from_blocks(item_blocks(path).chain([last])))
+    }
+}
+
+/// Blocks of the sequence items `path`.
+#[inline]
+fn item_blocks(path: &[(u32, u32)]) -> impl Iterator<Item = u64> + '_ {
+    path.iter()
+        .map(|&(tag, item)| block::item(tag, item as u64))
+}
+
+#[inline]
+fn from_blocks<I>(blocks: I) -> Key
+where
+    I: IntoIterator<Item = u64>,
+{
+    Key {
+        bytes: blocks
+            .into_iter()
+            .flat_map(|block| block.to_be_bytes())
+            .collect(),
+    }
+}
+
+/// Code of a VR written as two uppercase letters.
+#[inline]
+fn vr_code(vr: &str) -> Result<u16> {
+    match vr.as_bytes() {
+        &[first, second] if first.is_ascii_uppercase() && second.is_ascii_uppercase() => {
+            Ok(u16::from_be_bytes([first, second]))
+        }
+        _ => Err(DCKVError::InvalidVR),
+    }
+}
+
+impl fmt::Display for KeyRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (tag, number)) in self.parents().enumerate() {
+            if index > 0 {
+                write!(f, ".")?;
+            }
+            write!(f, "{tag:08X}[{number}]")?;
+        }
+
+        let Some(last) = self.blocks().next_back() else {
+            return Ok(());
+        };
+
+        let separator = if self.level() == 0 { "" } else { "." };
+        let tag = (last >> 32) as u32;
+
+        match self.kind() {
+            KeyKind::ItemStart => write!(f, "{separator}start"),
+            KeyKind::ItemEnd => write!(f, "{separator}end"),
+            KeyKind::SequenceStart => write!(f, "{separator}{tag:08X}:SQ"),
+            KeyKind::SequenceEnd => write!(f, "{separator}{tag:08X}:SQ.end"),
+            KeyKind::Element => {
+                let [first, second] = ((last >> 16) as u16).to_be_bytes();
+                write!(
+                    f,
+                    "{separator}{tag:08X}:{}{}",
+                    first as char, second as char
+                )?;
+                match last as u16 {
+                    0 => Ok(()),
+                    number => write!(f, "#{number}"),
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for Key {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_key_ref().fmt(f)
+    }
+}
+
+/// Series and instance numbers before the key, e.g. `1/2/00100010:PN`.
+impl fmt::Display for EKey {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}/{}", self.series(), self.instance(), self.key())
+    }
+}
+
+/// Tag of a segment, as 8 hex digits or a keyword.
+#[inline]
+fn parse_tag(text: &str) -> Option<u32> {
+    match text.len() == 8 && text.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        true => u32::from_str_radix(text, 16).ok(),
+        false => Dictionary::by_keyword(text).map(|entry| entry.tag()),
+    }
+}
+
+/// Tag and item number of an item segment, `TAG[n]` or `TAG` for item 1.
+#[inline]
+fn parse_item(text: &str) -> Option<(u32, u32, bool)> {
+    match text.split_once('[') {
+        Some((tag, number)) => {
+            let number = number.strip_suffix(']')?.parse::<u16>().ok()?;
+            match number {
+                0 => None,
+                number => Some((parse_tag(tag)?, number as u32, true)),
+            }
+        }
+        None => Some((parse_tag(text)?, 1, false)),
+    }
+}
+
+/// Blocks of the item segments `segments`.
+#[inline]
+fn parse_items(segments: &[&str]) -> Option<Vec<u64>> {
+    segments
+        .iter()
+        .map(|segment| {
+            let (tag, number, _) = parse_item(segment)?;
+            Some(block::item(tag, number as u64))
+        })
+        .collect()
+}
+
+/// Last block of an element segment, `TAG[:VR][#n]`.
+#[inline]
+fn parse_element(text: &str) -> Option<u64> {
+    let (text, number) = match text.split_once('#') {
+        Some((text, number)) => (text, Some(number.parse::<u16>().ok()?)),
+        None => (text, None),
+    };
+
+    let (tag, vr) = match text.split_once(':') {
+        Some((tag, vr)) => (parse_tag(tag)?, vr_code(vr).ok()?),
+        None => {
+            let tag = parse_tag(text)?;
+            (tag, dictionary::vr(tag, None, &PrivateDictionary::new()))
+        }
+    };
+
+    match (vr, number) {
+        (vr::SQ, None) => Some(block::sequence(tag)),
+        (vr::SQ, Some(_)) => None,
+        (vr, number) => Some(block::fragment(tag, vr, number.unwrap_or(0) as u64)),
+    }
+}
+
+impl FromStr for Key {
+    type Err = DCKVError;
+
+    fn from_str(text: &str) -> std::result::Result<Self, Self::Err> {
+        let invalid = || DCKVError::InvalidKey(text.to_string());
+
+        let segments = text.split('.').collect::<Vec<&str>>();
+        let (&last, parents) = segments.split_last().ok_or_else(invalid)?;
+
+        // Delimiters end the level of the segment before them.
+        let delimiter = match last {
+            "start" => block::ITEM_START,
+            "end" => block::ITEM_END,
+            _ => {
+                let mut blocks = parse_items(parents).ok_or_else(invalid)?;
+                blocks.push(parse_element(last).ok_or_else(invalid)?);
+                return Ok(from_blocks(blocks));
+            }
+        };
+
+        let (&ended, parents) = parents.split_last().ok_or_else(invalid)?;
+        let mut blocks = parse_items(parents).ok_or_else(invalid)?;
+
+        match (ended.strip_suffix(":SQ"), parse_item(ended)) {
+            (Some(tag), _) if delimiter == block::ITEM_END => {
+                blocks.push(block::sequence_end(parse_tag(tag).ok_or_else(invalid)?));
+            }
+            (None, Some((tag, number, true))) => {
+                blocks.push(block::item(tag, number as u64));
+                blocks.push(delimiter);
+            }
+            _ => return Err(invalid()),
+        }
+
+        Ok(from_blocks(blocks))
+    }
+}
+
+impl Serialize for Key {
+    /// Serializes the textual form of the key.
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Key {
+    /// Deserializes the textual form of a key.
+    ///
+    /// ```
+    /// use dckv::Key;
+    /// use serde::de::{value::StrDeserializer, Deserialize, IntoDeserializer};
+    ///
+    /// let deserializer: StrDeserializer<serde::de::value::Error> = "7FE00010:OB#2".into_deserializer();
+    /// let key = Key::deserialize(deserializer).unwrap();
+    /// assert_eq!((key.group(), key.element(), key.vr().unwrap()), (0x7FE0, 0x0010, "OB"));
+    /// ```
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let text = String::deserialize(deserializer)?;
+        text.parse().map_err(D::Error::custom)
+    }
+}
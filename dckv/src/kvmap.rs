@@ -1,12 +1,57 @@
 use async_trait::async_trait;
-use indexmap::IndexMap;
+use bytes::Bytes;
+use chrono::{DateTime, FixedOffset, NaiveTime, Offset, Utc};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::io::{Read, Seek, Write};
+use std::ops::{Bound, RangeBounds};
 use tokio::io::{AsyncReadExt, AsyncSeekExt};
 
-use crate::{Key, Deserializer, Value};
+use crate::private;
+use crate::{
+    block, vr, BulkDataPolicy, BulkDataRef, BytesDeserializer, CharacterSet, Control, DCKVError, DCKVFormat,
+    DCKVReader, DCKVWriter, Deserializer, Key, KeyKind, KeyRef, PrivateDictionary, Result,
+    SyncDeserializer, Value, MAX_DEPTH,
+};
 
-#[derive(Debug, Clone, Default, PartialEq, Eq)]
+mod json;
+mod study;
+
+pub use study::StudyMap;
+
+#[inline]
+fn decode_u32(bytes: &[u8]) -> u64 {
+    u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as u64
+}
+
+#[inline]
+fn decode_u64(bytes: &[u8]) -> u64 {
+    u64::from_le_bytes(bytes.try_into().unwrap_or_default())
+}
+
+/// Tag of the last block of `key`.
+#[inline]
+fn key_tag(key: &KeyRef<'_>) -> u32 {
+    ((key.group() as u32) << 16) + key.element() as u32
+}
+
+/// Key of a top-level element, from its tag and VR.
+#[inline]
+fn element_key(tag: u32, vr: u16) -> [u8; 8] {
+    block::element(tag, vr).to_be_bytes()
+}
+
+/// Keys and values of a dataset, sorted by key: in the order of the tags at
+/// every level, items in the order of their number between the start and the
+/// end of their sequence. Maps are equal when their keys and values are,
+/// whatever their parse settings.
+#[derive(Debug, Clone, Default)]
 pub struct KVMap {
-    map: IndexMap<Vec<u8>, Vec<u8>>,
+    map: BTreeMap<Vec<u8>, Vec<u8>>,
+    bulk_data: BTreeMap<Vec<u8>, BulkDataRef>,
+    bulk_data_policy: Option<BulkDataPolicy>,
+    max_depth: Option<usize>,
+    private_dictionary: Option<PrivateDictionary>,
 }
 
 impl KVMap {
@@ -15,6 +60,33 @@ impl KVMap {
         Self::default()
     }
 
+    /// Map deferring the values selected by `policy` when deserializing:
+    /// they are kept as references into the source, out of [`KVMap::get`].
+    /// Deflated datasets are read inline whatever the policy.
+    #[inline]
+    pub fn with_bulk_data(policy: BulkDataPolicy) -> Self {
+        Self {
+            bulk_data_policy: Some(policy),
+            ..Self::default()
+        }
+    }
+
+    /// Fails deserializing datasets with sequences nested over `depth`
+    /// levels, instead of [`MAX_DEPTH`].
+    #[inline]
+    pub fn with_max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Resolves the VR of private elements in implicit VR with
+    /// `dictionary` when deserializing.
+    #[inline]
+    pub fn with_private_dictionary(mut self, dictionary: PrivateDictionary) -> Self {
+        self.private_dictionary = Some(dictionary);
+        self
+    }
+
     #[inline]
     pub fn get(&self, key: Key) -> Option<&Vec<u8>> {
         self.map.get(key.bytes())
@@ -25,17 +97,538 @@ impl KVMap {
         self.map.get_key_value(key.bytes())
     }
 
+    /// Character set of the dataset holding `key`: its (0008,0005) Specific
+    /// Character Set, or the one of the nearest enclosing dataset.
+    pub fn character_set(&self, key: &Key) -> Result<CharacterSet> {
+        let bytes = key.bytes();
+        let charset_key = element_key(0x00080005, vr::CS);
+
+        for level in (0..=key.level()).rev() {
+            let dataset = &bytes[..level * 8];
+            if let Some(value) = self.map.get([dataset, &charset_key].concat().as_slice()) {
+                return CharacterSet::from_value(&Value::new(value.clone()));
+            }
+        }
+
+        Ok(CharacterSet::default())
+    }
+
+    /// Text of the value of `key`, decoded with its character set.
+    pub fn get_text(&self, key: Key) -> Result<Option<String>> {
+        let Some(value) = self.map.get(key.bytes()) else {
+            return Ok(None);
+        };
+
+        let level = key.level();
+        let vr = u16::from_be_bytes([key.bytes()[level * 8 + 4], key.bytes()[level * 8 + 5]]);
+        let charset = self.character_set(&key)?;
+
+        Ok(Some(Value::new(value.clone()).to_text(vr, &charset)))
+    }
+
+    /// Creator reserving the block of the private data element `key`, e.g.
+    /// `SIEMENS CSA HEADER`, held by the private creator element of its
+    /// dataset, whatever its VR, e.g. UN in a converted file.
+    pub fn private_creator(&self, key: &Key) -> Option<String> {
+        let tag = ((key.group() as u32) << 16) + key.element() as u32;
+        let creator_tag = private::creator_tag(tag)?;
+
+        let dataset = &key.bytes()[..key.level() * 8];
+        let start = [dataset, &block::sequence(creator_tag).to_be_bytes()].concat();
+        let tag_end = dataset.len() + 4;
+
+        self.map
+            .range::<[u8], _>((Bound::Included(start.as_slice()), Bound::Unbounded))
+            .next()
+            .filter(|(creator_key, _)| {
+                creator_key.len() == start.len() && creator_key[..tag_end] == start[..tag_end]
+            })
+            .map(|(_, value)| private::creator(value))
+    }
+
+    /// Creator reserving the block of the private data element `key`, with
+    /// the offset of the element within the block, e.g. 0x10 for (0029,1010)
+    /// and (0029,1110). `None` when its block is not reserved.
+    #[inline]
+    pub fn private_offset(&self, key: &Key) -> Option<(String, u8)> {
+        let creator = self.private_creator(key)?;
+        Some((creator, key.element() as u8))
+    }
+
+    /// Top-level element or sequence of `tag`, whatever its VR, with its
+    /// key. Sequences have an empty value.
+    ///
+    /// ```
+    /// use dckv::{KVMap, Key, Value};
+    ///
+    /// let mut kvmap = KVMap::new();
+    /// kvmap.insert("00100010:PN".parse().unwrap(), Value::new(b"Doe^John".to_vec()));
+    ///
+    /// let (key, value) = kvmap.get_tag(0x00100010).unwrap();
+    /// assert_eq!((key.vr().unwrap(), value.as_slice()), ("PN", &b"Doe^John"[..]));
+    /// ```
+    pub fn get_tag(&self, tag: u32) -> Option<(KeyRef<'_>, &Vec<u8>)> {
+        let start = block::sequence(tag).to_be_bytes();
+
+        // The start of a sequence, or the first fragment of an encapsulated
+        // value, comes first.
+        self.map
+            .range::<[u8], _>((Bound::Included(start.as_slice()), Bound::Unbounded))
+            .next()
+            .map(|(key, value)| (KeyRef { bytes: key }, value))
+            .filter(|(key, _)| {
+                key.level() == 0
+                    && key_tag(key) == tag
+                    && matches!(key.kind(), KeyKind::Element | KeyKind::SequenceStart)
+                    && key.bytes()[6..8] == [0, 0]
+            })
+    }
+
+    /// Start keys of the items of the sequence `sequence`, a sequence start
+    /// key, in the order of the items.
+    ///
+    /// ```
+    /// use dckv::{KVMap, Key, Value};
+    ///
+    /// let mut kvmap = KVMap::new();
+    /// for text in [
+    ///     "ContentSequence",
+    ///     "ContentSequence[1].start",
+    ///     "ContentSequence[1].TextValue",
+    ///     "ContentSequence[1].end",
+    ///     "ContentSequence[2].start",
+    ///     "ContentSequence[2].TextValue",
+    ///     "ContentSequence[2].end",
+    ///     "ContentSequence:SQ.end",
+    /// ] {
+    ///     kvmap.insert(text.parse().unwrap(), Value::new(text.as_bytes().to_vec()));
+    /// }
+    ///
+    /// let sequence = Key::from_keyword("ContentSequence").unwrap();
+    /// let items = kvmap.items(&sequence).map(|item| item.to_key()).collect::<Vec<_>>();
+    /// assert_eq!(items.len(), 2);
+    ///
+    /// let texts = kvmap
+    ///     .item_entries(&items[1])
+    ///     .filter(|(key, _)| key.keyword() == Some("TextValue"))
+    ///     .map(|(_, value)| value.clone())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(texts, [b"ContentSequence[2].TextValue".to_vec()]);
+    /// ```
+    pub fn items<'m>(&'m self, sequence: &Key) -> impl Iterator<Item = KeyRef<'m>> + 'm {
+        let level = sequence.level();
+        let dataset = &sequence.bytes()[..level * 8];
+        let tag = key_tag(&sequence.as_key_ref());
+        let start = [dataset, &block::item(tag, 1).to_be_bytes()].concat();
+        let end = [dataset, &block::sequence_end(tag).to_be_bytes()].concat();
+
+        self.map
+            .range(start..end)
+            .map(|(key, _)| KeyRef { bytes: key })
+            .filter(move |key| key.kind() == KeyKind::ItemStart && key.level() == level + 1)
+    }
+
+    /// Keys and values nested in the item holding `key`, e.g. its start
+    /// key, at any depth and with the item delimiters, in key order.
+    #[inline]
+    pub fn item_entries(&self, key: &Key) -> impl Iterator<Item = (KeyRef<'_>, &Vec<u8>)> {
+        let item = &key.bytes()[..key.level() * 8];
+        let in_item = !item.is_empty();
+
+        // Top-level keys are in no item.
+        self.prefixed(item).take_while(move |_| in_item)
+    }
+
+    /// Keys and values whose key starts with the bytes `prefix`, e.g. the
+    /// blocks of an item, in key order.
+    pub fn prefixed(&self, prefix: &[u8]) -> impl Iterator<Item = (KeyRef<'_>, &Vec<u8>)> {
+        let range = self
+            .map
+            .range::<[u8], _>((Bound::Included(prefix), Bound::Unbounded));
+        let prefix = prefix.to_vec();
+
+        range
+            .take_while(move |(key, _)| key.starts_with(&prefix))
+            .map(|(key, value)| (KeyRef { bytes: key }, value))
+    }
+
+    /// Keys and values in `range`, in key order, e.g. [`KVMap::group`].
+    /// Panics on a decreasing range, like [`BTreeMap::range`].
+    #[inline]
+    pub fn range<R>(&self, range: R) -> impl Iterator<Item = (KeyRef<'_>, &Vec<u8>)>
+    where
+        R: RangeBounds<Key>,
+    {
+        let bounds = (
+            range.start_bound().map(Key::bytes),
+            range.end_bound().map(Key::bytes),
+        );
+
+        self.map
+            .range::<[u8], _>(bounds)
+            .map(|(key, value)| (KeyRef { bytes: key }, value))
+    }
+
+    /// Top-level elements of `group`, with the items of its sequences, in
+    /// key order.
+    ///
+    /// ```
+    /// use dckv::{KVMap, Value};
+    ///
+    /// let mut kvmap = KVMap::new();
+    /// for text in ["00200013:IS", "00100010:PN", "0020000D:UI"] {
+    ///     kvmap.insert(text.parse().unwrap(), Value::new(vec![]));
+    /// }
+    ///
+    /// let keys = kvmap.group(0x0020).map(|(key, _)| key.to_string()).collect::<Vec<_>>();
+    /// assert_eq!(keys, ["0020000D:UI", "00200013:IS"]);
+    /// ```
+    #[inline]
+    pub fn group(&self, group: u16) -> impl Iterator<Item = (KeyRef<'_>, &Vec<u8>)> {
+        let start = |group: u64| Key {
+            bytes: block::sequence((group as u32) << 16).to_be_bytes().to_vec(),
+        };
+
+        let end = match group {
+            u16::MAX => Bound::Unbounded,
+            group => Bound::Excluded(start(group as u64 + 1)),
+        };
+
+        self.range((Bound::Included(start(group as u64)), end))
+    }
+
+    /// Reference of a deferred value.
+    #[inline]
+    pub fn get_bulk_data(&self, key: Key) -> Option<&BulkDataRef> {
+        self.bulk_data.get(key.bytes())
+    }
+
+    /// Value of `key`, read from the source `reader` if it was deferred.
+    pub async fn fetch<R>(&self, reader: &mut R, key: Key) -> Result<Option<Value>>
+    where
+        R: AsyncReadExt + AsyncSeekExt + Unpin,
+    {
+        if let Some(value) = self.map.get(key.bytes()) {
+            return Ok(Some(Value::new(value.clone())));
+        }
+
+        match self.bulk_data.get(key.bytes()) {
+            Some(bulk_data) => Ok(Some(bulk_data.read(reader).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Blocking [`KVMap::fetch`].
+    pub fn fetch_sync<R>(&self, reader: &mut R, key: Key) -> Result<Option<Value>>
+    where
+        R: Read + Seek,
+    {
+        if let Some(value) = self.map.get(key.bytes()) {
+            return Ok(Some(Value::new(value.clone())));
+        }
+
+        match self.bulk_data.get(key.bytes()) {
+            Some(bulk_data) => Ok(Some(bulk_data.read_sync(reader)?)),
+            None => Ok(None),
+        }
+    }
+
     #[inline]
     pub fn remove(&mut self, key: Key) -> Option<Vec<u8>> {
-        self.map.shift_remove(key.bytes())
+        self.bulk_data.remove(key.bytes());
+        self.map.remove(key.bytes())
     }
 
     #[inline]
     pub fn insert(&mut self, key: Key, value: Value) {
         self.map.insert(key.into_bytes(), value.into_bytes());
     }
+
+    /// Adds the keys and values of `other`, replacing the ones of the same
+    /// keys, in linear time (plus a lookup per deferred value).
+    ///
+    /// ```
+    /// use dckv::{KVMap, Value};
+    ///
+    /// let map = |entries: &[(&str, &str)]| {
+    ///     let mut kvmap = KVMap::new();
+    ///     for (key, value) in entries {
+    ///         kvmap.insert(key.parse().unwrap(), Value::new(value.as_bytes().to_vec()));
+    ///     }
+    ///     kvmap
+    /// };
+    /// let old = map(&[("PatientName", "Doe^John"), ("PatientID", "1")]);
+    /// let new = map(&[("PatientName", "Doe^Jane"), ("PatientSex", "F")]);
+    ///
+    /// let changes = old
+    ///     .diff(&new)
+    ///     .map(|(key, _, value)| (key.keyword().unwrap(), value.is_some()))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(changes, [("PatientName", true), ("PatientID", false), ("PatientSex", true)]);
+    ///
+    /// let mut merged = old.clone();
+    /// merged.merge(new.clone());
+    /// assert_eq!(merged, map(&[("PatientName", "Doe^Jane"), ("PatientID", "1"), ("PatientSex", "F")]));
+    /// ```
+    pub fn merge(&mut self, mut other: KVMap) {
+        if !self.bulk_data.is_empty() {
+            self.bulk_data.retain(|key, _| !other.map.contains_key(key));
+        }
+        if !other.bulk_data.is_empty() {
+            self.map.retain(|key, _| !other.bulk_data.contains_key(key));
+        }
+
+        self.map.append(&mut other.map);
+        self.bulk_data.append(&mut other.bulk_data);
+    }
+
+    /// Keys whose value differs from `other`, in key order and linear time,
+    /// with their value in `self` and in `other`, `None` when missing.
+    /// Deferred values are not compared.
+    pub fn diff<'m>(
+        &'m self,
+        other: &'m KVMap,
+    ) -> impl Iterator<Item = (KeyRef<'m>, Option<&'m Vec<u8>>, Option<&'m Vec<u8>>)> {
+        let mut left = self.map.iter().peekable();
+        let mut right = other.map.iter().peekable();
+
+        std::iter::from_fn(move || loop {
+            let order = match (left.peek(), right.peek()) {
+                (None, None) => return None,
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (Some((left_key, _)), Some((right_key, _))) => left_key.cmp(right_key),
+            };
+
+            let (key, left_value, right_value) = match order {
+                Ordering::Less => {
+                    let (key, value) = left.next()?;
+                    (key, Some(value), None)
+                }
+                Ordering::Greater => {
+                    let (key, value) = right.next()?;
+                    (key, None, Some(value))
+                }
+                Ordering::Equal => {
+                    let (key, value) = left.next()?;
+                    (key, Some(value), right.next().map(|(_, value)| value))
+                }
+            };
+
+            if left_value != right_value {
+                return Some((KeyRef { bytes: key }, left_value, right_value));
+            }
+        })
+    }
+
+    /// Writes every key and value to a DCKV file, in key order.
+    /// Deferred values are not written: fetch them first.
+    pub fn write_dckv<W>(&self, writer: &mut DCKVWriter<W>) -> Result<()>
+    where
+        W: Write,
+    {
+        if writer.format() != DCKVFormat::Dckv {
+            return Err(DCKVError::UnexpectedDCKVFormat(writer.format()));
+        }
+        if !self.bulk_data.is_empty() {
+            return Err(DCKVError::DeferredValue);
+        }
+
+        for (key, value) in &self.map {
+            writer.write(KeyRef { bytes: key }, value)?;
+        }
+
+        Ok(())
+    }
+
+    /// Map of the keys and values of a DCKV file.
+    pub fn read_dckv<R>(reader: DCKVReader<R>) -> Result<Self>
+    where
+        R: Read,
+    {
+        let mut kvmap = Self::new();
+        for record in reader {
+            let (key, value) = record?;
+            kvmap.insert(key, value);
+        }
+
+        Ok(kvmap)
+    }
+
+    /// Fragment `number` of the top-level encapsulated Pixel Data
+    /// (7FE0,0010), of VR OB or OW, fragment 0 being the Basic Offset
+    /// Table. Deferred fragments are not returned.
+    #[inline]
+    pub fn fragment(&self, number: u16) -> Option<&Vec<u8>> {
+        [vr::OB, vr::OW].into_iter().find_map(|vr| {
+            let key = block::fragment(0x7FE00010, vr, number as u64).to_be_bytes();
+            self.map.get(key.as_slice())
+        })
+    }
+
+    /// Length and value, unless deferred, of the fragment `number`.
+    fn fragment_entry(&self, number: u16) -> Option<(u64, Option<&Vec<u8>>)> {
+        [vr::OB, vr::OW].into_iter().find_map(|vr| {
+            let key = block::fragment(0x7FE00010, vr, number as u64).to_be_bytes();
+            match (
+                self.map.get(key.as_slice()),
+                self.bulk_data.get(key.as_slice()),
+            ) {
+                (Some(value), _) => Some((value.len() as u64, Some(value))),
+                (None, Some(bulk_data)) => Some((bulk_data.length() as u64, None)),
+                (None, None) => None,
+            }
+        })
+    }
+
+    /// Frame `index` of the top-level encapsulated Pixel Data, gathering
+    /// its fragments through the Extended Offset Table (7FE0,0001) and its
+    /// lengths (7FE0,0002), or else the Basic Offset Table. Without
+    /// offsets, multi-frame images are expected to hold one fragment per
+    /// frame (PS3.5 A.4).
+    ///
+    /// `None` without fragments or such a frame. Fails if a fragment of
+    /// the frame is deferred, to be fetched through its [`BulkDataRef`].
+    ///
+    /// ```
+    /// use dckv::{KVMap, Value};
+    ///
+    /// let mut kvmap = KVMap::new();
+    /// for (key, value) in [
+    ///     ("NumberOfFrames", &b"2 "[..]),
+    ///     ("7FE00010:OB#0", &[0, 0, 0, 0, 12, 0, 0, 0]),
+    ///     ("7FE00010:OB#1", &[1, 2, 3, 4]),
+    ///     ("7FE00010:OB#2", &[5, 6]),
+    /// ] {
+    ///     kvmap.insert(key.parse().unwrap(), Value::new(value.to_vec()));
+    /// }
+    ///
+    /// assert_eq!(kvmap.frame(0).unwrap(), Some(vec![1, 2, 3, 4]));
+    /// assert_eq!(kvmap.frame(1).unwrap(), Some(vec![5, 6]));
+    /// assert_eq!(kvmap.frame(2).unwrap(), None);
+    /// ```
+    pub fn frame(&self, index: usize) -> Result<Option<Vec<u8>>> {
+        let fragments = (1..=u16::MAX)
+            .map_while(|number| self.fragment_entry(number))
+            .collect::<Vec<_>>();
+        if fragments.is_empty() {
+            return Ok(None);
+        }
+        fn value<'m>((_, value): &(u64, Option<&'m Vec<u8>>)) -> Result<&'m Vec<u8>> {
+            value.ok_or(DCKVError::DeferredValue)
+        }
+
+        let table = |tag: u32| self.get_tag(tag).map(|(_, value)| value.as_slice());
+        let (offsets, lengths) = match table(0x7FE00001) {
+            Some(offsets) => (
+                offsets
+                    .chunks_exact(8)
+                    .map(decode_u64)
+                    .collect::<Vec<u64>>(),
+                table(0x7FE00002)
+                    .map(|lengths| lengths.chunks_exact(8).map(decode_u64).collect())
+                    .unwrap_or_default(),
+            ),
+            None => (
+                self.fragment(0)
+                    .map(|offsets| offsets.chunks_exact(4).map(decode_u32).collect())
+                    .unwrap_or_default(),
+                vec![],
+            ),
+        };
+
+        if offsets.is_empty() {
+            return match (self.number_of_frames(), index) {
+                (1, 0) => {
+                    let values = fragments.iter().map(value).collect::<Result<Vec<_>>>()?;
+                    Ok(Some(values.into_iter().flatten().copied().collect()))
+                }
+                (1, _) => Ok(None),
+                _ => fragments
+                    .get(index)
+                    .map(value)
+                    .transpose()
+                    .map(|value| value.cloned()),
+            };
+        }
+
+        let Some(&start) = offsets.get(index) else {
+            return Ok(None);
+        };
+        let end = offsets.get(index + 1).copied().unwrap_or(u64::MAX);
+
+        let mut frame = vec![];
+        let mut position = 0;
+        for fragment in &fragments {
+            if position >= end {
+                break;
+            }
+            if position >= start {
+                frame.extend_from_slice(value(fragment)?);
+            }
+            // Offsets count the item tag and length of every fragment.
+            position += fragment.0 + 8;
+        }
+
+        // Lengths leave out the padding of the last fragment.
+        if let Some(&length) = lengths.get(index) {
+            frame.truncate(length as usize);
+        }
+
+        Ok(Some(frame))
+    }
+
+    /// Study date and time in its timezone, from (0008,0020) Study Date,
+    /// (0008,0030) Study Time and (0008,0201) Timezone Offset From UTC.
+    /// `None` without a study date, midnight without a study time, and UTC
+    /// without an offset.
+    pub fn study_datetime(&self) -> Result<Option<DateTime<FixedOffset>>> {
+        let value = |tag: u32, vr: u16| {
+            self.map
+                .get(element_key(tag, vr).as_slice())
+                .filter(|value| !value.is_empty())
+                .map(|value| Value::new(value.clone()))
+        };
+
+        let Some(date) = value(0x00080020, vr::DA) else {
+            return Ok(None);
+        };
+        let date = date.to_date()?;
+        let time = match value(0x00080030, vr::TM) {
+            Some(time) => time.to_time()?,
+            None => NaiveTime::MIN,
+        };
+        let offset = match value(0x00080201, vr::SH) {
+            Some(offset) => offset.to_offset()?,
+            None => Utc.fix(),
+        };
+
+        date.and_time(time)
+            .and_local_timezone(offset)
+            .single()
+            .map(Some)
+            .ok_or_else(|| DCKVError::InvalidDateTime(date.to_string()))
+    }
+
+    /// (0028,0008) Number of Frames, 1 when missing.
+    fn number_of_frames(&self) -> usize {
+        self.map
+            .get(element_key(0x00280008, vr::IS).as_slice())
+            .and_then(|value| std::str::from_utf8(value).ok())
+            .and_then(|value| value.trim_matches([' ', '\0']).parse().ok())
+            .unwrap_or(1)
+    }
+}
+
+impl PartialEq for KVMap {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map && self.bulk_data == other.bulk_data
+    }
 }
 
+impl Eq for KVMap {}
+
 #[async_trait(?Send)]
 impl Deserializer for KVMap {
     #[inline]
@@ -45,8 +638,90 @@ impl Deserializer for KVMap {
         key: Key,
         length: usize,
         _vr: Option<u16>,
-    ) {
-        let value = Value::read(reader, length).await.unwrap();
+    ) -> Result<Control> {
+        let value = Value::read(reader, length).await?;
+        self.insert(key, value);
+
+        Ok(Control::Continue)
+    }
+
+    #[inline]
+    fn bulk_data_policy(&self) -> Option<&BulkDataPolicy> {
+        self.bulk_data_policy.as_ref()
+    }
+
+    #[inline]
+    fn append_bulk_data(&mut self, key: Key, bulk_data: BulkDataRef, _vr: u16) -> Result<Control> {
+        self.bulk_data.insert(key.into_bytes(), bulk_data);
+
+        Ok(Control::Continue)
+    }
+
+    #[inline]
+    fn private_dictionary(&self) -> Option<&PrivateDictionary> {
+        self.private_dictionary.as_ref()
+    }
+
+    #[inline]
+    fn max_depth(&self) -> usize {
+        self.max_depth.unwrap_or(MAX_DEPTH)
+    }
+}
+
+impl SyncDeserializer for KVMap {
+    #[inline]
+    fn append<R: Read + Seek>(
+        &mut self,
+        reader: &mut R,
+        key: Key,
+        length: usize,
+        _vr: Option<u16>,
+    ) -> Result<Control> {
+        let value = Value::read_sync(reader, length)?;
         self.insert(key, value);
+
+        Ok(Control::Continue)
+    }
+
+    #[inline]
+    fn bulk_data_policy(&self) -> Option<&BulkDataPolicy> {
+        self.bulk_data_policy.as_ref()
+    }
+
+    #[inline]
+    fn append_bulk_data(&mut self, key: Key, bulk_data: BulkDataRef, _vr: u16) -> Result<Control> {
+        self.bulk_data.insert(key.into_bytes(), bulk_data);
+
+        Ok(Control::Continue)
+    }
+
+    #[inline]
+    fn private_dictionary(&self) -> Option<&PrivateDictionary> {
+        self.private_dictionary.as_ref()
+    }
+
+    #[inline]
+    fn max_depth(&self) -> usize {
+        self.max_depth.unwrap_or(MAX_DEPTH)
+    }
+}
+
+/// Copies keys and values, the map owning its entries.
+impl BytesDeserializer for KVMap {
+    #[inline]
+    fn append(&mut self, key: KeyRef<'_>, value: Bytes, _vr: Option<u16>) -> Result<Control> {
+        self.map.insert(key.bytes().to_vec(), value.to_vec());
+
+        Ok(Control::Continue)
+    }
+
+    #[inline]
+    fn private_dictionary(&self) -> Option<&PrivateDictionary> {
+        self.private_dictionary.as_ref()
+    }
+
+    #[inline]
+    fn max_depth(&self) -> usize {
+        self.max_depth.unwrap_or(MAX_DEPTH)
     }
 }
@@ -0,0 +1,329 @@
+//! DICOM JSON model (PS3.18 F): a dataset is an object of attributes by
+//! tag, each one holding its `vr` and its `Value` array, `InlineBinary` or
+//! `BulkDataURI`.
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use std::ops::Bound;
+
+use super::{key_tag, KVMap};
+use crate::json::json_string;
+use crate::{vr, CharacterSet, DCKVError, KeyKind, KeyRef, PersonName, Result, Value};
+
+/// Attribute of a dataset: its key, the first fragment one for encapsulated
+/// values, its value, unless deferred, and whether it is encapsulated.
+struct Attribute<'m> {
+    key: KeyRef<'m>,
+    value: Option<&'m Vec<u8>>,
+    encapsulated: bool,
+}
+
+impl KVMap {
+    /// DICOM JSON object of the dataset (PS3.18 F.2), e.g. for the metadata
+    /// of an instance in WADO-RS.
+    ///
+    /// `bulk_data_uri` gives the BulkDataURI of the binary values, of VR OB,
+    /// OD, OF, OL, OV, OW or UN, or `None` to inline them in base64. Deferred
+    /// and encapsulated values cannot be inlined: they need a URI.
+    ///
+    /// The file meta information, group 0002, is not part of the dataset
+    /// and is left out. DS and IS values keep their text when it is a JSON
+    /// number, and malformed ones are kept as strings.
+    ///
+    /// ```
+    /// use dckv::{KVMap, Value};
+    ///
+    /// let mut kvmap = KVMap::new();
+    /// for (key, value) in [
+    ///     ("PatientName", &b"Doe^John"[..]),
+    ///     ("ContentSequence", b""),
+    ///     ("ContentSequence[1].start", b""),
+    ///     ("ContentSequence[1].ValueType", b"TEXT"),
+    ///     ("ContentSequence[1].end", b""),
+    ///     ("ContentSequence:SQ.end", b""),
+    ///     ("7FE00010:OW", &[0, 1, 2, 3]),
+    /// ] {
+    ///     kvmap.insert(key.parse().unwrap(), Value::new(value.to_vec()));
+    /// }
+    ///
+    /// let json = kvmap
+    ///     .to_json(|key| Some(format!("https://pacs/bulkdata/{key}")))
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     json,
+    ///     concat!(
+    ///         r#"{"00100010":{"vr":"PN","Value":[{"Alphabetic":"Doe^John"}]},"#,
+    ///         r#""0040A730":{"vr":"SQ","Value":[{"0040A040":{"vr":"CS","Value":["TEXT"]}}]},"#,
+    ///         r#""7FE00010":{"vr":"OW","BulkDataURI":"https://pacs/bulkdata/7FE00010:OW"}}"#,
+    ///     )
+    /// );
+    /// ```
+    pub fn to_json<F>(&self, bulk_data_uri: F) -> Result<String>
+    where
+        F: Fn(KeyRef<'_>) -> Option<String>,
+    {
+        let mut json = String::new();
+        self.write_dataset(&mut json, &[], &CharacterSet::default(), &bulk_data_uri)?;
+
+        Ok(json)
+    }
+
+    /// Attributes of the dataset `prefix`, the blocks of its item, in key
+    /// order.
+    fn attributes(&self, prefix: &[u8]) -> Vec<Attribute<'_>> {
+        let level = prefix.len() / 8;
+        let bounds = (Bound::Included(prefix), Bound::Unbounded);
+
+        let values = self
+            .map
+            .range::<[u8], _>(bounds)
+            .take_while(|(key, _)| key.starts_with(prefix))
+            .map(|(key, value)| (KeyRef { bytes: key }, Some(value)));
+        let deferred = self
+            .bulk_data
+            .range::<[u8], _>(bounds)
+            .take_while(|(key, _)| key.starts_with(prefix))
+            .map(|(key, _)| (KeyRef { bytes: key }, None));
+
+        let mut entries = values
+            .chain(deferred)
+            .filter(|(key, _)| key.level() == level)
+            .filter(|(key, _)| matches!(key.kind(), KeyKind::Element | KeyKind::SequenceStart))
+            .collect::<Vec<_>>();
+        entries.sort_unstable_by_key(|&(key, _)| key);
+
+        let mut attributes: Vec<Attribute<'_>> = vec![];
+        for (key, value) in entries {
+            match attributes.last_mut() {
+                // Fragments of the last attribute.
+                Some(last) if key_tag(&last.key) == key_tag(&key) => last.encapsulated = true,
+                _ => attributes.push(Attribute {
+                    key,
+                    value,
+                    encapsulated: false,
+                }),
+            }
+        }
+
+        attributes
+    }
+
+    fn write_dataset<F>(
+        &self,
+        json: &mut String,
+        prefix: &[u8],
+        charset: &CharacterSet,
+        bulk_data_uri: &F,
+    ) -> Result<()>
+    where
+        F: Fn(KeyRef<'_>) -> Option<String>,
+    {
+        // The file meta information is not part of the dataset.
+        let attributes = self
+            .attributes(prefix)
+            .into_iter()
+            .filter(|attribute| attribute.key.group() != 0x0002)
+            .collect::<Vec<_>>();
+
+        // (0008,0005) Specific Character Set of the dataset, if any.
+        let own_charset = attributes
+            .iter()
+            .find(|attribute| key_tag(&attribute.key) == 0x00080005)
+            .and_then(|attribute| attribute.value)
+            .map(|value| CharacterSet::from_value(&Value::new(value.clone())))
+            .transpose()?;
+        let charset = own_charset.as_ref().unwrap_or(charset);
+
+        json.push('{');
+        for (index, attribute) in attributes.iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+            json.push_str(&format!("\"{:08X}\":", key_tag(&attribute.key)));
+            self.write_attribute(json, attribute, charset, bulk_data_uri)?;
+        }
+        json.push('}');
+
+        Ok(())
+    }
+
+    fn write_attribute<F>(
+        &self,
+        json: &mut String,
+        attribute: &Attribute<'_>,
+        charset: &CharacterSet,
+        bulk_data_uri: &F,
+    ) -> Result<()>
+    where
+        F: Fn(KeyRef<'_>) -> Option<String>,
+    {
+        let key = attribute.key;
+
+        if key.kind() == KeyKind::SequenceStart {
+            json.push_str("{\"vr\":\"SQ\"");
+
+            let items = self.items(&key.to_key()).collect::<Vec<_>>();
+            if !items.is_empty() {
+                json.push_str(",\"Value\":[");
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        json.push(',');
+                    }
+                    let prefix = &item.bytes()[..item.level() * 8];
+                    self.write_dataset(json, prefix, charset, bulk_data_uri)?;
+                }
+                json.push(']');
+            }
+
+            json.push('}');
+            return Ok(());
+        }
+
+        let vr_text = key.vr()?;
+        let vr = u16::from_be_bytes([vr_text.as_bytes()[0], vr_text.as_bytes()[1]]);
+        json.push_str(&format!("{{\"vr\":{}", json_string(vr_text)));
+
+        let is_binary = matches!(
+            vr,
+            vr::OB | vr::OD | vr::OF | vr::OL | vr::OV | vr::OW | vr::UN
+        );
+
+        let is_bulk = is_binary || attribute.encapsulated || attribute.value.is_none();
+
+        match attribute.value {
+            Some(value) if value.is_empty() && !attribute.encapsulated => {}
+            _ if is_bulk => match (bulk_data_uri(key), attribute.value) {
+                (Some(uri), _) => {
+                    json.push_str(&format!(",\"BulkDataURI\":{}", json_string(&uri)));
+                }
+                (None, Some(value)) if !attribute.encapsulated => {
+                    json.push_str(&format!(",\"InlineBinary\":\"{}\"", STANDARD.encode(value)));
+                }
+                (None, _) => return Err(DCKVError::MissingBulkDataURI(key.to_string())),
+            },
+            Some(value) => {
+                let values = values(&Value::new(value.clone()), vr, charset)?;
+                if !values.is_empty() {
+                    json.push_str(&format!(",\"Value\":[{}]", values.join(",")));
+                }
+            }
+            None => {}
+        }
+
+        json.push('}');
+
+        Ok(())
+    }
+}
+
+/// JSON values of a value of `vr` (PS3.18 F.2.3), empty values being
+/// `null`.
+fn values(value: &Value, vr: u16, charset: &CharacterSet) -> Result<Vec<String>> {
+    let texts = || value.to_texts(vr, charset).into_iter();
+    let or_null = |json: Option<String>| json.unwrap_or_else(|| "null".to_string());
+
+    let values = match vr {
+        vr::PN => texts()
+            .map(|text| match text.is_empty() {
+                true => "null".to_string(),
+                false => person_name(&text),
+            })
+            .collect(),
+        vr::DS => texts()
+            .map(|text| number(text.trim(), |number: &f64| number.is_finite()))
+            .collect(),
+        vr::IS => texts()
+            .map(|text| number(text.trim(), |_: &i64| true))
+            .collect(),
+        vr::AT => value
+            .to_tags()?
+            .into_iter()
+            .map(|(group, element)| format!("\"{group:04X}{element:04X}\""))
+            .collect(),
+        vr::US => numbers(value.to_u16s()?),
+        vr::SS => numbers(value.to_i16s()?),
+        vr::UL => numbers(value.to_u32s()?),
+        vr::SL => numbers(value.to_i32s()?),
+        vr::UV => numbers(value.to_u64s()?),
+        vr::SV => numbers(value.to_i64s()?),
+        vr::FL => value
+            .to_f32s()?
+            .into_iter()
+            .map(|float| or_null(Some(float.to_string()).filter(|_| float.is_finite())))
+            .collect(),
+        vr::FD => value
+            .to_f64s()?
+            .into_iter()
+            .map(|float| or_null(Some(float.to_string()).filter(|_| float.is_finite())))
+            .collect(),
+        _ => texts()
+            .map(|text| or_null(Some(json_string(&text)).filter(|_| !text.is_empty())))
+            .collect(),
+    };
+
+    Ok(values)
+}
+
+/// JSON Person Name object of a PN value. A name with too many groups or
+/// components is kept whole as its alphabetic representation.
+#[inline]
+fn person_name(text: &str) -> String {
+    match text.parse::<PersonName>() {
+        Ok(name) => name.to_json(),
+        Err(_) => format!("{{\"Alphabetic\":{}}}", json_string(text)),
+    }
+}
+
+/// JSON number of an IS or DS value, `null` when empty: its text once
+/// validated, so that no digit is lost, or the number it reads as when
+/// JSON does not allow its text, e.g. `+1` or `.5`. Malformed values and
+/// numbers out of the JSON ones, e.g. `NaN`, are kept as strings.
+#[inline]
+fn number<T>(text: &str, is_valid: fn(&T) -> bool) -> String
+where
+    T: std::str::FromStr + ToString,
+{
+    if text.is_empty() {
+        return "null".to_string();
+    }
+
+    match text.parse::<T>().ok().filter(is_valid) {
+        Some(_) if is_json_number(text) => text.to_string(),
+        Some(number) => number.to_string(),
+        None => json_string(text),
+    }
+}
+
+/// Whether `text` follows the JSON number grammar (RFC 8259 6).
+fn is_json_number(text: &str) -> bool {
+    let digits = |text: &str| text.bytes().take_while(u8::is_ascii_digit).count();
+
+    let rest = text.strip_prefix('-').unwrap_or(text);
+    let rest = match digits(rest) {
+        0 => return false,
+        n if n > 1 && rest.starts_with('0') => return false,
+        n => &rest[n..],
+    };
+    let rest = match rest.strip_prefix('.') {
+        Some(fraction) => match digits(fraction) {
+            0 => return false,
+            n => &fraction[n..],
+        },
+        None => rest,
+    };
+    match rest.strip_prefix(['e', 'E']) {
+        Some(exponent) => {
+            let exponent = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+            digits(exponent) > 0 && digits(exponent) == exponent.len()
+        }
+        None => rest.is_empty(),
+    }
+}
+
+#[inline]
+fn numbers<T>(numbers: Vec<T>) -> Vec<String>
+where
+    T: ToString,
+{
+    numbers.iter().map(T::to_string).collect()
+}
@@ -0,0 +1,213 @@
+//! Studies: the instances of a study under eDCKV keys, numbered by series
+//! and instance.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{Read, Write};
+use std::ops::Bound;
+
+use super::KVMap;
+use crate::{
+    block, BulkDataRef, DCKVError, DCKVFormat, DCKVReader, DCKVWriter, EKey, KeyRef, Result, Value,
+};
+
+const SOP_INSTANCE_UID: u32 = 0x00080018;
+const SERIES_INSTANCE_UID: u32 = 0x0020000E;
+const SERIES_NUMBER: u32 = 0x00200011;
+const INSTANCE_NUMBER: u32 = 0x00200013;
+
+/// Keys and values of the instances of a study, sorted by series, then
+/// instance, then key. Each instance keeps the order of a [`KVMap`].
+///
+/// ```
+/// use dckv::{EKey, KVMap, StudyMap, Value};
+///
+/// let mut kvmap = KVMap::new();
+/// kvmap.insert("00100010:PN".parse().unwrap(), Value::new(b"Doe^John".to_vec()));
+///
+/// let mut study = StudyMap::new();
+/// study.add_instance(1, 2, kvmap.clone());
+///
+/// let key = EKey::new(1, 2, "00100010:PN".parse::<dckv::Key>().unwrap().as_key_ref());
+/// assert_eq!(study.get(&key).unwrap(), b"Doe^John");
+/// assert_eq!(study.instance(1, 2), kvmap);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StudyMap {
+    map: BTreeMap<Vec<u8>, Vec<u8>>,
+    bulk_data: BTreeMap<Vec<u8>, BulkDataRef>,
+}
+
+impl StudyMap {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Study of parsed instances. Series are numbered from 1 in the order
+    /// of their (0020,0011) Series Number, then of their (0020,000E) Series
+    /// Instance UID, and the instances of a series in the order of their
+    /// (0020,0013) Instance Number, then of their (0008,0018) SOP Instance
+    /// UID. Missing numbers sort last.
+    pub fn from_instances<I>(instances: I) -> Self
+    where
+        I: IntoIterator<Item = KVMap>,
+    {
+        let mut series = BTreeMap::<String, Vec<KVMap>>::new();
+        for kvmap in instances {
+            series
+                .entry(text(&kvmap, SERIES_INSTANCE_UID).unwrap_or_default())
+                .or_default()
+                .push(kvmap);
+        }
+
+        let mut series = series.into_iter().collect::<Vec<_>>();
+        series.sort_by_cached_key(|(uid, instances)| {
+            (number(&instances[0], SERIES_NUMBER), uid.clone())
+        });
+
+        let mut study = Self::new();
+        for (series_number, (_, mut instances)) in (1..).zip(series) {
+            instances.sort_by_cached_key(|kvmap| {
+                (
+                    number(kvmap, INSTANCE_NUMBER),
+                    text(kvmap, SOP_INSTANCE_UID),
+                )
+            });
+
+            for (instance_number, kvmap) in (1..).zip(instances) {
+                study.add_instance(series_number, instance_number, kvmap);
+            }
+        }
+
+        study
+    }
+
+    #[inline]
+    pub fn get(&self, key: &EKey) -> Option<&Vec<u8>> {
+        self.map.get(key.bytes())
+    }
+
+    /// Reference of a deferred value.
+    #[inline]
+    pub fn get_bulk_data(&self, key: &EKey) -> Option<&BulkDataRef> {
+        self.bulk_data.get(key.bytes())
+    }
+
+    #[inline]
+    pub fn insert(&mut self, key: EKey, value: Value) {
+        self.map.insert(key.into_bytes(), value.into_bytes());
+    }
+
+    /// Adds the keys and values of an instance, prefixing them with
+    /// `series` and `instance`. Deferred values stay deferred, into the
+    /// source of the instance.
+    pub fn add_instance(&mut self, series: u32, instance: u32, kvmap: KVMap) {
+        let prefix = block::prefix(series, instance).to_be_bytes();
+        let prefixed = |key: Vec<u8>| [prefix.as_slice(), &key].concat();
+
+        self.map.extend(
+            kvmap
+                .map
+                .into_iter()
+                .map(|(key, value)| (prefixed(key), value)),
+        );
+        self.bulk_data.extend(
+            kvmap
+                .bulk_data
+                .into_iter()
+                .map(|(key, bulk_data)| (prefixed(key), bulk_data)),
+        );
+    }
+
+    /// Keys and values of an instance, without their prefix.
+    pub fn instance(&self, series: u32, instance: u32) -> KVMap {
+        let prefix = block::prefix(series, instance).to_be_bytes();
+        let next = block::prefix(series, instance)
+            .checked_add(1)
+            .map(u64::to_be_bytes);
+        let bounds = (
+            Bound::Included(prefix.as_slice()),
+            next.as_ref()
+                .map_or(Bound::Unbounded, |next| Bound::Excluded(next.as_slice())),
+        );
+
+        KVMap {
+            map: self
+                .map
+                .range::<[u8], _>(bounds)
+                .map(|(key, value)| (key[8..].to_vec(), value.clone()))
+                .collect(),
+            bulk_data: self
+                .bulk_data
+                .range::<[u8], _>(bounds)
+                .map(|(key, bulk_data)| (key[8..].to_vec(), *bulk_data))
+                .collect(),
+            ..KVMap::new()
+        }
+    }
+
+    /// Series and instance numbers of the instances, in order.
+    pub fn instances(&self) -> impl Iterator<Item = (u32, u32)> {
+        self.map
+            .keys()
+            .chain(self.bulk_data.keys())
+            .map(|key| {
+                let prefix = u64::from_be_bytes(key[..8].try_into().unwrap_or_default());
+                ((prefix >> 32) as u32, prefix as u32)
+            })
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+    }
+
+    /// Writes every key and value to an eDCKV file, in key order.
+    /// Deferred values are not written: fetch them first.
+    pub fn write_dckv<W>(&self, writer: &mut DCKVWriter<W>) -> Result<()>
+    where
+        W: Write,
+    {
+        if writer.format() != DCKVFormat::Edckv {
+            return Err(DCKVError::UnexpectedDCKVFormat(writer.format()));
+        }
+        if !self.bulk_data.is_empty() {
+            return Err(DCKVError::DeferredValue);
+        }
+
+        for (key, value) in &self.map {
+            writer.write(KeyRef { bytes: key }, value)?;
+        }
+
+        Ok(())
+    }
+
+    /// Map of the keys and values of an eDCKV file.
+    pub fn read_dckv<R>(reader: DCKVReader<R, EKey>) -> Result<Self>
+    where
+        R: Read,
+    {
+        let mut study = Self::new();
+        for record in reader {
+            let (key, value) = record?;
+            study.insert(key, value);
+        }
+
+        Ok(study)
+    }
+}
+
+/// Text of a top-level element, without its padding.
+fn text(kvmap: &KVMap, tag: u32) -> Option<String> {
+    let (_, value) = kvmap.get_tag(tag)?;
+    let text = String::from_utf8_lossy(value);
+    Some(
+        text.trim_matches(|c: char| c == ' ' || c == '\0')
+            .to_string(),
+    )
+}
+
+/// Integer string of a top-level element, `None`s sorting last.
+fn number(kvmap: &KVMap, tag: u32) -> (bool, i64) {
+    match text(kvmap, tag).and_then(|text| text.parse().ok()) {
+        Some(number) => (false, number),
+        None => (true, 0),
+    }
+}
@@ -3,15 +3,43 @@
 
 use std::str;
 
+mod bulk;
+mod bytes_parser;
+mod charset;
+mod datetime;
+mod dictionary;
 mod error;
+mod format;
+mod json;
+mod key_kind;
+mod key_text;
 mod kvmap;
+mod machine;
+mod meta;
 mod parser;
 mod filter;
+mod path;
+mod person_name;
+mod private;
+mod sync_parser;
+mod value;
 
+pub use bulk::{BulkDataPolicy, BulkDataRef};
+pub use bytes_parser::BytesDeserializer;
+pub use charset::CharacterSet;
+pub use datetime::DateTimeRange;
+pub use dictionary::{Dictionary, DictionaryEntry, PrivateDictionary};
 pub use error::DCKVError;
-pub use kvmap::KVMap;
-pub use parser::{Deserializer, Value};
+pub use format::{DCKVFormat, DCKVKey, DCKVReader, DCKVWriter, DCKV_VERSION};
+pub use key_kind::KeyKind;
+pub use kvmap::{KVMap, StudyMap};
+pub use meta::{FileMeta, TransferSyntax};
+pub use parser::{Control, Deserializer};
 pub use filter::Filter;
+pub use path::TagPath;
+pub use person_name::{NameGroup, PersonName};
+pub use sync_parser::SyncDeserializer;
+pub use value::Value;
 
 pub type Result<T> = std::result::Result<T, DCKVError>;
 
@@ -52,28 +80,301 @@ mod vr {
     pub(crate) const UN: u16 = 0x554E;
 
     pub(crate) const SQ: u16 = 0x5351;
+
+    /// VRs encoded with a 2 bytes reserved field and a 4 bytes length in
+    /// explicit VR transfer syntaxes.
+    #[inline]
+    pub(crate) fn is_long(vr: u16) -> bool {
+        matches!(
+            vr,
+            OB | OD | OF | OL | OV | OW | SQ | SV | UC | UR | UT | UN | UV
+        )
+    }
+
+    /// VRs of elements holding a value, i.e. every VR but SQ.
+    #[inline]
+    pub(crate) fn is_value(vr: u16) -> bool {
+        matches!(
+            vr,
+            AE | AS
+                | AT
+                | CS
+                | DA
+                | DS
+                | DT
+                | FL
+                | FD
+                | IS
+                | LO
+                | LT
+                | PN
+                | SH
+                | SL
+                | SS
+                | ST
+                | TM
+                | UI
+                | UL
+                | US
+                | UC
+                | UT
+                | UR
+                | SV
+                | UV
+                | OB
+                | OD
+                | OF
+                | OL
+                | OV
+                | OW
+                | UN
+        )
+    }
+
+    /// Size of the binary numbers held by a VR, i.e. the unit to byte swap
+    /// when the byte order changes. `None` for byte and text VRs.
+    #[inline]
+    pub(crate) fn swap_width(vr: u16) -> Option<usize> {
+        match vr {
+            AT | OW | SS | US => Some(2),
+            FL | OF | OL | SL | UL => Some(4),
+            FD | OD | OV | SV | UV => Some(8),
+            _ => None,
+        }
+    }
+}
+
+/// Key blocks, a key holding one block per nesting level.
+mod block {
+    /// Last block of the key opening an item.
+    pub(crate) const ITEM_START: u64 = 0x2b2b0000;
+    /// Last block of the key closing an item.
+    pub(crate) const ITEM_END: u64 = 0xFFFFFFFF5F5F0000;
+
+    #[inline]
+    pub(crate) fn element(tag: u32, vr: u16) -> u64 {
+        ((tag as u64) << 32) + ((vr as u64) << 16)
+    }
+
+    /// Fragment `number` of an encapsulated value, 0 being the basic offset
+    /// table.
+    #[inline]
+    pub(crate) fn fragment(tag: u32, vr: u16, number: u64) -> u64 {
+        element(tag, vr) + number
+    }
+
+    #[inline]
+    pub(crate) fn sequence(tag: u32) -> u64 {
+        (tag as u64) << 32
+    }
+
+    /// Item `number` of a sequence, starting at 1. The next level holds the
+    /// item start, end and elements.
+    #[inline]
+    pub(crate) fn item(tag: u32, number: u64) -> u64 {
+        sequence(tag) + number
+    }
+
+    #[inline]
+    pub(crate) fn sequence_end(tag: u32) -> u64 {
+        sequence(tag) + 0xFFFF0000
+    }
+
+    /// eDCKV prefix of the keys of an instance.
+    #[inline]
+    pub(crate) fn prefix(series: u32, instance: u32) -> u64 {
+        ((series as u64) << 32) + instance as u64
+    }
+}
+
+/// Default maximum number of nested sequence levels, see
+/// [`Deserializer::max_depth`].
+pub const MAX_DEPTH: usize = 64;
+
+/// Blocks of the key being parsed, one per nesting level, the last one
+/// being replaced element after element, with the private creator
+/// reservations of its levels.
+#[derive(Debug, Clone)]
+struct KeyBlocks {
+    blocks: Vec<u64>,
+    creators: private::PrivateCreators,
+}
+
+impl KeyBlocks {
+    /// Blocks of a top-level key.
+    #[inline]
+    fn new() -> Self {
+        Self {
+            blocks: vec![0],
+            creators: private::PrivateCreators::default(),
+        }
+    }
+
+    /// Records the private creator element `tag`, holding `value`, of the
+    /// last level.
+    #[inline]
+    fn reserve(&mut self, tag: u32, value: &[u8]) {
+        self.creators.reserve(self.blocks.len() - 1, tag, value);
+    }
+
+    /// Creator of the private data element `tag` of the last level.
+    #[inline]
+    fn creator(&self, tag: u32) -> Option<&str> {
+        self.creators.creator(self.blocks.len() - 1, tag)
+    }
+
+    /// Leaves the item of the last level, whose private creator
+    /// reservations end with it.
+    #[inline]
+    fn leave_item(&mut self) {
+        self.blocks.pop();
+        self.creators.leave(self.blocks.len() - 1);
+    }
 }
 
-// Supports max. 12 sequence nested levels.
-type KeyBlocks = [u64; 12];
+impl std::ops::Deref for KeyBlocks {
+    type Target = Vec<u64>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.blocks
+    }
+}
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+impl std::ops::DerefMut for KeyBlocks {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.blocks
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Key {
     bytes: Vec<u8>,
 }
 
 impl Key {
     #[inline]
-    fn from_key_blocks(key_blocks: &mut [u64; 12]) -> Self {
+    fn from_key_blocks(key_blocks: &KeyBlocks) -> Self {
         let bytes = key_blocks
             .iter()
-            .filter(|&&t| t != 0)
             .flat_map(|&t| t.to_be_bytes())
             .collect::<Vec<u8>>();
 
         Self { bytes }
     }
 
+    #[inline]
+    pub fn as_key_ref(&self) -> KeyRef<'_> {
+        KeyRef { bytes: &self.bytes }
+    }
+
+    #[inline]
+    pub fn level(&self) -> usize {
+        self.as_key_ref().level()
+    }
+
+    #[inline]
+    pub fn group(&self) -> u16 {
+        self.as_key_ref().group()
+    }
+
+    #[inline]
+    pub fn element(&self) -> u16 {
+        self.as_key_ref().element()
+    }
+
+    #[inline]
+    pub fn vr(&self) -> Result<&str> {
+        self.as_key_ref().vr()
+    }
+
+    #[inline]
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    #[inline]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+/// eDCKV key: a [`Key`] prefixed with a block holding the series and
+/// instance numbers it belongs to. Keys sort by series, then instance, then
+/// attribute.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EKey {
+    bytes: Vec<u8>,
+}
+
+impl EKey {
+    #[inline]
+    pub fn new(series: u32, instance: u32, key: KeyRef<'_>) -> Self {
+        let mut bytes = Vec::with_capacity(key.bytes.len() + 8);
+        bytes.extend_from_slice(&block::prefix(series, instance).to_be_bytes());
+        bytes.extend_from_slice(key.bytes);
+
+        Self { bytes }
+    }
+
+    /// eDCKV key of `bytes`, a prefix block and at least one key block.
+    #[inline]
+    pub(crate) fn from_bytes(bytes: Vec<u8>) -> Self {
+        Self { bytes }
+    }
+
+    #[inline]
+    pub fn series(&self) -> u32 {
+        u32::from_be_bytes([self.bytes[0], self.bytes[1], self.bytes[2], self.bytes[3]])
+    }
+
+    #[inline]
+    pub fn instance(&self) -> u32 {
+        u32::from_be_bytes([self.bytes[4], self.bytes[5], self.bytes[6], self.bytes[7]])
+    }
+
+    /// Key without its prefix.
+    #[inline]
+    pub fn key(&self) -> KeyRef<'_> {
+        KeyRef {
+            bytes: &self.bytes[8..],
+        }
+    }
+
+    #[inline]
+    pub fn level(&self) -> usize {
+        self.key().level()
+    }
+
+    #[inline]
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    #[inline]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+/// Borrowed [`Key`], viewing bytes owned elsewhere, e.g. by the parser.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct KeyRef<'k> {
+    bytes: &'k [u8],
+}
+
+impl<'k> KeyRef<'k> {
+    /// Writes the key of `key_blocks` into `buffer`, reusing its
+    /// allocation, and views it.
+    #[inline]
+    fn from_key_blocks(buffer: &'k mut Vec<u8>, key_blocks: &KeyBlocks) -> Self {
+        buffer.clear();
+        buffer.extend(key_blocks.iter().flat_map(|&t| t.to_be_bytes()));
+
+        Self { bytes: buffer }
+    }
+
     #[inline]
     pub fn level(&self) -> usize {
         (self.bytes.len() / 8).saturating_sub(1)
@@ -96,18 +397,20 @@ impl Key {
     }
 
     #[inline]
-    pub fn vr(&self) -> Result<&str> {
+    pub fn vr(&self) -> Result<&'k str> {
         let vr_text = str::from_utf8(&self.bytes[self.level() * 8 + 4..self.level() * 8 + 6])?;
         Ok(vr_text)
     }
 
     #[inline]
-    pub fn bytes(&self) -> &[u8] {
-        &self.bytes
+    pub fn bytes(&self) -> &'k [u8] {
+        self.bytes
     }
 
     #[inline]
-    pub fn into_bytes(self) -> Vec<u8> {
-        self.bytes
+    pub fn to_key(&self) -> Key {
+        Key {
+            bytes: self.bytes.to_vec(),
+        }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,897 @@
+//! Parsing state machine shared by the async, blocking and in-memory
+//! deserializers. It does no I/O: each [`Step`] tells its driver which
+//! bytes to read, peek or skip, or which key to append, so that drivers only
+//! move bytes between their source and their deserializer.
+
+use flate2::read::DeflateDecoder;
+use std::io::Read;
+
+use crate::{
+    block, dictionary, private, vr, BulkDataPolicy, BulkDataRef, Control, DCKVError, FileMeta,
+    Filter, KeyBlocks, PrivateDictionary, Result, TransferSyntax,
+};
+
+/// Settings of a parse, shared by every nesting level.
+pub(crate) struct Options<'f> {
+    pub(crate) filter: &'f Filter,
+    /// `None` when values cannot be referenced in the source, e.g. when it
+    /// is deflated.
+    pub(crate) bulk_data: Option<BulkDataPolicy>,
+    pub(crate) max_depth: usize,
+    /// VRs of the private elements in implicit VR.
+    pub(crate) private: PrivateDictionary,
+}
+
+impl Options<'_> {
+    /// Whether a value of `length` bytes and VR `vr` is appended as a
+    /// reference into the source.
+    #[inline]
+    pub(crate) fn defers(&self, length: usize, vr: u16) -> bool {
+        self.bulk_data
+            .as_ref()
+            .is_some_and(|policy| policy.defers(length, vr))
+    }
+}
+
+/// What the driver of a [`Machine`] does before resuming it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Step {
+    /// Reads the next bytes, fewer at the end of the source, and resumes
+    /// with them.
+    Read(usize),
+    /// Same as `Read`, leaving the source where it was.
+    Peek(usize),
+    /// Skips the next bytes.
+    Skip(u64),
+    /// Appends the key with a value of `length` bytes, whose binary numbers
+    /// of `swap_width` bytes are swapped, and resumes with its control. A
+    /// skipped value is consumed by the driver.
+    Value {
+        vr: u16,
+        length: usize,
+        swap_width: Option<usize>,
+    },
+    /// Skips a value deferred by the bulk data policy, and appends the key
+    /// with a reference to it.
+    BulkData { vr: u16, bulk_data: BulkDataRef },
+    /// Appends the key of a sequence or item, which has no value.
+    Marker { vr: Option<u16> },
+    /// Inflates the rest of the source, which is read from its start on.
+    Inflate,
+    /// Ends the parse.
+    End,
+}
+
+/// What a [`Machine`] is resumed with.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Input<'b> {
+    /// Nothing, after a skip or an inflation.
+    None,
+    /// Bytes read or peeked.
+    Bytes(&'b [u8]),
+    /// Control returned by an append.
+    Control(Control),
+}
+
+impl<'b> Input<'b> {
+    #[inline]
+    fn bytes(self) -> &'b [u8] {
+        match self {
+            Self::Bytes(bytes) => bytes,
+            _ => &[],
+        }
+    }
+
+    #[inline]
+    fn control(self) -> Control {
+        match self {
+            Self::Control(control) => control,
+            _ => Control::Continue,
+        }
+    }
+}
+
+/// Nesting level being parsed.
+#[derive(Debug, Clone, Copy)]
+enum Frame {
+    /// Group 0002 elements of the file meta information, always explicit VR
+    /// little endian, up to `end` given by (0002,0000) File Meta Information
+    /// Group Length, or else up to another group.
+    Meta { end: u64 },
+    /// Elements of the dataset or of an item, up to `end`.
+    Dataset { end: u64, syntax: TransferSyntax },
+    /// Items of the sequence `tag`, up to `end`.
+    Items {
+        tag: u32,
+        end: u64,
+        syntax: TransferSyntax,
+        number: u64,
+    },
+    /// Fragments of the encapsulated value `tag`, up to its sequence
+    /// delimitation item.
+    Fragments {
+        tag: u32,
+        vr: u16,
+        syntax: TransferSyntax,
+        number: u64,
+    },
+    /// Items of a skipped value of undefined length, up to its sequence
+    /// delimitation item.
+    SkipItems { syntax: TransferSyntax },
+    /// Elements of a skipped item of undefined length, up to its item
+    /// delimitation item.
+    SkipElements { syntax: TransferSyntax },
+}
+
+/// Element header, with the encoding of its items if it is a sequence.
+#[derive(Debug, Clone, Copy)]
+struct Element {
+    tag: u32,
+    vr: u16,
+    vl: u32,
+    items: TransferSyntax,
+}
+
+/// What the machine waits for to go on.
+#[derive(Debug, Clone, Copy)]
+enum Pending {
+    /// Nothing, the next step depends on the current frame.
+    Nothing,
+    /// Nothing, the source being untouched.
+    Begin,
+    /// First bytes of the source, for a preamble, a "DICM" prefix or file
+    /// meta elements.
+    Start,
+    /// First bytes of a dataset without transfer syntax, to guess it.
+    Detect,
+    /// Inflation of the rest of the source.
+    Inflate,
+    /// Tag of the next file meta element, peeked.
+    MetaTag,
+    /// File meta element `tag`, its tag being skipped.
+    MetaHeader(u32),
+    /// Tag of the next element.
+    Tag,
+    /// VR and length of the element `tag`, or its length in implicit VR.
+    Header(u32),
+    /// Length of the element `tag` of a long VR, after 2 reserved bytes.
+    LongLength(u32, u16),
+    /// First bytes of the implicit VR element `tag` of length `vl`,
+    /// missing from the dictionary, for an item tag.
+    Unknown(u32, u32),
+    /// Value of an element, peeked.
+    Peeked(Element),
+    /// Item tag and length.
+    Item,
+    /// Control of a sequence start.
+    SequenceStart(Element),
+    /// Control of an item start, the item having `length` and ending at
+    /// `end`.
+    ItemStart { length: u32, end: u64 },
+    /// Control of an item end.
+    ItemEnd,
+    /// Control of any other append.
+    Appended,
+    /// Item delimitation item, skipped.
+    DatasetEnd,
+    /// Nothing, the parse having ended.
+    Ended,
+}
+
+/// State of a parse: its nesting levels, the key being built and the file
+/// meta information read so far.
+pub(crate) struct Machine<'f> {
+    options: Options<'f>,
+    /// Only the file meta information is parsed.
+    meta_only: bool,
+    meta: Option<FileMeta>,
+    position: u64,
+    frames: Vec<Frame>,
+    pending: Pending,
+    key_depth: usize,
+    key_blocks: KeyBlocks,
+}
+
+impl<'f> Machine<'f> {
+    /// Parse of a source from `position`, where a DICOM file or a bare
+    /// dataset starts.
+    #[inline]
+    pub(crate) fn new(options: Options<'f>, position: u64) -> Self {
+        Self {
+            options,
+            meta_only: false,
+            meta: None,
+            position,
+            frames: vec![],
+            pending: Pending::Begin,
+            key_depth: 0,
+            key_blocks: KeyBlocks::new(),
+        }
+    }
+
+    /// Ends the parse before the dataset, after the file meta information.
+    #[inline]
+    pub(crate) fn meta_only(mut self) -> Self {
+        self.meta_only = true;
+        self
+    }
+
+    /// Blocks of the key to append.
+    #[inline]
+    pub(crate) fn key_blocks(&self) -> &KeyBlocks {
+        &self.key_blocks
+    }
+
+    /// File meta information, if the source has any.
+    #[inline]
+    pub(crate) fn into_meta(self) -> Option<FileMeta> {
+        self.meta
+    }
+
+    /// Next step, given the outcome of the last one.
+    pub(crate) fn resume(&mut self, input: Input<'_>) -> Result<Step> {
+        match std::mem::replace(&mut self.pending, Pending::Nothing) {
+            Pending::Nothing => self.next(),
+            Pending::Begin => Ok(self.peek(132, Pending::Start)),
+            Pending::Start => self.start(input.bytes()),
+            Pending::Detect => self.dataset(detect_syntax(input.bytes())),
+            Pending::Inflate => {
+                // Offsets into the inflated stream could not be fetched from
+                // the source.
+                self.options.bulk_data = None;
+                self.position = 0;
+                self.frames.push(Frame::Dataset {
+                    end: u64::MAX,
+                    syntax: TransferSyntax::ExplicitVRLittleEndian,
+                });
+                self.next()
+            }
+            Pending::MetaTag => self.meta_tag(input.bytes()),
+            Pending::MetaHeader(tag) => Ok(self.read(4, Pending::Header(tag))),
+            Pending::Tag => self.tag(input.bytes()),
+            Pending::Header(tag) => self.header(tag, input.bytes()),
+            Pending::LongLength(tag, vr) => {
+                let vl = decode_u32(&self.consume::<4>(input.bytes())?, self.syntax());
+                self.element(tag, vr, vl)
+            }
+            Pending::Unknown(tag, vl) => {
+                let vr = match input.bytes() {
+                    [0xFE, 0xFF, 0x00, 0xE0] => vr::SQ,
+                    _ => vr::UN,
+                };
+                self.element(tag, vr, vl)
+            }
+            Pending::Peeked(element) => self.peeked(element, input.bytes()),
+            Pending::Item => self.item(input.bytes()),
+            Pending::SequenceStart(element) => self.sequence_start(element, input.control()),
+            Pending::ItemStart { length, end } => self.item_start(length, end, input.control()),
+            Pending::ItemEnd => match input.control() {
+                Control::Stop => self.stop(),
+                _ => {
+                    self.leave_item();
+                    self.next()
+                }
+            },
+            Pending::Appended => match input.control() {
+                Control::Stop => self.stop(),
+                _ => self.next(),
+            },
+            Pending::DatasetEnd => self.end_dataset(),
+            Pending::Ended => self.stop(),
+        }
+    }
+
+    #[inline]
+    fn read(&mut self, length: usize, pending: Pending) -> Step {
+        self.pending = pending;
+        Step::Read(length)
+    }
+
+    #[inline]
+    fn peek(&mut self, length: usize, pending: Pending) -> Step {
+        self.pending = pending;
+        Step::Peek(length)
+    }
+
+    #[inline]
+    fn skip(&mut self, length: u64, pending: Pending) -> Step {
+        self.position += length;
+        self.pending = pending;
+        Step::Skip(length)
+    }
+
+    #[inline]
+    fn stop(&mut self) -> Result<Step> {
+        self.pending = Pending::Ended;
+        Ok(Step::End)
+    }
+
+    /// Takes read bytes, failing if the source ended before them.
+    #[inline]
+    fn consume<const N: usize>(&mut self, bytes: &[u8]) -> Result<[u8; N]> {
+        self.position += bytes.len() as u64;
+        bytes.try_into().map_err(|_| eof())
+    }
+
+    /// Encoding of the current frame.
+    #[inline]
+    fn syntax(&self) -> TransferSyntax {
+        match self.frames.last() {
+            Some(
+                Frame::Dataset { syntax, .. }
+                | Frame::Items { syntax, .. }
+                | Frame::Fragments { syntax, .. }
+                | Frame::SkipItems { syntax }
+                | Frame::SkipElements { syntax },
+            ) => *syntax,
+            Some(Frame::Meta { .. }) | None => TransferSyntax::ExplicitVRLittleEndian,
+        }
+    }
+
+    /// End of the current dataset.
+    #[inline]
+    fn end(&self) -> u64 {
+        match self.frames.last() {
+            Some(Frame::Dataset { end, .. }) => *end,
+            _ => u64::MAX,
+        }
+    }
+
+    /// Bytes left in the current dataset.
+    #[inline]
+    fn remaining(&self) -> u64 {
+        self.end().saturating_sub(self.position)
+    }
+
+    fn next(&mut self) -> Result<Step> {
+        match self.frames.last().copied() {
+            Some(Frame::Meta { end }) if self.position >= end => self.end_meta(),
+            Some(Frame::Meta { .. }) => Ok(self.peek(4, Pending::MetaTag)),
+            Some(Frame::Dataset { end, .. }) if self.position >= end => self.end_dataset(),
+            Some(Frame::Items { tag, end, .. }) if self.position >= end => self.end_sequence(tag),
+            Some(Frame::Dataset { .. } | Frame::SkipElements { .. }) => {
+                Ok(self.read(4, Pending::Tag))
+            }
+            Some(Frame::Items { .. } | Frame::Fragments { .. } | Frame::SkipItems { .. }) => {
+                Ok(self.read(8, Pending::Item))
+            }
+            None => self.stop(),
+        }
+    }
+
+    fn start(&mut self, bytes: &[u8]) -> Result<Step> {
+        let prefix = match bytes {
+            // 128 bytes preamble followed by "DICM".
+            _ if bytes.get(128..132) == Some(&b"DICM"[..]) => Some(132),
+            // "DICM" prefix without preamble.
+            _ if bytes.starts_with(b"DICM") => Some(4),
+            // Group 0002 elements without preamble nor prefix.
+            _ if is_meta_header(bytes) => Some(0),
+            _ => None,
+        };
+
+        match prefix {
+            Some(prefix) => {
+                self.meta = Some(FileMeta::default());
+                self.frames.push(Frame::Meta { end: u64::MAX });
+                Ok(self.skip(prefix, Pending::Nothing))
+            }
+            None if self.meta_only => self.stop(),
+            None => self.dataset(detect_syntax(bytes)),
+        }
+    }
+
+    fn meta_tag(&mut self, bytes: &[u8]) -> Result<Step> {
+        match bytes {
+            &[0x02, 0x00, first, second] => {
+                let tag = 0x00020000 + u16::from_le_bytes([first, second]) as u32;
+                Ok(self.skip(4, Pending::MetaHeader(tag)))
+            }
+            _ => self.end_meta(),
+        }
+    }
+
+    fn end_meta(&mut self) -> Result<Step> {
+        self.frames.pop();
+
+        if self.meta_only {
+            return self.stop();
+        }
+
+        let syntax = match &self.meta {
+            Some(meta) => meta.transfer_syntax()?,
+            None => None,
+        };
+        match syntax {
+            Some(syntax) => self.dataset(syntax),
+            None => Ok(self.peek(6, Pending::Detect)),
+        }
+    }
+
+    /// Starts the dataset, encoded in `syntax`.
+    fn dataset(&mut self, syntax: TransferSyntax) -> Result<Step> {
+        if syntax.is_deflated() {
+            self.pending = Pending::Inflate;
+            return Ok(Step::Inflate);
+        }
+
+        self.frames.push(Frame::Dataset {
+            end: u64::MAX,
+            syntax,
+        });
+        self.next()
+    }
+
+    fn tag(&mut self, bytes: &[u8]) -> Result<Step> {
+        let skipping = self.skipping();
+
+        // End parsing if the EOF is reached, which only the top-level
+        // dataset may end with.
+        if bytes.len() < 4 && !skipping {
+            return match self.frames.len() {
+                0 | 1 => self.end_dataset(),
+                _ => Err(DCKVError::TruncatedSQ),
+            };
+        }
+
+        let tag = decode_tag(&self.consume::<4>(bytes)?, self.syntax());
+
+        match tag {
+            // skip item length (4 bytes).
+            0xFFFEE00D if skipping => {
+                self.frames.pop();
+                Ok(self.skip(4, Pending::Nothing))
+            }
+            // End parsing if found SQ item delimitation tag.
+            0xFFFEE00D => Ok(self.skip(4, Pending::DatasetEnd)),
+            // Items and sequence delimitation items are only valid within
+            // a sequence.
+            0xFFFEE000 | 0xFFFEE0DD => Err(DCKVError::InvalidSQTag),
+            _ => Ok(self.read(4, Pending::Header(tag))),
+        }
+    }
+
+    /// Reads the VR and VL of the element `tag`. Implicit VR elements take
+    /// their VR from the data dictionary and always have a 4 bytes length.
+    fn header(&mut self, tag: u32, bytes: &[u8]) -> Result<Step> {
+        let header = self.consume::<4>(bytes)?;
+        let syntax = self.syntax();
+
+        if !syntax.is_explicit_vr() {
+            let vl = u32::from_le_bytes(header);
+            // Only sequences may have an undefined length (PS3.5 7.5.1).
+            let vr = match vl {
+                u32::MAX => vr::SQ,
+                _ => dictionary::vr(tag, self.creator(tag), &self.options.private),
+            };
+            // Elements missing from the dictionary may be sequences, which
+            // start with an item.
+            if vr == vr::UN && vl >= 8 && !self.skipping() {
+                return Ok(self.peek(4, Pending::Unknown(tag, vl)));
+            }
+            return self.element(tag, vr, vl);
+        }
+
+        let vr = u16::from_be_bytes([header[0], header[1]]);
+        match vr::is_long(vr) {
+            // skip reserved bytes.
+            true => Ok(self.read(4, Pending::LongLength(tag, vr))),
+            false => self.element(tag, vr, decode_u16(&header[2..], syntax) as u32),
+        }
+    }
+
+    #[inline]
+    fn skipping(&self) -> bool {
+        matches!(self.frames.last(), Some(Frame::SkipElements { .. }))
+    }
+
+    /// Creator of the private element `tag`, unless skipping.
+    #[inline]
+    fn creator(&self, tag: u32) -> Option<&str> {
+        match self.skipping() {
+            true => None,
+            false => self.key_blocks.creator(tag),
+        }
+    }
+
+    fn element(&mut self, tag: u32, vr: u16, vl: u32) -> Result<Step> {
+        let (vr, items) = sequence_vr(vr, vl, self.syntax());
+        let element = Element { tag, vr, vl, items };
+
+        match self.frames.last() {
+            Some(Frame::SkipElements { .. }) => self.skip_value(element),
+            // File meta elements are read ahead to fill the file meta
+            // information.
+            Some(Frame::Meta { .. }) if vr::is_value(vr) && vl != u32::MAX => {
+                Ok(self.peek(vl as usize, Pending::Peeked(element)))
+            }
+            // Private creators are read ahead, as their reservation holds
+            // even when they are filtered out. Values out of their LO
+            // length or of their dataset reserve nothing.
+            _ if private::is_creator(tag)
+                && vr::is_value(vr)
+                && vl as u64 <= private::MAX_CREATOR_LENGTH.min(self.remaining()) =>
+            {
+                Ok(self.peek(vl as usize, Pending::Peeked(element)))
+            }
+            _ => self.dispatch(element),
+        }
+    }
+
+    fn peeked(&mut self, element: Element, value: &[u8]) -> Result<Step> {
+        if value.len() < element.vl as usize {
+            return Err(eof());
+        }
+
+        match self.frames.last_mut() {
+            Some(Frame::Meta { end }) => {
+                if element.tag == 0x00020000 && value.len() >= 4 {
+                    let length = decode_u32(value, TransferSyntax::ExplicitVRLittleEndian);
+                    *end = self.position + element.vl as u64 + length as u64;
+                }
+                if let Some(meta) = &mut self.meta {
+                    meta.set(element.tag, value);
+                }
+            }
+            _ => self.key_blocks.reserve(element.tag, value),
+        }
+
+        self.dispatch(element)
+    }
+
+    /// Skips a value, or the items of a value of undefined length.
+    fn skip_value(&mut self, element: Element) -> Result<Step> {
+        match element.vl {
+            u32::MAX => {
+                self.frames.push(Frame::SkipItems {
+                    syntax: element.items,
+                });
+                self.next()
+            }
+            vl => Ok(self.skip(vl as u64, Pending::Nothing)),
+        }
+    }
+
+    fn dispatch(&mut self, element: Element) -> Result<Step> {
+        let Element { tag, vr, vl, .. } = element;
+        let syntax = self.syntax();
+
+        // Only sequences and encapsulated values may have an undefined
+        // length (PS3.5 7.1.1), undefined length UN being sequences.
+        if vl == u32::MAX && vr::is_value(vr) && !matches!(vr, vr::OB | vr::OW) {
+            let vr_text = String::from_utf8_lossy(&vr.to_be_bytes()).into_owned();
+            return Err(DCKVError::UndefinedValueLength(vr_text));
+        }
+
+        // Skip filtered out values and sequences without reading them.
+        let parents = &self.key_blocks[..self.key_depth];
+        let creator = self.key_blocks.creator(tag);
+        if self
+            .options
+            .filter
+            .skips(parents, tag, creator, vr == vr::SQ)
+        {
+            return self.skip_value(element);
+        }
+
+        match vr {
+            // Encapsulated pixel data. The basic offset table and each
+            // fragment come in their own item (PS3.5 A.4), and are keyed
+            // as the element with the fragment number in the last 2 bytes,
+            // the basic offset table being fragment 0.
+            vr::OB | vr::OW if vl == u32::MAX => {
+                self.frames.push(Frame::Fragments {
+                    tag,
+                    vr,
+                    syntax,
+                    number: 0,
+                });
+                self.next()
+            }
+            // Value VR.
+            vr if vr::is_value(vr) => {
+                self.key_blocks[self.key_depth] = block::element(tag, vr);
+                Ok(self.value(vr, vl as usize, swap_width(vr, syntax)))
+            }
+            // Sequence
+            vr::SQ => {
+                self.key_blocks[self.key_depth] = block::sequence(tag);
+                self.pending = Pending::SequenceStart(element);
+                Ok(Step::Marker { vr: Some(vr) })
+            }
+            _ => Err(unsupported_vr(vr)),
+        }
+    }
+
+    /// Appends a value, or a reference to it if the bulk data policy defers
+    /// it.
+    fn value(&mut self, vr: u16, length: usize, swap_width: Option<usize>) -> Step {
+        let offset = self.position;
+        self.position += length as u64;
+        self.pending = Pending::Appended;
+
+        match self.options.defers(length, vr) {
+            true => Step::BulkData {
+                vr,
+                bulk_data: BulkDataRef::new(offset, length, swap_width),
+            },
+            false => Step::Value {
+                vr,
+                length,
+                swap_width,
+            },
+        }
+    }
+
+    fn sequence_start(&mut self, element: Element, control: Control) -> Result<Step> {
+        match control {
+            Control::Continue => {
+                let end = sequence_end(self.position, element.vl, self.end())?;
+                self.frames.push(Frame::Items {
+                    tag: element.tag,
+                    end,
+                    syntax: element.items,
+                    number: 1,
+                });
+                self.next()
+            }
+            Control::Skip => self.skip_value(element),
+            Control::Stop => self.stop(),
+        }
+    }
+
+    /// Reads an item tag and length.
+    fn item(&mut self, bytes: &[u8]) -> Result<Step> {
+        let syntax = self.syntax();
+        let frame = self.frames.last().copied();
+
+        // The source ended within the sequence.
+        if matches!(frame, Some(Frame::Items { .. })) && bytes.len() < 8 {
+            return Err(DCKVError::TruncatedSQ);
+        }
+
+        let header = self.consume::<8>(bytes)?;
+        let item_tag = decode_tag(&header[..4], syntax);
+        let item_length = decode_u32(&header[4..], syntax);
+
+        match (frame, item_tag) {
+            (
+                Some(Frame::Items {
+                    tag, end, number, ..
+                }),
+                0xFFFEE000,
+            ) => {
+                let item_end = item_end(self.position, item_length, end)?;
+
+                self.key_blocks[self.key_depth] = block::item(tag, number);
+
+                self.key_depth += 1;
+                if self.key_depth > self.options.max_depth {
+                    return Err(DCKVError::TooManyNestedLevels(self.options.max_depth));
+                }
+                self.key_blocks.push(block::ITEM_START);
+
+                self.pending = Pending::ItemStart {
+                    length: item_length,
+                    end: item_end,
+                };
+                Ok(Step::Marker { vr: None })
+            }
+            (Some(Frame::Items { tag, .. }), 0xFFFEE0DD) => self.end_sequence(tag),
+            (
+                Some(Frame::Fragments {
+                    tag, vr, number, ..
+                }),
+                0xFFFEE000,
+            ) => {
+                if number > 0xFFFF {
+                    return Err(DCKVError::TooManyFragments);
+                }
+
+                self.key_blocks[self.key_depth] = block::fragment(tag, vr, number);
+                if let Some(Frame::Fragments { number, .. }) = self.frames.last_mut() {
+                    *number += 1;
+                }
+
+                // Fragments hold a byte stream, never swapped.
+                Ok(self.value(vr, item_length as usize, None))
+            }
+            (Some(Frame::Fragments { .. }), 0xFFFEE0DD) => {
+                self.frames.pop();
+                self.next()
+            }
+            (Some(Frame::Fragments { .. }), _) => Err(DCKVError::InvalidFragmentTag),
+            (Some(Frame::SkipItems { syntax }), 0xFFFEE000) => match item_length {
+                u32::MAX => {
+                    self.frames.push(Frame::SkipElements { syntax });
+                    self.next()
+                }
+                _ => Ok(self.skip(item_length as u64, Pending::Nothing)),
+            },
+            (Some(Frame::SkipItems { .. }), 0xFFFEE0DD) => {
+                self.frames.pop();
+                self.next()
+            }
+            _ => Err(DCKVError::InvalidSQTag),
+        }
+    }
+
+    fn item_start(&mut self, length: u32, end: u64, control: Control) -> Result<Step> {
+        let syntax = self.syntax();
+
+        match control {
+            Control::Continue => {
+                self.frames.push(Frame::Dataset { end, syntax });
+                self.next()
+            }
+            Control::Skip => {
+                self.leave_item();
+                match length {
+                    u32::MAX => {
+                        self.frames.push(Frame::SkipElements { syntax });
+                        self.next()
+                    }
+                    _ => Ok(self.skip(length as u64, Pending::Nothing)),
+                }
+            }
+            Control::Stop => self.stop(),
+        }
+    }
+
+    /// Ends the dataset of the current frame, appending its item end if it
+    /// is an item.
+    fn end_dataset(&mut self) -> Result<Step> {
+        self.frames.pop();
+
+        match self.frames.last() {
+            Some(Frame::Items { .. }) => {
+                self.key_blocks[self.key_depth] = block::ITEM_END;
+                self.pending = Pending::ItemEnd;
+                Ok(Step::Marker { vr: None })
+            }
+            _ => self.stop(),
+        }
+    }
+
+    /// Leaves the item of the current sequence, before the next one.
+    fn leave_item(&mut self) {
+        self.key_blocks.leave_item();
+        self.key_depth -= 1;
+
+        if let Some(Frame::Items { number, .. }) = self.frames.last_mut() {
+            *number += 1;
+        }
+    }
+
+    fn end_sequence(&mut self, tag: u32) -> Result<Step> {
+        self.frames.pop();
+
+        self.key_blocks[self.key_depth] = block::sequence_end(tag);
+        self.pending = Pending::Appended;
+        Ok(Step::Marker { vr: None })
+    }
+}
+
+#[inline]
+fn eof() -> DCKVError {
+    std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into()
+}
+
+#[inline]
+fn decode_u16(bytes: &[u8], syntax: TransferSyntax) -> u16 {
+    let bytes = [bytes[0], bytes[1]];
+    match syntax.is_big_endian() {
+        true => u16::from_be_bytes(bytes),
+        false => u16::from_le_bytes(bytes),
+    }
+}
+
+#[inline]
+fn decode_u32(bytes: &[u8], syntax: TransferSyntax) -> u32 {
+    let bytes = [bytes[0], bytes[1], bytes[2], bytes[3]];
+    match syntax.is_big_endian() {
+        true => u32::from_be_bytes(bytes),
+        false => u32::from_le_bytes(bytes),
+    }
+}
+
+/// Decodes a tag as group and element.
+#[inline]
+fn decode_tag(bytes: &[u8], syntax: TransferSyntax) -> u32 {
+    let group = decode_u16(bytes, syntax) as u32;
+    let element = decode_u16(&bytes[2..], syntax) as u32;
+    element + (group << 16)
+}
+
+/// Whether the first bytes of a source are a group 0002 element, in
+/// explicit VR little endian.
+#[inline]
+fn is_meta_header(header: &[u8]) -> bool {
+    header.len() >= 6 && header[..2] == [0x02, 0x00] && is_explicit_vr_header(header)
+}
+
+/// Whether the first bytes of a dataset are explicit VR, which carry two
+/// uppercase letters right after the first tag.
+#[inline]
+fn is_explicit_vr_header(header: &[u8]) -> bool {
+    header.len() >= 6 && header[4].is_ascii_uppercase() && header[5].is_ascii_uppercase()
+}
+
+/// Guesses the encoding of a dataset from its first bytes.
+#[inline]
+fn detect_syntax(header: &[u8]) -> TransferSyntax {
+    match header.len() < 6 || is_explicit_vr_header(header) {
+        true => TransferSyntax::ExplicitVRLittleEndian,
+        false => TransferSyntax::ImplicitVRLittleEndian,
+    }
+}
+
+/// VR of an element, and encoding of its items if it is a sequence. UN
+/// values with undefined length are sequences encoded in implicit VR
+/// little endian (PS3.5 6.2.2).
+#[inline]
+fn sequence_vr(vr: u16, vl: u32, syntax: TransferSyntax) -> (u16, TransferSyntax) {
+    match (vr, vl) {
+        (vr::UN, u32::MAX) => (vr::SQ, TransferSyntax::ImplicitVRLittleEndian),
+        _ => (vr, syntax),
+    }
+}
+
+/// Size of the binary numbers to swap in a value, so that deserializers
+/// never see a big endian source.
+#[inline]
+fn swap_width(vr: u16, syntax: TransferSyntax) -> Option<usize> {
+    match syntax.is_big_endian() {
+        true => vr::swap_width(vr),
+        false => None,
+    }
+}
+
+#[inline]
+pub(crate) fn swap(bytes: &mut [u8], width: usize) {
+    bytes
+        .chunks_exact_mut(width)
+        .for_each(|number| number.reverse());
+}
+
+/// End of a sequence whose value starts at `position`, within a parent
+/// ending at `length`. A sequence of undefined length ends with a sequence
+/// delimitation item, or with the item or dataset containing it if the
+/// delimiter is missing.
+#[inline]
+fn sequence_end(position: u64, vl: u32, length: u64) -> Result<u64> {
+    match vl {
+        u32::MAX => Ok(length),
+        _ if position + vl as u64 > length => Err(DCKVError::InvalidSQLength),
+        _ => Ok(position + vl as u64),
+    }
+}
+
+/// End of an item whose value starts at `position`, within a sequence
+/// ending at `seq_offset`. An item of undefined length ends with an item
+/// delimitation item, or with its sequence.
+#[inline]
+fn item_end(position: u64, item_length: u32, seq_offset: u64) -> Result<u64> {
+    match item_length {
+        u32::MAX => Ok(seq_offset),
+        _ if position + item_length as u64 > seq_offset => Err(DCKVError::InvalidSQItemLength),
+        _ => Ok(position + item_length as u64),
+    }
+}
+
+#[inline]
+fn unsupported_vr(vr: u16) -> DCKVError {
+    match String::from_utf8(vr.to_be_bytes().to_vec()) {
+        Ok(vr_text) => DCKVError::UnsupportedVR(vr_text),
+        Err(_) => DCKVError::InvalidVR,
+    }
+}
+
+/// Inflates a deflated explicit VR little endian dataset (PS3.5 A.5), so
+/// that it can be parsed as explicit VR little endian.
+pub(crate) fn inflate_bytes(deflated: &[u8]) -> Result<Vec<u8>> {
+    let mut inflated = vec![];
+    DeflateDecoder::new(deflated).read_to_end(&mut inflated)?;
+
+    Ok(inflated)
+}
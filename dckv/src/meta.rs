@@ -0,0 +1,306 @@
+use async_trait::async_trait;
+use std::io::{Read, Seek};
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+use crate::machine::{Machine, Options};
+use crate::{
+    parser, sync_parser, Control, DCKVError, Deserializer, Filter, Key, PrivateDictionary, Result,
+    SyncDeserializer, MAX_DEPTH,
+};
+
+/// Encoding of the data elements of a dataset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferSyntax {
+    ImplicitVRLittleEndian,
+    ExplicitVRLittleEndian,
+    DeflatedExplicitVRLittleEndian,
+    ExplicitVRBigEndian,
+}
+
+/// Transfer syntaxes whose pixel data is encapsulated, or sent apart, the
+/// dataset being encoded in explicit VR little endian (PS3.5 A.4, PS3.6 A).
+const ENCAPSULATED: &[&str] = &[
+    // Encapsulated Uncompressed.
+    "1.2.840.10008.1.2.1.98",
+    // JPEG, including the retired processes.
+    "1.2.840.10008.1.2.4.50",
+    "1.2.840.10008.1.2.4.51",
+    "1.2.840.10008.1.2.4.52",
+    "1.2.840.10008.1.2.4.53",
+    "1.2.840.10008.1.2.4.54",
+    "1.2.840.10008.1.2.4.55",
+    "1.2.840.10008.1.2.4.56",
+    "1.2.840.10008.1.2.4.57",
+    "1.2.840.10008.1.2.4.58",
+    "1.2.840.10008.1.2.4.59",
+    "1.2.840.10008.1.2.4.60",
+    "1.2.840.10008.1.2.4.61",
+    "1.2.840.10008.1.2.4.62",
+    "1.2.840.10008.1.2.4.63",
+    "1.2.840.10008.1.2.4.64",
+    "1.2.840.10008.1.2.4.65",
+    "1.2.840.10008.1.2.4.66",
+    "1.2.840.10008.1.2.4.70",
+    // JPEG-LS.
+    "1.2.840.10008.1.2.4.80",
+    "1.2.840.10008.1.2.4.81",
+    // JPEG 2000.
+    "1.2.840.10008.1.2.4.90",
+    "1.2.840.10008.1.2.4.91",
+    "1.2.840.10008.1.2.4.92",
+    "1.2.840.10008.1.2.4.93",
+    // JPIP Referenced.
+    "1.2.840.10008.1.2.4.94",
+    // MPEG-2, MPEG-4 and HEVC.
+    "1.2.840.10008.1.2.4.100",
+    "1.2.840.10008.1.2.4.100.1",
+    "1.2.840.10008.1.2.4.101",
+    "1.2.840.10008.1.2.4.101.1",
+    "1.2.840.10008.1.2.4.102",
+    "1.2.840.10008.1.2.4.102.1",
+    "1.2.840.10008.1.2.4.103",
+    "1.2.840.10008.1.2.4.103.1",
+    "1.2.840.10008.1.2.4.104",
+    "1.2.840.10008.1.2.4.104.1",
+    "1.2.840.10008.1.2.4.105",
+    "1.2.840.10008.1.2.4.105.1",
+    "1.2.840.10008.1.2.4.106",
+    "1.2.840.10008.1.2.4.106.1",
+    "1.2.840.10008.1.2.4.107",
+    "1.2.840.10008.1.2.4.108",
+    // JPEG XL.
+    "1.2.840.10008.1.2.4.110",
+    "1.2.840.10008.1.2.4.111",
+    "1.2.840.10008.1.2.4.112",
+    // High-Throughput JPEG 2000, and its JPIP Referenced.
+    "1.2.840.10008.1.2.4.201",
+    "1.2.840.10008.1.2.4.202",
+    "1.2.840.10008.1.2.4.203",
+    "1.2.840.10008.1.2.4.204",
+    // RLE Lossless.
+    "1.2.840.10008.1.2.5",
+    // SMPTE ST 2110.
+    "1.2.840.10008.1.2.7.1",
+    "1.2.840.10008.1.2.7.2",
+    "1.2.840.10008.1.2.7.3",
+    // Deflated Image Frame Compression.
+    "1.2.840.10008.1.2.8.1",
+];
+
+impl TransferSyntax {
+    /// Maps a transfer syntax UID to the encoding of its dataset, the
+    /// encapsulated transfer syntaxes being encoded in explicit VR little
+    /// endian (PS3.5 A.4). Fails on UIDs out of PS3.6 A, e.g. private ones,
+    /// whose encoding is unknown.
+    ///
+    /// ```
+    /// use dckv::TransferSyntax;
+    ///
+    /// assert_eq!(
+    ///     TransferSyntax::from_uid("1.2.840.10008.1.2.4.50").unwrap(),
+    ///     TransferSyntax::ExplicitVRLittleEndian
+    /// );
+    /// assert!(TransferSyntax::from_uid("1.2.3.4").is_err());
+    /// ```
+    #[inline]
+    pub fn from_uid(uid: &str) -> Result<Self> {
+        match uid {
+            "1.2.840.10008.1.2" => Ok(Self::ImplicitVRLittleEndian),
+            "1.2.840.10008.1.2.1" => Ok(Self::ExplicitVRLittleEndian),
+            // JPIP Referenced Deflate, and its High-Throughput JPEG 2000
+            // version, deflate their dataset as well.
+            "1.2.840.10008.1.2.1.99" | "1.2.840.10008.1.2.4.95" | "1.2.840.10008.1.2.4.205" => {
+                Ok(Self::DeflatedExplicitVRLittleEndian)
+            }
+            "1.2.840.10008.1.2.2" => Ok(Self::ExplicitVRBigEndian),
+            uid if ENCAPSULATED.contains(&uid) => Ok(Self::ExplicitVRLittleEndian),
+            uid => Err(DCKVError::UnsupportedTransferSyntax(uid.to_string())),
+        }
+    }
+
+    #[inline]
+    pub fn is_explicit_vr(&self) -> bool {
+        !matches!(self, Self::ImplicitVRLittleEndian)
+    }
+
+    #[inline]
+    pub fn is_big_endian(&self) -> bool {
+        matches!(self, Self::ExplicitVRBigEndian)
+    }
+
+    #[inline]
+    pub fn is_deflated(&self) -> bool {
+        matches!(self, Self::DeflatedExplicitVRLittleEndian)
+    }
+}
+
+/// File Meta Information group (0002,xxxx) of a DICOM file (PS3.10 7.1).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FileMeta {
+    media_storage_sop_class_uid: String,
+    media_storage_sop_instance_uid: String,
+    transfer_syntax_uid: String,
+    implementation_class_uid: String,
+    implementation_version_name: String,
+    source_application_entity_title: String,
+}
+
+impl FileMeta {
+    /// Reads the file meta information at the current position, skipping
+    /// the preamble and "DICM" prefix when present. Returns `None`, leaving
+    /// the reader untouched, if the stream is a bare dataset.
+    pub async fn read<R>(reader: &mut R) -> Result<Option<Self>>
+    where
+        R: AsyncReadExt + AsyncSeekExt + Unpin,
+    {
+        let filter = Filter::none();
+        let options = MetaOnly::options(&filter);
+        let mut machine = Machine::new(options, reader.stream_position().await?).meta_only();
+
+        parser::drive(&mut MetaOnly, reader, &mut machine).await?;
+
+        Ok(machine.into_meta())
+    }
+
+    /// Blocking [`FileMeta::read`].
+    pub fn read_sync<R>(reader: &mut R) -> Result<Option<Self>>
+    where
+        R: Read + Seek,
+    {
+        let filter = Filter::none();
+        let options = MetaOnly::options(&filter);
+        let mut machine = Machine::new(options, reader.stream_position()?).meta_only();
+
+        sync_parser::drive(&mut MetaOnly, reader, &mut machine)?;
+
+        Ok(machine.into_meta())
+    }
+
+    /// Records the value of the element `tag`, if kept.
+    #[inline]
+    pub(crate) fn set(&mut self, tag: u32, value: &[u8]) {
+        if let Some(field) = self.field_mut(tag as u16) {
+            *field = text(value);
+        }
+    }
+
+    /// Field of the element (0002,`element`), if kept.
+    #[inline]
+    fn field_mut(&mut self, element: u16) -> Option<&mut String> {
+        match element {
+            0x0002 => Some(&mut self.media_storage_sop_class_uid),
+            0x0003 => Some(&mut self.media_storage_sop_instance_uid),
+            0x0010 => Some(&mut self.transfer_syntax_uid),
+            0x0012 => Some(&mut self.implementation_class_uid),
+            0x0013 => Some(&mut self.implementation_version_name),
+            0x0016 => Some(&mut self.source_application_entity_title),
+            _ => None,
+        }
+    }
+
+    /// Encoding of the dataset following the file meta information, or
+    /// `None` if (0002,0010) is missing. Fails on unknown transfer syntaxes,
+    /// see [`TransferSyntax::from_uid`].
+    #[inline]
+    pub fn transfer_syntax(&self) -> Result<Option<TransferSyntax>> {
+        match self.transfer_syntax_uid.is_empty() {
+            true => Ok(None),
+            false => TransferSyntax::from_uid(&self.transfer_syntax_uid).map(Some),
+        }
+    }
+
+    /// (0002,0010) Transfer Syntax UID.
+    #[inline]
+    pub fn transfer_syntax_uid(&self) -> &str {
+        &self.transfer_syntax_uid
+    }
+
+    /// (0002,0002) Media Storage SOP Class UID.
+    #[inline]
+    pub fn media_storage_sop_class_uid(&self) -> &str {
+        &self.media_storage_sop_class_uid
+    }
+
+    /// (0002,0003) Media Storage SOP Instance UID.
+    #[inline]
+    pub fn media_storage_sop_instance_uid(&self) -> &str {
+        &self.media_storage_sop_instance_uid
+    }
+
+    /// (0002,0012) Implementation Class UID.
+    #[inline]
+    pub fn implementation_class_uid(&self) -> &str {
+        &self.implementation_class_uid
+    }
+
+    /// (0002,0013) Implementation Version Name.
+    #[inline]
+    pub fn implementation_version_name(&self) -> &str {
+        &self.implementation_version_name
+    }
+
+    /// (0002,0016) Source Application Entity Title.
+    #[inline]
+    pub fn source_application_entity_title(&self) -> &str {
+        &self.source_application_entity_title
+    }
+}
+
+/// Text of a meta element value, without padding. Invalid characters, e.g.
+/// of a non-conformant implementation version name, are replaced rather
+/// than failing the parse.
+#[inline]
+fn text(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes)
+        .trim_end_matches(['\0', ' '])
+        .trim_start()
+        .to_string()
+}
+
+/// Deserializer of the file meta information alone, whose elements are
+/// read ahead by the parser.
+#[derive(Clone)]
+struct MetaOnly;
+
+impl MetaOnly {
+    #[inline]
+    fn options(filter: &Filter) -> Options<'_> {
+        Options {
+            filter,
+            bulk_data: None,
+            max_depth: MAX_DEPTH,
+            private: PrivateDictionary::new(),
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl Deserializer for MetaOnly {
+    async fn append<R>(
+        &mut self,
+        _reader: &mut R,
+        _key: Key,
+        _length: usize,
+        _vr: Option<u16>,
+    ) -> Result<Control>
+    where
+        R: AsyncReadExt + AsyncSeekExt + Unpin,
+    {
+        Ok(Control::Skip)
+    }
+}
+
+impl SyncDeserializer for MetaOnly {
+    fn append<R>(
+        &mut self,
+        _reader: &mut R,
+        _key: Key,
+        _length: usize,
+        _vr: Option<u16>,
+    ) -> Result<Control>
+    where
+        R: Read + Seek,
+    {
+        Ok(Control::Skip)
+    }
+}
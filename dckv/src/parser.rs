@@ -1,193 +1,127 @@
-use async_recursion::async_recursion;
 use async_trait::async_trait;
-use std::io::SeekFrom;
+use std::io::{Cursor, SeekFrom};
 use tokio::io::{AsyncReadExt, AsyncSeekExt};
 
-use crate::{vr, DCKVError, Filter, Key, KeyBlocks, Result};
+use crate::machine::{inflate_bytes, swap, Input, Machine, Options, Step};
+use crate::{
+    BulkDataPolicy, BulkDataRef, FileMeta, Filter, Key, PrivateDictionary, Result, MAX_DEPTH,
+};
+
+/// What the parser does after a deserializer appended a key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    /// Goes on with the next key, the value having been read.
+    Continue,
+    /// Skips the value without it having been read. Skipping a sequence or
+    /// an item skips everything nested in it, including its end key.
+    Skip,
+    /// Ends the parsing, successfully.
+    Stop,
+}
+
+/// Reads up to `length` bytes, fewer at the end of the source.
+#[inline]
+async fn fill<R>(reader: &mut R, buffer: &mut Vec<u8>, length: usize) -> Result<()>
+where
+    R: AsyncReadExt + Unpin,
+{
+    buffer.clear();
+    (&mut *reader)
+        .take(length as u64)
+        .read_to_end(buffer)
+        .await?;
 
+    Ok(())
+}
+
+/// Appends a value, swapping its binary numbers of `swap_width` bytes so
+/// that deserializers never see a big endian source. Skipped values are
+/// consumed, so that only `Stop` is left to handle.
 #[inline]
-#[async_recursion(?Send)]
-async fn parser<'r, S, R>(
+async fn append_value<S, R>(
     shared: &mut S,
     reader: &mut R,
-    key_depth: &mut usize,
-    key_blocks: &mut KeyBlocks,
-    length: u64,
-    filter: &Filter,
-) -> Result<()>
+    key: Key,
+    length: usize,
+    vr: u16,
+    swap_width: Option<usize>,
+) -> Result<Control>
 where
     S: Clone + Deserializer,
     R: AsyncReadExt + AsyncSeekExt + Unpin,
 {
-    loop {
-        // End if length is bigger than current position.
-        if reader.stream_position().await? >= length {
-            break;
+    match swap_width {
+        Some(width) => {
+            let mut bytes = vec![0; length];
+            reader.read_exact(&mut bytes).await?;
+            swap(&mut bytes, width);
+
+            let mut swapped = Cursor::new(bytes);
+            shared.append(&mut swapped, key, length, Some(vr)).await
         }
-
-        // Read group and element. Use the first read to
-        // end parsing if the EOF is reached.
-        let group = match reader.read_u16_le().await {
-            Ok(t) => t as u32,
-            Err(err) => match err.kind() {
-                std::io::ErrorKind::UnexpectedEof => break,
-                _ => return Err(err.into()),
-            },
-        };
-        let element = reader.read_u16_le().await? as u32;
-        let tag = element + (group << 16);
-
-        // End parsing if found SQ item delimitation tag.
-        if tag == 0xFFFEE00D {
-            // skip item length (4 bytes).
-            reader.seek(SeekFrom::Current(4)).await?;
-            break;
+        None => {
+            let control = shared.append(reader, key, length, Some(vr)).await?;
+            if control == Control::Skip {
+                reader.seek(SeekFrom::Current(length as i64)).await?;
+            }
+            Ok(control)
         }
+    }
+}
 
-        // Read VR and VL.
-        let vr = reader.read_u16().await?;
-        let vl = reader.read_u16_le().await?;
-
-        match vr {
-            // Short length value VR.
-            vr::AE
-            | vr::AS
-            | vr::AT
-            | vr::CS
-            | vr::DA
-            | vr::DS
-            | vr::DT
-            | vr::FL
-            | vr::FD
-            | vr::IS
-            | vr::LO
-            | vr::LT
-            | vr::PN
-            | vr::SH
-            | vr::SL
-            | vr::SS
-            | vr::ST
-            | vr::TM
-            | vr::UI
-            | vr::UL
-            | vr::US => {
-                key_blocks[*key_depth] = ((tag as u64) << 32) + ((vr as u64) << 16);
-                let key = Key::from_key_blocks(key_blocks);
+/// Runs the steps of `machine` over `reader`, until the parse ends or the
+/// rest of the source has to be inflated, returning whether it has.
+pub(crate) async fn drive<S, R>(
+    shared: &mut S,
+    reader: &mut R,
+    machine: &mut Machine<'_>,
+) -> Result<bool>
+where
+    S: Clone + Deserializer,
+    R: AsyncReadExt + AsyncSeekExt + Unpin,
+{
+    let mut buffer = vec![];
+    let mut input = Input::None;
 
-                shared.append(reader, key, vl as usize, Some(vr)).await;
+    loop {
+        input = match machine.resume(input)? {
+            Step::Read(length) => {
+                fill(reader, &mut buffer, length).await?;
+                Input::Bytes(&buffer)
             }
-            // Long length value VR.
-            vr::UC
-            | vr::UT
-            | vr::UR
-            | vr::SV
-            | vr::UV
-            | vr::OB
-            | vr::OD
-            | vr::OF
-            | vr::OL
-            | vr::OV
-            | vr::OW
-            | vr::UN => {
-                key_blocks[*key_depth] = ((tag as u64) << 32) + ((vr as u64) << 16);
-                let key = Key::from_key_blocks(key_blocks);
-                let vll = reader.read_u32_le().await?;
-
-                shared.append(reader, key, vll as usize, Some(vr)).await;
+            Step::Peek(length) => {
+                let position = reader.stream_position().await?;
+                fill(reader, &mut buffer, length).await?;
+                reader.seek(SeekFrom::Start(position)).await?;
+                Input::Bytes(&buffer)
             }
-            // Sequence
-            vr::SQ => {
-                key_blocks[*key_depth] = (tag as u64) << 32;
-                let key = Key::from_key_blocks(key_blocks);
-
-                shared.append(reader, key, 0, Some(vr)).await;
-
-                let seq_length = reader.read_u32_le().await?;
-
-                let seq_offset = if seq_length == u32::MAX {
-                    u64::MAX
-                } else {
-                    reader.stream_position().await? + seq_length as u64
-                };
-
-                let mut item_number: u64 = 1;
-
-                loop {
-                    if reader.stream_position().await? <= seq_offset {
-                        // read item tag
-                        let group = reader.read_u16_le().await? as u32;
-                        let element = reader.read_u16_le().await? as u32;
-                        let item_tag = element + (group << 16);
-
-                        match item_tag {
-                            0xFFFEE000 => {
-                                key_blocks[*key_depth] = ((tag as u64) << 32) + item_number;
-
-                                *key_depth += 1;
-                                key_blocks[*key_depth] = 0x2b2b0000;
-
-                                let key = Key::from_key_blocks(key_blocks);
-                                shared.append(reader, key, 0, None).await;
-
-                                let item_length = reader.read_u32_le().await?;
-
-                                if item_length > seq_length {
-                                    return Err(DCKVError::InvalidSQItemLength);
-                                }
-
-                                let item_offset = if seq_length == u32::MAX {
-                                    u64::MAX
-                                } else {
-                                    reader.stream_position().await? + seq_length as u64
-                                };
-
-                                parser(shared, reader, key_depth, key_blocks, item_offset, filter)
-                                    .await?;
-
-                                key_blocks[*key_depth] = 0xFFFFFFFF5F5F0000;
-
-                                let key = Key::from_key_blocks(key_blocks);
-                                shared.append(reader, key, 0, None).await;
-
-                                key_blocks[*key_depth] = 0x0;
-                                *key_depth -= 1;
-
-                                item_number += 1;
-                            }
-                            0xFFFEE0DD => {
-                                key_blocks[*key_depth] &= 0xFFFFFFFF00000000;
-                                key_blocks[*key_depth] |= 0x00000000FFFF0000;
-
-                                let key = Key::from_key_blocks(key_blocks);
-                                shared.append(reader, key, 0, None).await;
-
-                                // skip item length (4 bytes).
-                                reader.seek(SeekFrom::Current(4)).await?;
-
-                                break;
-                            }
-                            _ => return Err(DCKVError::InvalidSQTag),
-                        }
-                    } else {
-                        key_blocks[*key_depth] &= 0xFFFFFFFF00000000;
-                        key_blocks[*key_depth] |= 0x00000000FFFF0000;
-
-                        let key = Key::from_key_blocks(key_blocks);
-                        shared.append(reader, key, 0, None).await;
-
-                        break;
-                    }
-                }
+            Step::Skip(length) => {
+                reader.seek(SeekFrom::Current(length as i64)).await?;
+                Input::None
             }
-            _ => {
-                let bytes = vr.to_le_bytes().to_vec();
-                let vr_text = String::from_utf8(bytes).map_err(|_| DCKVError::InvalidVR)?;
-                return Err(DCKVError::UnsupportedVR(vr_text));
+            Step::Value {
+                vr,
+                length,
+                swap_width,
+            } => {
+                let key = Key::from_key_blocks(machine.key_blocks());
+                Input::Control(append_value(shared, reader, key, length, vr, swap_width).await?)
             }
-        }
+            Step::BulkData { vr, bulk_data } => {
+                reader
+                    .seek(SeekFrom::Current(bulk_data.length() as i64))
+                    .await?;
+                let key = Key::from_key_blocks(machine.key_blocks());
+                Input::Control(shared.append_bulk_data(key, bulk_data, vr)?)
+            }
+            Step::Marker { vr } => {
+                let key = Key::from_key_blocks(machine.key_blocks());
+                Input::Control(shared.append(reader, key, 0, vr).await?)
+            }
+            Step::Inflate => return Ok(true),
+            Step::End => return Ok(false),
+        };
     }
-
-    Ok(())
 }
 
 #[async_trait(?Send)]
@@ -195,85 +129,76 @@ pub trait Deserializer
 where
     Self: Clone,
 {
+    /// Parses a DICOM file or a bare dataset, returning its file meta
+    /// information if present. The dataset is decoded according to the
+    /// transfer syntax (0002,0010), or guessed when there is none, until
+    /// its end or until an append returns [`Control::Stop`].
     #[inline]
-    async fn deserialize<R>(&mut self, mut reader: R, filter: &Filter) -> Result<()>
+    async fn deserialize<R>(&mut self, mut reader: R, filter: &Filter) -> Result<Option<FileMeta>>
     where
         R: AsyncReadExt + AsyncSeekExt + Unpin,
     {
-        let mut key_depth = 0;
-        let mut key_blocks = KeyBlocks::default();
-
-        // skip preamble
-        reader.seek(SeekFrom::Start(144)).await?;
-
-        parser(
-            self,
-            &mut reader,
-            &mut key_depth,
-            &mut key_blocks,
-            u64::MAX,
+        let options = Options {
             filter,
-        )
-        .await?;
+            bulk_data: self.bulk_data_policy().cloned(),
+            max_depth: self.max_depth(),
+            private: self.private_dictionary().cloned().unwrap_or_default(),
+        };
+        let mut machine = Machine::new(options, reader.stream_position().await?);
 
-        Ok(())
-    }
+        if drive(self, &mut reader, &mut machine).await? {
+            let mut deflated = vec![];
+            reader.read_to_end(&mut deflated).await?;
 
-    async fn append<R>(&mut self, reader: &mut R, key: Key, length: usize, vr: Option<u16>)
-    where
-        R: AsyncReadExt + AsyncSeekExt + Unpin;
-}
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Value {
-    bytes: Vec<u8>,
-}
+            let mut inflated = Cursor::new(inflate_bytes(&deflated)?);
+            drive(self, &mut inflated, &mut machine).await?;
+        }
 
-impl Value {
-    #[inline]
-    pub fn new(bytes: Vec<u8>) -> Self {
-        Self { bytes }
+        Ok(machine.into_meta())
     }
 
-    #[inline]
-    pub async fn read<R>(reader: &mut R, offset: usize) -> Result<Self>
+    /// Appends the key of an element, sequence or item, reading its value
+    /// of `length` bytes from `reader` unless it returns [`Control::Skip`].
+    /// Errors end the parsing and are returned by `deserialize`.
+    async fn append<R>(
+        &mut self,
+        reader: &mut R,
+        key: Key,
+        length: usize,
+        vr: Option<u16>,
+    ) -> Result<Control>
     where
-        R: AsyncReadExt + Unpin,
-    {
-        let mut bytes = vec![0; offset];
-        reader.read_exact(&mut bytes).await?;
-
-        Ok(Self { bytes })
-    }
+        R: AsyncReadExt + AsyncSeekExt + Unpin;
 
+    /// Values to defer instead of appending them, none by default.
     #[inline]
-    pub async fn seek<R>(reader: &mut R, offset: u64) -> Result<()>
-    where
-        R: AsyncSeekExt + Unpin,
-    {
-        reader.seek(SeekFrom::Start(offset)).await?;
-
-        Ok(())
+    fn bulk_data_policy(&self) -> Option<&BulkDataPolicy> {
+        None
     }
 
+    /// Appends a value deferred by the bulk data policy, to be read later
+    /// from the source with [`BulkDataRef::read`].
     #[inline]
-    pub fn to_string(&self, vr: Option<u16>) -> String {
-        match vr {
-            Some(vr::OB) | Some(vr::OD) | Some(vr::OF) | Some(vr::OL) | Some(vr::OV)
-            | Some(vr::OW) | Some(vr::SV) | Some(vr::UC) | Some(vr::UR) | Some(vr::UT)
-            | Some(vr::UV) | Some(vr::UN) | Some(vr::FD) => String::new(),
-            Some(_) => String::from_utf8_lossy(&self.bytes).to_string(),
-            None => String::new(),
-        }
+    fn append_bulk_data(
+        &mut self,
+        _key: Key,
+        _bulk_data: BulkDataRef,
+        _vr: u16,
+    ) -> Result<Control> {
+        Ok(Control::Continue)
     }
 
+    /// Private dictionary resolving the VR of private elements in implicit
+    /// VR, none by default.
     #[inline]
-    pub fn bytes(&self) -> &[u8] {
-        &self.bytes
+    fn private_dictionary(&self) -> Option<&PrivateDictionary> {
+        None
     }
 
+    /// Maximum number of nested sequence levels, beyond which parsing fails
+    /// with [`DCKVError::TooManyNestedLevels`]. [`MAX_DEPTH`] by default.
     #[inline]
-    pub fn into_bytes(self) -> Vec<u8> {
-        self.bytes
+    fn max_depth(&self) -> usize {
+        MAX_DEPTH
     }
 }
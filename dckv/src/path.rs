@@ -0,0 +1,209 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{block, DCKVError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Segment {
+    /// Tag pattern, with the nibbles to compare in `mask`, and the item
+    /// number when one is selected.
+    Tag {
+        value: u32,
+        mask: u32,
+        item: Option<u32>,
+    },
+    /// Any number of sequence levels, including none.
+    AnyDepth,
+}
+
+impl Segment {
+    /// Whether the segment matches the sequence level `block`, a key block
+    /// with the sequence tag in its high 4 bytes and the item number in its
+    /// low 4 bytes (0 when the item is unknown yet).
+    #[inline]
+    fn matches_level(&self, block: u64) -> bool {
+        let item_number = block as u32;
+        match *self {
+            Segment::Tag { value, mask, item } => {
+                value == ((block >> 32) as u32) & mask
+                    && item.is_none_or(|item| item_number == 0 || item == item_number)
+            }
+            Segment::AnyDepth => false,
+        }
+    }
+
+    #[inline]
+    fn matches_tag(&self, tag: u32) -> bool {
+        match *self {
+            Segment::Tag { value, mask, .. } => value == tag & mask,
+            Segment::AnyDepth => false,
+        }
+    }
+}
+
+/// Pattern on the contextual path of an element, made of tags separated by
+/// dots, from the top-level sequence down to the element.
+///
+/// Tags are written `(gggg,eeee)` or `ggggeeee`, with `x` standing for any
+/// hex digit. Sequences can select an item with `[n]`, any item being
+/// matched otherwise (or with `[*]`), and `**` stands for any number of
+/// sequence levels.
+///
+/// ```
+/// use dckv::TagPath;
+///
+/// // Concept names of the content items of an SR.
+/// let concept_names: TagPath = "(0040,A730)[*].(0040,A043)".parse().unwrap();
+/// // Code values at any level.
+/// let code_values: TagPath = "**.(0008,0100)".parse().unwrap();
+/// // Top-level relationship attributes only.
+/// let relationship: TagPath = "(0020,xxxx)".parse().unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagPath {
+    segments: Vec<Segment>,
+}
+
+impl TagPath {
+    /// Whether the element `tag`, nested in the sequence items `parents`,
+    /// matches the pattern.
+    #[inline]
+    pub(crate) fn matches(&self, parents: &[u64], tag: u32) -> bool {
+        matches(&self.segments, parents, tag)
+    }
+
+    /// Whether elements nested in the sequence `tag`, itself nested in the
+    /// sequence items `parents`, may match the pattern.
+    #[inline]
+    pub(crate) fn may_match_within(&self, parents: &[u64], tag: u32) -> bool {
+        let mut levels = parents.to_vec();
+        levels.push(block::sequence(tag));
+        may_match_within(&self.segments, &levels)
+    }
+}
+
+fn matches(segments: &[Segment], parents: &[u64], tag: u32) -> bool {
+    match segments.split_first() {
+        None => false,
+        Some((Segment::AnyDepth, rest)) => {
+            (0..=parents.len()).any(|skip| matches(rest, &parents[skip..], tag))
+        }
+        Some((segment, [])) => parents.is_empty() && segment.matches_tag(tag),
+        Some((segment, rest)) => match parents.split_first() {
+            Some((&parent, parents)) => {
+                segment.matches_level(parent) && matches(rest, parents, tag)
+            }
+            None => false,
+        },
+    }
+}
+
+fn may_match_within(segments: &[Segment], levels: &[u64]) -> bool {
+    match segments.split_first() {
+        None => false,
+        Some((Segment::AnyDepth, rest)) => {
+            (0..=levels.len()).any(|skip| may_match_within(rest, &levels[skip..]))
+        }
+        Some((segment, rest)) => match levels.split_first() {
+            // Segments are left to match deeper elements.
+            None => true,
+            Some((&level, levels)) => {
+                !rest.is_empty() && segment.matches_level(level) && may_match_within(rest, levels)
+            }
+        },
+    }
+}
+
+impl FromStr for TagPath {
+    type Err = DCKVError;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        let invalid = || DCKVError::InvalidTagPath(path.to_string());
+
+        let segments = path
+            .split('.')
+            .map(|segment| {
+                if segment == "**" {
+                    return Ok(Segment::AnyDepth);
+                }
+
+                let (tag, item) = match segment.split_once('[') {
+                    Some((tag, item)) => {
+                        let item = match item.strip_suffix(']').ok_or_else(invalid)? {
+                            "*" => None,
+                            number => Some(number.parse::<u32>().map_err(|_| invalid())?),
+                        };
+                        (tag, item)
+                    }
+                    None => (segment, None),
+                };
+
+                let digits = match tag.strip_prefix('(').and_then(|tag| tag.strip_suffix(')')) {
+                    Some(tag) => {
+                        let (group, element) = tag.split_once(',').ok_or_else(invalid)?;
+                        if group.len() != 4 || element.len() != 4 {
+                            return Err(invalid());
+                        }
+                        format!("{group}{element}")
+                    }
+                    None => tag.to_string(),
+                };
+
+                if digits.len() != 8 {
+                    return Err(invalid());
+                }
+
+                let (mut value, mut mask) = (0u32, 0u32);
+                for digit in digits.chars() {
+                    value <<= 4;
+                    mask <<= 4;
+                    if !matches!(digit, 'x' | 'X') {
+                        value |= digit.to_digit(16).ok_or_else(invalid)?;
+                        mask |= 0xF;
+                    }
+                }
+
+                Ok(Segment::Tag { value, mask, item })
+            })
+            .collect::<Result<Vec<Segment>, DCKVError>>()?;
+
+        // The path ends with the element, without item.
+        match segments.last() {
+            Some(Segment::Tag { item: None, .. }) => Ok(Self { segments }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl fmt::Display for TagPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, segment) in self.segments.iter().enumerate() {
+            if index > 0 {
+                write!(f, ".")?;
+            }
+
+            match *segment {
+                Segment::AnyDepth => write!(f, "**")?,
+                Segment::Tag { value, mask, item } => {
+                    write!(f, "(")?;
+                    for nibble in (0..8).rev() {
+                        if nibble == 3 {
+                            write!(f, ",")?;
+                        }
+                        match (mask >> (nibble * 4)) & 0xF {
+                            0 => write!(f, "x")?,
+                            _ => write!(f, "{:X}", (value >> (nibble * 4)) & 0xF)?,
+                        }
+                    }
+                    write!(f, ")")?;
+
+                    if let Some(item) = item {
+                        write!(f, "[{item}]")?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,231 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::json::json_string;
+use crate::DCKVError;
+
+/// Components of a name representation: family name, given name, middle
+/// name, prefix and suffix (PS3.5 6.2.1.1).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct NameGroup {
+    components: [String; 5],
+}
+
+impl NameGroup {
+    /// Family name, compound surnames being separated by `>`, e.g.
+    /// `Apellido1>Apellido2`.
+    #[inline]
+    pub fn family(&self) -> &str {
+        &self.components[0]
+    }
+
+    #[inline]
+    pub fn given(&self) -> &str {
+        &self.components[1]
+    }
+
+    #[inline]
+    pub fn middle(&self) -> &str {
+        &self.components[2]
+    }
+
+    #[inline]
+    pub fn prefix(&self) -> &str {
+        &self.components[3]
+    }
+
+    #[inline]
+    pub fn suffix(&self) -> &str {
+        &self.components[4]
+    }
+
+    /// Surnames of a compound family name.
+    #[inline]
+    pub fn family_names(&self) -> Vec<&str> {
+        split_names(self.family(), '>')
+    }
+
+    #[inline]
+    pub fn given_names(&self) -> Vec<&str> {
+        split_names(self.given(), ' ')
+    }
+
+    /// Name in display order, e.g. `Dr. Nombre1 Apellido1 Apellido2`.
+    pub fn display(&self) -> String {
+        let family = self.family_names().join(" ");
+
+        [
+            self.prefix(),
+            self.given(),
+            self.middle(),
+            &family,
+            self.suffix(),
+        ]
+        .into_iter()
+        .filter(|component| !component.is_empty())
+        .collect::<Vec<&str>>()
+        .join(" ")
+    }
+
+    /// Initials of the given, middle and family names, e.g. `NAA` for
+    /// `Apellido1>Apellido2^Nombre1`.
+    pub fn initials(&self) -> String {
+        let given = self.given_names();
+        let middle = split_names(self.middle(), ' ');
+        let family = self.family_names();
+
+        given
+            .iter()
+            .chain(&middle)
+            .chain(&family)
+            .filter_map(|name| name.chars().next())
+            .flat_map(char::to_uppercase)
+            .collect()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.components.iter().all(String::is_empty)
+    }
+}
+
+impl FromStr for NameGroup {
+    type Err = DCKVError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut components: [String; 5] = Default::default();
+
+        let mut parts = text.split('^');
+        for component in components.iter_mut() {
+            *component = parts.next().unwrap_or_default().trim().to_string();
+        }
+
+        match parts.next() {
+            Some(_) => Err(DCKVError::InvalidPersonName(text.to_string())),
+            None => Ok(Self { components }),
+        }
+    }
+}
+
+impl fmt::Display for NameGroup {
+    /// DICOM encoding of the group, without trailing empty components.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let last = self
+            .components
+            .iter()
+            .rposition(|component| !component.is_empty())
+            .map_or(0, |last| last + 1);
+
+        write!(f, "{}", self.components[..last].join("^"))
+    }
+}
+
+/// PN value: alphabetic, ideographic and phonetic representations of a name.
+///
+/// ```
+/// use dckv::PersonName;
+///
+/// let name: PersonName = "Apellido1>Apellido2^Nombre1 Nombre2".parse().unwrap();
+/// let alphabetic = name.alphabetic().unwrap();
+///
+/// assert_eq!(alphabetic.family_names(), ["Apellido1", "Apellido2"]);
+/// assert_eq!(alphabetic.display(), "Nombre1 Nombre2 Apellido1 Apellido2");
+/// assert_eq!(alphabetic.initials(), "NNAA");
+/// assert_eq!(name.to_json(), r#"{"Alphabetic":"Apellido1>Apellido2^Nombre1 Nombre2"}"#);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PersonName {
+    groups: [Option<NameGroup>; 3],
+}
+
+impl PersonName {
+    #[inline]
+    pub fn alphabetic(&self) -> Option<&NameGroup> {
+        self.groups[0].as_ref()
+    }
+
+    #[inline]
+    pub fn ideographic(&self) -> Option<&NameGroup> {
+        self.groups[1].as_ref()
+    }
+
+    #[inline]
+    pub fn phonetic(&self) -> Option<&NameGroup> {
+        self.groups[2].as_ref()
+    }
+
+    /// Family name of the alphabetic representation, empty without one.
+    #[inline]
+    pub fn family(&self) -> &str {
+        self.alphabetic().map_or("", NameGroup::family)
+    }
+
+    /// Given name of the alphabetic representation, empty without one.
+    #[inline]
+    pub fn given(&self) -> &str {
+        self.alphabetic().map_or("", NameGroup::given)
+    }
+
+    /// DICOM JSON Person Name object (PS3.18 F.2.2), holding the
+    /// representations present.
+    pub fn to_json(&self) -> String {
+        let members = ["Alphabetic", "Ideographic", "Phonetic"]
+            .into_iter()
+            .zip(&self.groups)
+            .filter_map(|(name, group)| {
+                let group = group.as_ref()?;
+                Some(format!("\"{name}\":{}", json_string(&group.to_string())))
+            })
+            .collect::<Vec<String>>();
+
+        format!("{{{}}}", members.join(","))
+    }
+}
+
+impl FromStr for PersonName {
+    type Err = DCKVError;
+
+    /// Parses a single PN value, its padding stripped.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut groups: [Option<NameGroup>; 3] = Default::default();
+
+        let mut parts = text.split('=');
+        for group in groups.iter_mut() {
+            *group = match parts.next() {
+                Some(part) => Some(part.parse::<NameGroup>()?).filter(|group| !group.is_empty()),
+                None => None,
+            };
+        }
+
+        match parts.next() {
+            Some(_) => Err(DCKVError::InvalidPersonName(text.to_string())),
+            None => Ok(Self { groups }),
+        }
+    }
+}
+
+impl fmt::Display for PersonName {
+    /// DICOM encoding of the name, without trailing empty groups.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let groups = self
+            .groups
+            .iter()
+            .map(|group| group.as_ref().map(NameGroup::to_string).unwrap_or_default())
+            .collect::<Vec<String>>();
+        let last = groups
+            .iter()
+            .rposition(|group| !group.is_empty())
+            .map_or(0, |last| last + 1);
+
+        write!(f, "{}", groups[..last].join("="))
+    }
+}
+
+#[inline]
+fn split_names(component: &str, separator: char) -> Vec<&str> {
+    component
+        .split(separator)
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .collect()
+}
@@ -0,0 +1,80 @@
+//! Private data elements (PS3.5 7.8.1). A private creator element
+//! (gggg,00xx) reserves the block (gggg,xx00-xxFF) of its dataset for the
+//! elements of the creator it holds, e.g. `SIEMENS CSA HEADER`, vendors
+//! choosing the block.
+
+/// Whether `tag` is in a private group, an odd group but 0001, 0003, 0005,
+/// 0007 and FFFF.
+#[inline]
+fn is_private(tag: u32) -> bool {
+    let group = tag >> 16;
+    group & 1 == 1 && !matches!(group, 0x0001..=0x0007 | 0xFFFF)
+}
+
+/// Maximum length of a private creator value, an LO.
+pub(crate) const MAX_CREATOR_LENGTH: u64 = 64;
+
+/// Whether `tag` is a private creator element, (gggg,0010-00FF).
+#[inline]
+pub(crate) fn is_creator(tag: u32) -> bool {
+    is_private(tag) && (0x0010..=0x00FF).contains(&(tag & 0xFFFF))
+}
+
+/// Tag of the private creator element reserving the block of the private
+/// data element `tag`, (gggg,1000-FFFF).
+#[inline]
+pub(crate) fn creator_tag(tag: u32) -> Option<u32> {
+    match is_private(tag) && tag & 0xFFFF >= 0x1000 {
+        true => Some((tag & 0xFFFF0000) + ((tag >> 8) & 0xFF)),
+        false => None,
+    }
+}
+
+/// Creator of a private creator value, without its padding.
+#[inline]
+pub(crate) fn creator(value: &[u8]) -> String {
+    String::from_utf8_lossy(value)
+        .trim_matches([' ', '\0'])
+        .to_string()
+}
+
+/// Private creator reservations of the datasets being parsed, each one
+/// lasting until the end of the item it was made in.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PrivateCreators {
+    /// Level of the dataset, and tag of the creator element, of each
+    /// reservation, with its creator.
+    reservations: Vec<(usize, u32, String)>,
+}
+
+impl PrivateCreators {
+    /// Reserves a block at `level` with the private creator element `tag`
+    /// holding `value`.
+    #[inline]
+    pub(crate) fn reserve(&mut self, level: usize, tag: u32, value: &[u8]) {
+        self.reservations.push((level, tag, creator(value)));
+    }
+
+    /// Creator of the private data element `tag` at `level`, if its block
+    /// is reserved.
+    #[inline]
+    pub(crate) fn creator(&self, level: usize, tag: u32) -> Option<&str> {
+        let creator_tag = creator_tag(tag)?;
+
+        self.reservations
+            .iter()
+            .rev()
+            .find(|&&(reserved_level, reserved_tag, _)| {
+                reserved_level == level && reserved_tag == creator_tag
+            })
+            .map(|(_, _, creator)| creator.as_str())
+    }
+
+    /// Drops the reservations of the levels deeper than `level`, when an
+    /// item of `level` ends.
+    #[inline]
+    pub(crate) fn leave(&mut self, level: usize) {
+        self.reservations
+            .retain(|&(reserved_level, _, _)| reserved_level <= level);
+    }
+}
@@ -0,0 +1,192 @@
+use std::io::{Cursor, Read, Seek, SeekFrom};
+
+use crate::machine::{inflate_bytes, swap, Input, Machine, Options, Step};
+use crate::{
+    BulkDataPolicy, BulkDataRef, Control, FileMeta, Filter, Key, PrivateDictionary, Result,
+    MAX_DEPTH,
+};
+
+/// Reads up to `length` bytes, fewer at the end of the source.
+#[inline]
+fn fill<R>(reader: &mut R, buffer: &mut Vec<u8>, length: usize) -> Result<()>
+where
+    R: Read,
+{
+    buffer.clear();
+    reader.by_ref().take(length as u64).read_to_end(buffer)?;
+
+    Ok(())
+}
+
+/// Appends a value, swapping its binary numbers of `swap_width` bytes so
+/// that deserializers never see a big endian source. Skipped values are
+/// consumed, so that only `Stop` is left to handle.
+#[inline]
+fn append_value<S, R>(
+    shared: &mut S,
+    reader: &mut R,
+    key: Key,
+    length: usize,
+    vr: u16,
+    swap_width: Option<usize>,
+) -> Result<Control>
+where
+    S: Clone + SyncDeserializer,
+    R: Read + Seek,
+{
+    match swap_width {
+        Some(width) => {
+            let mut bytes = vec![0; length];
+            reader.read_exact(&mut bytes)?;
+            swap(&mut bytes, width);
+
+            let mut swapped = Cursor::new(bytes);
+            shared.append(&mut swapped, key, length, Some(vr))
+        }
+        None => {
+            let control = shared.append(reader, key, length, Some(vr))?;
+            if control == Control::Skip {
+                reader.seek(SeekFrom::Current(length as i64))?;
+            }
+            Ok(control)
+        }
+    }
+}
+
+/// Runs the steps of `machine` over `reader`, until the parse ends or the
+/// rest of the source has to be inflated, returning whether it has.
+pub(crate) fn drive<S, R>(shared: &mut S, reader: &mut R, machine: &mut Machine<'_>) -> Result<bool>
+where
+    S: Clone + SyncDeserializer,
+    R: Read + Seek,
+{
+    let mut buffer = vec![];
+    let mut input = Input::None;
+
+    loop {
+        input = match machine.resume(input)? {
+            Step::Read(length) => {
+                fill(reader, &mut buffer, length)?;
+                Input::Bytes(&buffer)
+            }
+            Step::Peek(length) => {
+                let position = reader.stream_position()?;
+                fill(reader, &mut buffer, length)?;
+                reader.seek(SeekFrom::Start(position))?;
+                Input::Bytes(&buffer)
+            }
+            Step::Skip(length) => {
+                reader.seek(SeekFrom::Current(length as i64))?;
+                Input::None
+            }
+            Step::Value {
+                vr,
+                length,
+                swap_width,
+            } => {
+                let key = Key::from_key_blocks(machine.key_blocks());
+                Input::Control(append_value(shared, reader, key, length, vr, swap_width)?)
+            }
+            Step::BulkData { vr, bulk_data } => {
+                reader.seek(SeekFrom::Current(bulk_data.length() as i64))?;
+                let key = Key::from_key_blocks(machine.key_blocks());
+                Input::Control(shared.append_bulk_data(key, bulk_data, vr)?)
+            }
+            Step::Marker { vr } => {
+                let key = Key::from_key_blocks(machine.key_blocks());
+                Input::Control(shared.append(reader, key, 0, vr)?)
+            }
+            Step::Inflate => return Ok(true),
+            Step::End => return Ok(false),
+        };
+    }
+}
+
+/// Blocking [`Deserializer`](crate::Deserializer), parsing from a
+/// `std::io::Read + Seek` source or a byte slice without a runtime. Keys,
+/// values and control flow are the same as the async parser's.
+///
+/// Both traits have the same method names, so a type implementing both is
+/// used with only one of them in scope.
+pub trait SyncDeserializer
+where
+    Self: Clone,
+{
+    /// Parses a DICOM file or a bare dataset, returning its file meta
+    /// information if present. The dataset is decoded according to the
+    /// transfer syntax (0002,0010), or guessed when there is none, until
+    /// its end or until an append returns [`Control::Stop`].
+    fn deserialize<R>(&mut self, mut reader: R, filter: &Filter) -> Result<Option<FileMeta>>
+    where
+        R: Read + Seek,
+    {
+        let options = Options {
+            filter,
+            bulk_data: self.bulk_data_policy().cloned(),
+            max_depth: self.max_depth(),
+            private: self.private_dictionary().cloned().unwrap_or_default(),
+        };
+        let mut machine = Machine::new(options, reader.stream_position()?);
+
+        if drive(self, &mut reader, &mut machine)? {
+            let mut deflated = vec![];
+            reader.read_to_end(&mut deflated)?;
+
+            let mut inflated = Cursor::new(inflate_bytes(&deflated)?);
+            drive(self, &mut inflated, &mut machine)?;
+        }
+
+        Ok(machine.into_meta())
+    }
+
+    /// Parses a DICOM file or a bare dataset held in memory.
+    #[inline]
+    fn deserialize_bytes(&mut self, bytes: &[u8], filter: &Filter) -> Result<Option<FileMeta>> {
+        self.deserialize(Cursor::new(bytes), filter)
+    }
+
+    /// Appends the key of an element, sequence or item, reading its value
+    /// of `length` bytes from `reader` unless it returns [`Control::Skip`].
+    /// Errors end the parsing and are returned by `deserialize`.
+    fn append<R>(
+        &mut self,
+        reader: &mut R,
+        key: Key,
+        length: usize,
+        vr: Option<u16>,
+    ) -> Result<Control>
+    where
+        R: Read + Seek;
+
+    /// Values to defer instead of appending them, none by default.
+    #[inline]
+    fn bulk_data_policy(&self) -> Option<&BulkDataPolicy> {
+        None
+    }
+
+    /// Appends a value deferred by the bulk data policy, to be read later
+    /// from the source with [`BulkDataRef::read_sync`].
+    #[inline]
+    fn append_bulk_data(
+        &mut self,
+        _key: Key,
+        _bulk_data: BulkDataRef,
+        _vr: u16,
+    ) -> Result<Control> {
+        Ok(Control::Continue)
+    }
+
+    /// Private dictionary resolving the VR of private elements in implicit
+    /// VR, none by default.
+    #[inline]
+    fn private_dictionary(&self) -> Option<&PrivateDictionary> {
+        None
+    }
+
+    /// Maximum number of nested sequence levels, beyond which parsing fails
+    /// with [`DCKVError::TooManyNestedLevels`]. [`MAX_DEPTH`] by default.
+    #[inline]
+    fn max_depth(&self) -> usize {
+        MAX_DEPTH
+    }
+}
@@ -0,0 +1,324 @@
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime};
+use std::io::{Read, SeekFrom};
+use std::str;
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+use crate::datetime::{parse_date, parse_datetime, parse_offset, parse_range, parse_time};
+use crate::{vr, CharacterSet, DCKVError, DateTimeRange, PersonName, Result};
+
+/// Value of an element. Binary numbers are little endian, whatever the
+/// transfer syntax of the source.
+///
+/// ```
+/// use dckv::Value;
+///
+/// // (0028,0030) Pixel Spacing, DS.
+/// let spacing = Value::new(b"0.5\\0.25 ".to_vec());
+/// assert_eq!(spacing.to_decimals().unwrap(), [0.5, 0.25]);
+///
+/// // (0028,0010) Rows, US.
+/// let rows = Value::new(512u16.to_le_bytes().to_vec());
+/// assert_eq!(rows.to_u16s().unwrap(), [512]);
+/// assert!(Value::new(vec![0; 3]).to_u16s().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Value {
+    bytes: Vec<u8>,
+}
+
+impl Value {
+    #[inline]
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self { bytes }
+    }
+
+    #[inline]
+    pub async fn read<R>(reader: &mut R, offset: usize) -> Result<Self>
+    where
+        R: AsyncReadExt + Unpin,
+    {
+        let mut bytes = vec![0; offset];
+        reader.read_exact(&mut bytes).await?;
+
+        Ok(Self { bytes })
+    }
+
+    /// Blocking [`Value::read`].
+    #[inline]
+    pub fn read_sync<R>(reader: &mut R, length: usize) -> Result<Self>
+    where
+        R: Read,
+    {
+        let mut bytes = vec![0; length];
+        reader.read_exact(&mut bytes)?;
+
+        Ok(Self { bytes })
+    }
+
+    #[inline]
+    pub async fn seek<R>(reader: &mut R, offset: u64) -> Result<()>
+    where
+        R: AsyncSeekExt + Unpin,
+    {
+        reader.seek(SeekFrom::Start(offset)).await?;
+
+        Ok(())
+    }
+
+    /// Text of the value, numbers being separated by backslashes. Empty for
+    /// bulk VRs, e.g. OB or OW, and for sequences and items.
+    pub fn to_string(&self, vr: Option<u16>) -> String {
+        match vr {
+            Some(vr::US) => join(self.to_u16s()),
+            Some(vr::SS) => join(self.to_i16s()),
+            Some(vr::UL) => join(self.to_u32s()),
+            Some(vr::SL) => join(self.to_i32s()),
+            Some(vr::UV) => join(self.to_u64s()),
+            Some(vr::SV) => join(self.to_i64s()),
+            Some(vr::FL) => join(self.to_f32s()),
+            Some(vr::FD) => join(self.to_f64s()),
+            Some(vr::AT) => join(self.to_tags().map(|tags| {
+                tags.iter()
+                    .map(|(group, element)| format!("({group:04X},{element:04X})"))
+                    .collect()
+            })),
+            Some(vr::OB | vr::OD | vr::OF | vr::OL | vr::OV | vr::OW | vr::UN) | None => {
+                Ok(String::new())
+            }
+            Some(_) => Ok(String::from_utf8_lossy(&self.bytes).to_string()),
+        }
+        .unwrap_or_default()
+    }
+
+    /// Text of a string VR, without its padding (PS3.5 6.2).
+    pub fn to_str(&self, vr: u16) -> Result<&str> {
+        Ok(trim(str::from_utf8(&self.bytes)?, vr))
+    }
+
+    /// Values of a string VR, split at backslashes unless the VR is single
+    /// valued (LT, ST, UR and UT), without their padding. Empty when the
+    /// value is.
+    pub fn to_strs(&self, vr: u16) -> Result<Vec<&str>> {
+        Ok(split(self.to_str(vr)?, vr))
+    }
+
+    /// Text of a value of `vr` decoded with `charset`, without its padding.
+    #[inline]
+    pub fn to_text(&self, vr: u16, charset: &CharacterSet) -> String {
+        trim(&charset.decode(&self.bytes, vr), vr).to_string()
+    }
+
+    /// Values of a value of `vr` decoded with `charset`, see
+    /// [`Value::to_strs`].
+    #[inline]
+    pub fn to_texts(&self, vr: u16, charset: &CharacterSet) -> Vec<String> {
+        let text = charset.decode(&self.bytes, vr);
+
+        split(trim(&text, vr), vr)
+            .into_iter()
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// DS values.
+    pub fn to_decimals(&self) -> Result<Vec<f64>> {
+        self.to_strs(vr::DS)?
+            .into_iter()
+            .map(parse_number)
+            .collect()
+    }
+
+    /// IS values.
+    pub fn to_integers(&self) -> Result<Vec<i64>> {
+        self.to_strs(vr::IS)?
+            .into_iter()
+            .map(parse_number)
+            .collect()
+    }
+
+    /// AT values, as group and element pairs.
+    pub fn to_tags(&self) -> Result<Vec<(u16, u16)>> {
+        let numbers = self.to_u16s()?;
+        if numbers.len() % 2 != 0 {
+            return Err(DCKVError::InvalidValueLength(self.bytes.len()));
+        }
+
+        Ok(numbers
+            .chunks_exact(2)
+            .map(|tag| (tag[0], tag[1]))
+            .collect())
+    }
+
+    /// PN values decoded with `charset`.
+    pub fn to_person_names(&self, charset: &CharacterSet) -> Result<Vec<PersonName>> {
+        self.to_texts(vr::PN, charset)
+            .iter()
+            .map(|name| name.parse())
+            .collect()
+    }
+
+    /// DA value.
+    #[inline]
+    pub fn to_date(&self) -> Result<NaiveDate> {
+        parse_date(self.to_str(vr::DA)?)
+    }
+
+    /// TM value, missing components being 0, e.g. `10` is 10:00:00.
+    #[inline]
+    pub fn to_time(&self) -> Result<NaiveTime> {
+        parse_time(self.to_str(vr::TM)?, false)
+    }
+
+    /// DT value, missing components being the earliest. Without its own
+    /// offset, the value is in `offset`, usually (0008,0201) Timezone Offset
+    /// From UTC, or in UTC.
+    #[inline]
+    pub fn to_datetime(&self, offset: Option<FixedOffset>) -> Result<DateTime<FixedOffset>> {
+        parse_datetime(self.to_str(vr::DT)?, offset, false)
+    }
+
+    /// (0008,0201) Timezone Offset From UTC.
+    #[inline]
+    pub fn to_offset(&self) -> Result<FixedOffset> {
+        parse_offset(self.to_str(vr::SH)?)
+    }
+
+    /// DA range of a query.
+    #[inline]
+    pub fn to_date_range(&self) -> Result<DateTimeRange<NaiveDate>> {
+        parse_range(self.to_str(vr::DA)?, |text, _| parse_date(text))
+    }
+
+    /// TM range of a query, e.g. `10-12` from 10:00:00 to 12:59:59.999999.
+    #[inline]
+    pub fn to_time_range(&self) -> Result<DateTimeRange<NaiveTime>> {
+        parse_range(self.to_str(vr::TM)?, parse_time)
+    }
+
+    /// DT range of a query, see [`Value::to_datetime`].
+    #[inline]
+    pub fn to_datetime_range(
+        &self,
+        offset: Option<FixedOffset>,
+    ) -> Result<DateTimeRange<DateTime<FixedOffset>>> {
+        parse_range(self.to_str(vr::DT)?, |text, latest| {
+            parse_datetime(text, offset, latest)
+        })
+    }
+
+    /// US or OW values.
+    #[inline]
+    pub fn to_u16s(&self) -> Result<Vec<u16>> {
+        self.numbers(u16::from_le_bytes)
+    }
+
+    /// SS values.
+    #[inline]
+    pub fn to_i16s(&self) -> Result<Vec<i16>> {
+        self.numbers(i16::from_le_bytes)
+    }
+
+    /// UL or OL values.
+    #[inline]
+    pub fn to_u32s(&self) -> Result<Vec<u32>> {
+        self.numbers(u32::from_le_bytes)
+    }
+
+    /// SL values.
+    #[inline]
+    pub fn to_i32s(&self) -> Result<Vec<i32>> {
+        self.numbers(i32::from_le_bytes)
+    }
+
+    /// UV or OV values.
+    #[inline]
+    pub fn to_u64s(&self) -> Result<Vec<u64>> {
+        self.numbers(u64::from_le_bytes)
+    }
+
+    /// SV values.
+    #[inline]
+    pub fn to_i64s(&self) -> Result<Vec<i64>> {
+        self.numbers(i64::from_le_bytes)
+    }
+
+    /// FL or OF values.
+    #[inline]
+    pub fn to_f32s(&self) -> Result<Vec<f32>> {
+        self.numbers(f32::from_le_bytes)
+    }
+
+    /// FD or OD values.
+    #[inline]
+    pub fn to_f64s(&self) -> Result<Vec<f64>> {
+        self.numbers(f64::from_le_bytes)
+    }
+
+    #[inline]
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    #[inline]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// Little endian numbers of `N` bytes.
+    #[inline]
+    fn numbers<const N: usize, T>(&self, from_le_bytes: fn([u8; N]) -> T) -> Result<Vec<T>> {
+        let chunks = self.bytes.chunks_exact(N);
+        if !chunks.remainder().is_empty() {
+            return Err(DCKVError::InvalidValueLength(self.bytes.len()));
+        }
+
+        Ok(chunks
+            .map(|chunk| from_le_bytes(chunk.try_into().unwrap_or([0; N])))
+            .collect())
+    }
+}
+
+/// Values of a text without its padding, separated by backslashes unless
+/// `vr` is single valued.
+#[inline]
+fn split(text: &str, vr: u16) -> Vec<&str> {
+    match (text.is_empty(), vr) {
+        (true, _) => vec![],
+        (false, vr::LT | vr::ST | vr::UR | vr::UT) => vec![text],
+        (false, _) => text.split('\\').map(|value| trim(value, vr)).collect(),
+    }
+}
+
+/// Values separated by backslashes.
+#[inline]
+fn join<T>(values: Result<Vec<T>>) -> Result<String>
+where
+    T: ToString,
+{
+    Ok(values?
+        .iter()
+        .map(T::to_string)
+        .collect::<Vec<String>>()
+        .join("\\"))
+}
+
+/// Strips the padding of a string VR: trailing spaces, and the trailing NUL
+/// of UI values, always, and leading spaces unless they are significant.
+#[inline]
+fn trim(text: &str, vr: u16) -> &str {
+    let text = text.trim_end_matches([' ', '\0']);
+
+    match vr {
+        vr::LT | vr::PN | vr::ST | vr::UC | vr::UI | vr::UR | vr::UT => text,
+        _ => text.trim_start_matches(' '),
+    }
+}
+
+#[inline]
+fn parse_number<T>(text: &str) -> Result<T>
+where
+    T: str::FromStr,
+{
+    text.parse()
+        .map_err(|_| DCKVError::InvalidNumber(text.to_string()))
+}
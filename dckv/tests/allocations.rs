@@ -0,0 +1,127 @@
+//! Allocations of the in-memory parser, which hands out views of its input
+//! instead of copies.
+
+use bytes::Bytes;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use dckv::{BytesDeserializer, Control, DCKVError, Filter, KeyRef};
+
+/// Counts the allocations of each thread, tests running side by side.
+struct Counting;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// Counts values and their bytes, keeping nothing.
+#[derive(Debug, Clone, Default)]
+struct Lengths {
+    values: usize,
+    bytes: usize,
+}
+
+impl BytesDeserializer for Lengths {
+    fn append(
+        &mut self,
+        _key: KeyRef<'_>,
+        value: Bytes,
+        _vr: Option<u16>,
+    ) -> Result<Control, DCKVError> {
+        self.values += 1;
+        self.bytes += value.len();
+        Ok(Control::Continue)
+    }
+}
+
+fn explicit_element(group: u16, element: u16, vr: &[u8; 2], value: &[u8]) -> Vec<u8> {
+    [
+        &group.to_le_bytes()[..],
+        &element.to_le_bytes(),
+        vr,
+        &(value.len() as u16).to_le_bytes(),
+        value,
+    ]
+    .concat()
+}
+
+fn item(content: &[u8]) -> Vec<u8> {
+    [
+        &[0xFE, 0xFF, 0x00, 0xE0][..],
+        &(content.len() as u32).to_le_bytes(),
+        content,
+    ]
+    .concat()
+}
+
+/// Allocations made to parse `bytes`, with the values it holds.
+fn allocations(bytes: Vec<u8>) -> (usize, Lengths) {
+    let bytes = Bytes::from(bytes);
+    let filter = Filter::none();
+    let mut lengths = Lengths::default();
+
+    let before = ALLOCATIONS.with(Cell::get);
+    BytesDeserializer::deserialize(&mut lengths, bytes, &filter).unwrap();
+    let after = ALLOCATIONS.with(Cell::get);
+
+    (after - before, lengths)
+}
+
+/// Dataset of `count` (0008,1xxx) SH elements.
+fn elements(count: u16) -> Vec<u8> {
+    (0..count)
+        .flat_map(|n| explicit_element(0x0008, 0x1000 + n, b"SH", b"ABCD"))
+        .collect()
+}
+
+/// Dataset of a (0040,A730) Content Sequence of `count` items, each one
+/// holding a (0040,A040) Value Type.
+fn items(count: usize) -> Vec<u8> {
+    let value_type = explicit_element(0x0040, 0xA040, b"CS", b"TEXT");
+    let items = (0..count)
+        .flat_map(|_| item(&value_type))
+        .collect::<Vec<_>>();
+
+    [
+        &[0x40, 0x00, 0x30, 0xA7, b'S', b'Q', 0, 0][..],
+        &(items.len() as u32).to_le_bytes(),
+        &items,
+    ]
+    .concat()
+}
+
+#[test]
+fn allocations_do_not_grow_with_elements() {
+    let (few, lengths) = allocations(elements(10));
+    assert_eq!((lengths.values, lengths.bytes), (10, 40));
+
+    let (many, lengths) = allocations(elements(1000));
+    assert_eq!((lengths.values, lengths.bytes), (1000, 4000));
+
+    assert_eq!(few, many);
+}
+
+#[test]
+fn allocations_do_not_grow_with_items() {
+    let (few, lengths) = allocations(items(10));
+    assert_eq!(lengths.values, 2 + 3 * 10);
+
+    let (many, lengths) = allocations(items(1000));
+    assert_eq!(lengths.values, 2 + 3 * 1000);
+
+    assert_eq!(few, many);
+}
@@ -0,0 +1,188 @@
+//! Values deferred by a bulk data policy, and fetched back from the source.
+
+mod common;
+
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+use std::io::{Cursor, Write};
+
+use common::{big_endian_element, element};
+use dckv::{BulkDataPolicy, Filter, KVMap, Key, SyncDeserializer};
+
+const EXPLICIT_LITTLE_ENDIAN: &str = "1.2.840.10008.1.2.1";
+const DEFLATED: &str = "1.2.840.10008.1.2.1.99";
+const BIG_ENDIAN: &str = "1.2.840.10008.1.2.2";
+
+/// Preamble, prefix and file meta information of a file in `syntax`.
+fn file_meta(syntax: &str) -> Vec<u8> {
+    let mut uid = syntax.as_bytes().to_vec();
+    if uid.len() % 2 == 1 {
+        uid.push(0);
+    }
+    [
+        vec![0; 128],
+        b"DICM".to_vec(),
+        element(0x00020010, b"UI", &uid),
+    ]
+    .concat()
+}
+
+/// Binary and text values of 0 to 8 bytes.
+fn dataset(big_endian: bool) -> Vec<u8> {
+    let numbers = |bytes: [u8; 8], width: usize| -> Vec<u8> {
+        match big_endian {
+            true => bytes
+                .chunks(width)
+                .flat_map(|n| n.iter().rev())
+                .copied()
+                .collect(),
+            false => bytes.to_vec(),
+        }
+    };
+    let element = |tag, vr: &[u8; 2], value: &[u8]| match big_endian {
+        true => big_endian_element(tag, vr, value),
+        false => element(tag, vr, value),
+    };
+    [
+        element(0x00080060, b"CS", b""),
+        element(0x00100010, b"PN", b"Doe^John"),
+        element(0x00180050, b"DS", b"1.5 "),
+        element(0x00189087, b"FD", &numbers(2.5f64.to_le_bytes(), 8)),
+        element(
+            0x00209165,
+            b"AT",
+            &numbers([0x18, 0, 0x87, 0x90, 0, 0, 0, 0], 2)[..4],
+        ),
+        element(
+            0x00280010,
+            b"US",
+            &numbers([0, 2, 0, 0, 0, 0, 0, 0], 2)[..2],
+        ),
+        element(0x00281201, b"OW", &numbers([1, 0, 2, 0, 3, 0, 4, 0], 2)),
+        element(0x00291010, b"OB", &[1, 2, 3]),
+    ]
+    .concat()
+}
+
+fn parse(bytes: &[u8], kvmap: KVMap) -> KVMap {
+    let mut kvmap = kvmap;
+    SyncDeserializer::deserialize(&mut kvmap, Cursor::new(bytes), &Filter::none()).unwrap();
+    kvmap
+}
+
+/// Keys of the deferred values.
+fn deferred(kvmap: &KVMap, inline: &KVMap) -> Vec<String> {
+    inline
+        .range(..)
+        .map(|(key, _)| key.to_key())
+        .filter(|key| kvmap.get_bulk_data(key.clone()).is_some())
+        .map(|key| key.to_string())
+        .collect()
+}
+
+#[test]
+fn values_over_the_threshold_are_deferred() {
+    let bytes = [file_meta(EXPLICIT_LITTLE_ENDIAN), dataset(false)].concat();
+    let inline = parse(&bytes, KVMap::new());
+
+    let kvmap = parse(
+        &bytes,
+        KVMap::with_bulk_data(BulkDataPolicy::new().threshold(4)),
+    );
+    assert_eq!(
+        deferred(&kvmap, &inline),
+        ["00020010:UI", "00100010:PN", "00189087:FD", "00281201:OW"]
+    );
+    assert_eq!(kvmap.get("PatientName".parse().unwrap()), None);
+    assert!(kvmap.get("SliceThickness".parse().unwrap()).is_some());
+
+    // Empty values never are.
+    let kvmap = parse(
+        &bytes,
+        KVMap::with_bulk_data(BulkDataPolicy::new().threshold(0)),
+    );
+    assert!(kvmap.get("Modality".parse().unwrap()).is_some());
+    assert_eq!(
+        deferred(&kvmap, &inline).len(),
+        inline.range(..).count() - 1
+    );
+}
+
+#[test]
+fn values_of_the_selected_vrs_are_deferred() {
+    let bytes = [file_meta(EXPLICIT_LITTLE_ENDIAN), dataset(false)].concat();
+    let inline = parse(&bytes, KVMap::new());
+
+    let policy = BulkDataPolicy::new().vrs(["OB", "OW", "XXX"]);
+    let kvmap = parse(&bytes, KVMap::with_bulk_data(policy));
+    assert_eq!(deferred(&kvmap, &inline), ["00281201:OW", "00291010:OB"]);
+
+    // Either rule defers a value.
+    let policy = BulkDataPolicy::new().vr("OB").threshold(6);
+    let kvmap = parse(&bytes, KVMap::with_bulk_data(policy));
+    assert_eq!(
+        deferred(&kvmap, &inline),
+        [
+            "00020010:UI",
+            "00100010:PN",
+            "00189087:FD",
+            "00281201:OW",
+            "00291010:OB"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn deferred_values_read_back_as_inline_ones() {
+    for syntax in [EXPLICIT_LITTLE_ENDIAN, BIG_ENDIAN] {
+        let bytes = [file_meta(syntax), dataset(syntax == BIG_ENDIAN)].concat();
+        let inline = parse(&bytes, KVMap::new());
+        let kvmap = parse(
+            &bytes,
+            KVMap::with_bulk_data(BulkDataPolicy::new().threshold(0)),
+        );
+
+        let keys = inline
+            .range(..)
+            .map(|(key, _)| key.to_key())
+            .filter(|key| kvmap.get_bulk_data(key.clone()).is_some())
+            .collect::<Vec<Key>>();
+        assert_eq!(keys.len(), 8);
+        // Big endian numbers are swapped either way.
+        assert_eq!(inline.get("Rows".parse().unwrap()).unwrap(), &[0, 2]);
+
+        for key in keys {
+            let value = inline.get(key.clone()).unwrap();
+            let bulk_data = kvmap.get_bulk_data(key.clone()).unwrap();
+
+            let read_sync = bulk_data.read_sync(&mut Cursor::new(&bytes)).unwrap();
+            assert_eq!(read_sync.bytes(), value, "{key}");
+            let read = bulk_data.read(&mut Cursor::new(&bytes)).await.unwrap();
+            assert_eq!(read.bytes(), value, "{key}");
+
+            let fetched = kvmap.fetch(&mut Cursor::new(&bytes), key).await.unwrap();
+            assert_eq!(fetched.unwrap().bytes(), value);
+        }
+    }
+}
+
+#[test]
+fn deflated_datasets_are_read_inline() {
+    let mut encoder = DeflateEncoder::new(vec![], Compression::default());
+    encoder.write_all(&dataset(false)).unwrap();
+    let bytes = [file_meta(DEFLATED), encoder.finish().unwrap()].concat();
+
+    let inline = parse(&bytes, KVMap::new());
+    let kvmap = parse(
+        &bytes,
+        KVMap::with_bulk_data(BulkDataPolicy::new().threshold(0)),
+    );
+
+    // Only the file meta information, out of the deflated stream, is
+    // deferred.
+    assert_eq!(deferred(&kvmap, &inline), ["00020010:UI"]);
+    assert_eq!(
+        kvmap.get("PatientName".parse().unwrap()).unwrap(),
+        b"Doe^John"
+    );
+}
@@ -0,0 +1,114 @@
+//! Specific Character Set decoding: ISO 2022 code extensions, person names
+//! mixing character sets, and character sets of nested datasets.
+
+mod common;
+
+use common::{element, kvmap, sequence};
+use dckv::{CharacterSet, Value};
+
+const PN: u16 = u16::from_be_bytes(*b"PN");
+
+/// Text of a PN value in the character set of `specific_character_set`.
+fn decode(specific_character_set: &[u8], bytes: &[u8]) -> String {
+    let charset = CharacterSet::from_value(&Value::new(specific_character_set.to_vec())).unwrap();
+    Value::new(bytes.to_vec()).to_text(PN, &charset)
+}
+
+#[test]
+fn japanese_kanji_and_hiragana_are_decoded_through_euc_jp() {
+    // PS3.5 H.3.1.
+    let name = [
+        b"Yamada^Tarou=".as_slice(),
+        b"\x1B$B;3ED\x1B(B^\x1B$BB@O:\x1B(B=",
+        b"\x1B$B$d$^$@\x1B(B^\x1B$B$?$m$&\x1B(B",
+    ]
+    .concat();
+    assert_eq!(
+        decode(b"\\ISO 2022 IR 87", &name),
+        "Yamada^Tarou=山田^太郎=やまだ^たろう"
+    );
+
+    // JIS X 0212 supplementary kanji, 丂 and 丄.
+    let name = b"\x1B$(D0!0\"\x1B(B".as_slice();
+    assert_eq!(decode(b"\\ISO 2022 IR 87\\ISO 2022 IR 159", name), "丂丄");
+}
+
+#[test]
+fn korean_and_chinese_are_designated_to_g1() {
+    // PS3.5 I.2.
+    let name = [
+        b"Hong^Gildong=".as_slice(),
+        b"\x1B$)C\xFB\xF3^\x1B$)C\xD1\xCE\xD4\xD7=",
+        b"\x1B$)C\xC8\xAB^\x1B$)C\xB1\xE6\xB5\xBF",
+    ]
+    .concat();
+    assert_eq!(
+        decode(b"\\ISO 2022 IR 149", &name),
+        "Hong^Gildong=洪^吉洞=홍^길동"
+    );
+
+    // PS3.5 K.2.
+    let name = b"Zhang^XiaoDong=\x1B$)A\xD5\xC5^\x1B$)A\xD0\xA1\xB6\xAB=".as_slice();
+    assert_eq!(decode(b"\\ISO 2022 IR 58", name), "Zhang^XiaoDong=张^小东=");
+}
+
+#[test]
+fn person_names_switch_character_sets_across_their_groups() {
+    // PS3.5 H.3.2: half-width katakana in G1 for the alphabetic group, kanji
+    // and hiragana in G0 for the others, each delimiter invoking the
+    // katakana again.
+    let name = [
+        b"\xD4\xCF\xC0\xDE^\xC0\xDB\xB3=".as_slice(),
+        b"\x1B$B;3ED\x1B(J^\x1B$BB@O:\x1B(J=",
+        b"\x1B$B$d$^$@\x1B(J^\x1B$B$?$m$&\x1B(J",
+    ]
+    .concat();
+    let text = decode(b"ISO 2022 IR 13\\ISO 2022 IR 87", &name);
+    assert_eq!(text, "ﾔﾏﾀﾞ^ﾀﾛｳ=山田^太郎=やまだ^たろう");
+
+    let groups = text.split('=').collect::<Vec<_>>();
+    assert_eq!(groups, ["ﾔﾏﾀﾞ^ﾀﾛｳ", "山田^太郎", "やまだ^たろう"]);
+
+    // A designation does not outlast its group.
+    let name = b"\x1B$)C\xC8\xAB^\xC8\xAB".as_slice();
+    assert_eq!(decode(b"\\ISO 2022 IR 149", name), "홍^\u{FFFD}\u{FFFD}");
+}
+
+#[test]
+fn items_may_override_the_character_set_of_their_dataset() {
+    let bytes = [
+        element(0x00080005, b"CS", b"ISO_IR 100"),
+        element(0x00100010, b"PN", b"Mu\xF1oz^Jos\xE9"),
+        sequence(
+            0x0040A730,
+            &[
+                [
+                    element(0x00080005, b"CS", b"ISO_IR 144"),
+                    element(
+                        0x00100010,
+                        b"PN",
+                        b"\xBB\xDE\xE6\xDC\xD0\xDD^\xB0\xDD\xD4\xE0\xD5\xD9",
+                    ),
+                    sequence(
+                        0x0040A730,
+                        &[element(0x00100010, b"PN", b"\xB8\xD2\xD0\xDD")],
+                    ),
+                ]
+                .concat(),
+                element(0x00100010, b"PN", b"Garc\xEDa"),
+            ],
+        ),
+    ]
+    .concat();
+    let kvmap = kvmap(&bytes);
+    let text = |key: &str| kvmap.get_text(key.parse().unwrap()).unwrap().unwrap();
+
+    assert_eq!(text("PatientName"), "Muñoz^José");
+    assert_eq!(text("ContentSequence[1].PatientName"), "Лоцман^Андрей");
+    // Nested datasets inherit the character set of the nearest one.
+    assert_eq!(
+        text("ContentSequence[1].ContentSequence[1].PatientName"),
+        "Иван"
+    );
+    assert_eq!(text("ContentSequence[2].PatientName"), "García");
+}
@@ -0,0 +1,180 @@
+//! Fixtures shared by the integration tests.
+
+#![allow(dead_code)]
+
+use dckv::{DCKVFormat, DCKVWriter, Filter, KVMap, Result, SyncDeserializer};
+
+pub fn tag_bytes(tag: u32) -> Vec<u8> {
+    endian_tag_bytes(tag, false)
+}
+
+pub fn big_endian_tag_bytes(tag: u32) -> Vec<u8> {
+    endian_tag_bytes(tag, true)
+}
+
+/// Explicit VR little endian element, with a 4 byte length for the VRs
+/// having one (PS3.5 7.1.2).
+pub fn element(tag: u32, vr: &[u8; 2], value: &[u8]) -> Vec<u8> {
+    endian_element(tag, vr, value, false)
+}
+
+/// Explicit VR big endian [`element`].
+pub fn big_endian_element(tag: u32, vr: &[u8; 2], value: &[u8]) -> Vec<u8> {
+    endian_element(tag, vr, value, true)
+}
+
+/// Implicit VR little endian element.
+pub fn implicit_element(tag: u32, value: &[u8]) -> Vec<u8> {
+    let mut bytes = tag_bytes(tag);
+    bytes.extend_from_slice(&(value.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(value);
+    bytes
+}
+
+/// Sequence of undefined length holding items of undefined length.
+pub fn sequence(tag: u32, items: &[Vec<u8>]) -> Vec<u8> {
+    endian_sequence(tag, items, false)
+}
+
+/// Explicit VR big endian [`sequence`].
+pub fn big_endian_sequence(tag: u32, items: &[Vec<u8>]) -> Vec<u8> {
+    endian_sequence(tag, items, true)
+}
+
+/// Implicit VR [`sequence`].
+pub fn implicit_sequence(tag: u32, items: &[Vec<u8>]) -> Vec<u8> {
+    implicit_sequence_of_items(tag, &undefined_length_items(items), false)
+}
+
+/// Item of `content`, of defined length or ended by an item delimiter.
+pub fn item(content: &[u8], defined: bool) -> Vec<u8> {
+    endian_item(content, defined, false)
+}
+
+/// Encoded `items`, ended by a sequence delimiter unless `defined`.
+pub fn items(items: &[Vec<u8>], defined: bool) -> Vec<u8> {
+    endian_items(items, defined, false)
+}
+
+/// Explicit VR little endian sequence of encoded `items`, of defined
+/// length or ended by a sequence delimiter.
+pub fn sequence_of_items(tag: u32, items: &[Vec<u8>], defined: bool) -> Vec<u8> {
+    let content = self::items(items, defined);
+    let mut bytes = tag_bytes(tag);
+    bytes.extend_from_slice(b"SQ\0\0");
+    bytes.extend_from_slice(&length(&content, defined, false));
+    bytes.extend_from_slice(&content);
+    bytes
+}
+
+/// Implicit VR [`sequence_of_items`].
+pub fn implicit_sequence_of_items(tag: u32, items: &[Vec<u8>], defined: bool) -> Vec<u8> {
+    let content = self::items(items, defined);
+    let mut bytes = tag_bytes(tag);
+    bytes.extend_from_slice(&length(&content, defined, false));
+    bytes.extend_from_slice(&content);
+    bytes
+}
+
+fn endian_tag_bytes(tag: u32, big_endian: bool) -> Vec<u8> {
+    let (group, element) = ((tag >> 16) as u16, tag as u16);
+    match big_endian {
+        true => [group.to_be_bytes(), element.to_be_bytes()].concat(),
+        false => [group.to_le_bytes(), element.to_le_bytes()].concat(),
+    }
+}
+
+fn endian_element(tag: u32, vr: &[u8; 2], value: &[u8], big_endian: bool) -> Vec<u8> {
+    let mut bytes = endian_tag_bytes(tag, big_endian);
+    bytes.extend_from_slice(vr);
+    match vr {
+        b"OB" | b"OD" | b"OF" | b"OL" | b"OV" | b"OW" | b"SQ" | b"SV" | b"UC" | b"UN" | b"UR"
+        | b"UT" | b"UV" => {
+            bytes.extend_from_slice(&[0, 0]);
+            bytes.extend_from_slice(&length(value, true, big_endian));
+        }
+        _ => {
+            let length = value.len() as u16;
+            bytes.extend_from_slice(&match big_endian {
+                true => length.to_be_bytes(),
+                false => length.to_le_bytes(),
+            });
+        }
+    }
+    bytes.extend_from_slice(value);
+    bytes
+}
+
+fn endian_sequence(tag: u32, items: &[Vec<u8>], big_endian: bool) -> Vec<u8> {
+    let items = items
+        .iter()
+        .map(|item| endian_item(item, false, big_endian))
+        .collect::<Vec<_>>();
+    let mut bytes = endian_tag_bytes(tag, big_endian);
+    bytes.extend_from_slice(b"SQ\0\0\xFF\xFF\xFF\xFF");
+    bytes.extend_from_slice(&endian_items(&items, false, big_endian));
+    bytes
+}
+
+fn undefined_length_items(items: &[Vec<u8>]) -> Vec<Vec<u8>> {
+    items.iter().map(|content| item(content, false)).collect()
+}
+
+fn endian_item(content: &[u8], defined: bool, big_endian: bool) -> Vec<u8> {
+    let mut bytes = endian_tag_bytes(0xFFFEE000, big_endian);
+    bytes.extend_from_slice(&length(content, defined, big_endian));
+    bytes.extend_from_slice(content);
+    if !defined {
+        bytes.extend_from_slice(&endian_tag_bytes(0xFFFEE00D, big_endian));
+        bytes.extend_from_slice(&[0; 4]);
+    }
+    bytes
+}
+
+fn endian_items(items: &[Vec<u8>], defined: bool, big_endian: bool) -> Vec<u8> {
+    let mut bytes = items.concat();
+    if !defined {
+        bytes.extend_from_slice(&endian_tag_bytes(0xFFFEE0DD, big_endian));
+        bytes.extend_from_slice(&[0; 4]);
+    }
+    bytes
+}
+
+/// 4 byte length of `content`, undefined unless `defined`.
+fn length(content: &[u8], defined: bool, big_endian: bool) -> [u8; 4] {
+    let length = match defined {
+        true => content.len() as u32,
+        false => u32::MAX,
+    };
+    match big_endian {
+        true => length.to_be_bytes(),
+        false => length.to_le_bytes(),
+    }
+}
+
+/// Map of explicit VR little endian `bytes`, without file meta information.
+pub fn kvmap(bytes: &[u8]) -> KVMap {
+    let mut kvmap = KVMap::new();
+    kvmap.deserialize_bytes(bytes, &Filter::none()).unwrap();
+    kvmap
+}
+
+/// Bytes of the sr.dcm sample, a structured report.
+pub fn sr_bytes() -> Vec<u8> {
+    std::fs::read(concat!(env!("CARGO_MANIFEST_DIR"), "/../sr.dcm")).unwrap()
+}
+
+/// Map of the sr.dcm sample.
+pub fn sr() -> KVMap {
+    kvmap(&sr_bytes())
+}
+
+/// File in `format` of the records `write` writes.
+pub fn dckv_file<F>(format: DCKVFormat, write: F) -> Vec<u8>
+where
+    F: FnOnce(&mut DCKVWriter<Vec<u8>>) -> Result<()>,
+{
+    let mut writer = DCKVWriter::new(vec![], format).unwrap();
+    write(&mut writer).unwrap();
+    writer.finish().unwrap()
+}
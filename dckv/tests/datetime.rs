@@ -0,0 +1,193 @@
+//! DA, TM and DT values of partial precision, their ranges, offsets and
+//! leap seconds.
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime, Timelike};
+
+use dckv::{DCKVError, Value};
+
+fn value(text: &str) -> Value {
+    Value::new(text.as_bytes().to_vec())
+}
+
+fn time(hour: u32, minute: u32, second: u32, micro: u32) -> NaiveTime {
+    NaiveTime::from_hms_micro_opt(hour, minute, second, micro).unwrap()
+}
+
+fn datetime(text: &str) -> DateTime<FixedOffset> {
+    DateTime::parse_from_rfc3339(text).unwrap()
+}
+
+/// Start and end of the TM range of `text`.
+fn time_range(text: &str) -> (Option<NaiveTime>, Option<NaiveTime>) {
+    let range = value(text).to_time_range().unwrap();
+    (range.start(), range.end())
+}
+
+/// Start and end of the DT range of `text`, in UTC by default.
+fn datetime_range(text: &str) -> (Option<DateTime<FixedOffset>>, Option<DateTime<FixedOffset>>) {
+    let range = value(text).to_datetime_range(None).unwrap();
+    (range.start(), range.end())
+}
+
+#[test]
+fn partial_dates_and_times_stand_for_their_whole_period() {
+    assert_eq!(
+        value("20240229").to_date().unwrap(),
+        NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()
+    );
+    assert_eq!(
+        value("2024.02.29").to_date().unwrap(),
+        NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()
+    );
+    for invalid in ["20230229", "202402", "2024-02-29"] {
+        assert!(value(invalid).to_date().is_err(), "{invalid}");
+    }
+
+    assert_eq!(value("10").to_time().unwrap(), time(10, 0, 0, 0));
+    assert_eq!(
+        value("10:30:15.5").to_time().unwrap(),
+        time(10, 30, 15, 500_000)
+    );
+    assert_eq!(
+        time_range("10"),
+        (Some(time(10, 0, 0, 0)), Some(time(10, 59, 59, 999_999)))
+    );
+    assert_eq!(
+        time_range("1030"),
+        (Some(time(10, 30, 0, 0)), Some(time(10, 30, 59, 999_999)))
+    );
+    for invalid in ["1", "103", "1030.5", "103015.1234567"] {
+        assert!(value(invalid).to_time().is_err(), "{invalid}");
+    }
+
+    assert_eq!(
+        datetime_range("2024"),
+        (
+            Some(datetime("2024-01-01T00:00:00Z")),
+            Some(datetime("2024-12-31T23:59:59.999999Z"))
+        )
+    );
+    assert_eq!(
+        datetime_range("20230210"),
+        (
+            Some(datetime("2023-02-10T00:00:00Z")),
+            Some(datetime("2023-02-10T23:59:59.999999Z"))
+        )
+    );
+}
+
+#[test]
+fn fractions_end_with_their_last_microsecond() {
+    // A time to the second ends with its last microsecond.
+    assert_eq!(
+        time_range("103015"),
+        (Some(time(10, 30, 15, 0)), Some(time(10, 30, 15, 999_999)))
+    );
+    // A fraction of 1 to 5 digits stands for the microseconds it starts.
+    assert_eq!(
+        time_range("103015.5"),
+        (
+            Some(time(10, 30, 15, 500_000)),
+            Some(time(10, 30, 15, 599_999))
+        )
+    );
+    assert_eq!(
+        time_range("103015.12345"),
+        (
+            Some(time(10, 30, 15, 123_450)),
+            Some(time(10, 30, 15, 123_459))
+        )
+    );
+    assert_eq!(
+        time_range("103015.123456"),
+        (
+            Some(time(10, 30, 15, 123_456)),
+            Some(time(10, 30, 15, 123_456))
+        )
+    );
+    assert_eq!(
+        datetime_range("20240101103015.5"),
+        (
+            Some(datetime("2024-01-01T10:30:15.5Z")),
+            Some(datetime("2024-01-01T10:30:15.599999Z"))
+        )
+    );
+}
+
+#[test]
+fn ranges_may_miss_a_bound() {
+    assert_eq!(
+        time_range("10-1130"),
+        (Some(time(10, 0, 0, 0)), Some(time(11, 30, 59, 999_999)))
+    );
+    assert_eq!(time_range("-10"), (None, Some(time(10, 59, 59, 999_999))));
+    assert_eq!(time_range("10-"), (Some(time(10, 0, 0, 0)), None));
+    assert!(value("-").to_time_range().is_err());
+
+    let range = value("20240101-20240131").to_date_range().unwrap();
+    assert!(range.contains(&NaiveDate::from_ymd_opt(2024, 1, 31).unwrap()));
+    assert!(!range.contains(&NaiveDate::from_ymd_opt(2024, 2, 1).unwrap()));
+
+    // The offset of a single DT is not mistaken for a range.
+    assert_eq!(
+        datetime_range("2024-0500"),
+        (
+            Some(datetime("2024-01-01T00:00:00-05:00")),
+            Some(datetime("2024-12-31T23:59:59.999999-05:00"))
+        )
+    );
+    assert_eq!(
+        datetime_range("202401-202402"),
+        (
+            Some(datetime("2024-01-01T00:00:00Z")),
+            Some(datetime("2024-02-29T23:59:59.999999Z"))
+        )
+    );
+}
+
+#[test]
+fn offsets_range_from_minus_12_to_plus_14_hours() {
+    let east = |hours: i32, minutes: i32| FixedOffset::east_opt(hours * 3600 + minutes * 60);
+    assert_eq!(value("+1400").to_offset().ok(), east(14, 0));
+    assert_eq!(value("-1200").to_offset().ok(), east(-12, 0));
+    assert_eq!(value("+0530").to_offset().ok(), east(5, 30));
+    assert_eq!(value("-0330").to_offset().ok(), east(-3, -30));
+    for invalid in ["+1401", "-1201", "-1400", "+0560", "0100", "+01:00"] {
+        assert!(
+            matches!(
+                value(invalid).to_offset(),
+                Err(DCKVError::InvalidDateTime(_))
+            ),
+            "{invalid}"
+        );
+    }
+
+    // A DT is in its own offset, or else in the given one.
+    let tokyo = east(9, 0);
+    assert_eq!(
+        value("20240101120000").to_datetime(tokyo).unwrap(),
+        datetime("2024-01-01T12:00:00+09:00")
+    );
+    assert_eq!(
+        value("20240101120000+0100").to_datetime(tokyo).unwrap(),
+        datetime("2024-01-01T12:00:00+01:00")
+    );
+    assert!(value("20240101120000-1300").to_datetime(None).is_err());
+}
+
+#[test]
+fn leap_seconds_are_the_60th_second_of_their_minute() {
+    let leap = value("235960").to_time().unwrap();
+    assert_eq!((leap.hour(), leap.minute(), leap.second()), (23, 59, 59));
+    assert_eq!(leap.nanosecond(), 1_000_000_000);
+    assert!(time(23, 59, 59, 999_999) < leap);
+
+    assert_eq!(
+        time_range("235960.5").1.unwrap(),
+        time(23, 59, 59, 1_599_999)
+    );
+
+    let leap = value("20161231235960+0000").to_datetime(None).unwrap();
+    assert!(datetime("2016-12-31T23:59:59.999999Z") < leap);
+    assert!(leap < datetime("2017-01-01T00:00:00Z"));
+}
@@ -0,0 +1,83 @@
+//! DCKV files: their header, and records read back as written.
+
+mod common;
+
+use common::{dckv_file, sr};
+use dckv::{DCKVError, DCKVFormat, DCKVReader, KVMap};
+
+/// Header of a DCKV file, then `records`.
+fn file(records: &[u8]) -> Vec<u8> {
+    [b"DCKV\x01\x00\x00\x00".as_slice(), records].concat()
+}
+
+#[test]
+fn dckv_files_round_trip() {
+    let kvmap = sr();
+    let file = dckv_file(DCKVFormat::Dckv, |writer| kvmap.write_dckv(writer));
+
+    let reader = DCKVReader::new(file.as_slice()).unwrap();
+    assert_eq!(reader.format(), DCKVFormat::Dckv);
+    assert_eq!(KVMap::read_dckv(reader).unwrap(), kvmap);
+
+    // A file may only end between records.
+    let reader = DCKVReader::new(&file[..file.len() - 1]).unwrap();
+    assert!(KVMap::read_dckv(reader).is_err());
+
+    assert!(matches!(
+        DCKVReader::new(&b"DCKV\x02\x00\x00\x00"[..]),
+        Err(DCKVError::UnsupportedDCKVVersion(2))
+    ));
+}
+
+#[test]
+fn records_are_bounded_by_the_rest_of_the_file() {
+    let key = 0x00100010504E0000u64.to_be_bytes();
+    let record = |key_length: u32, value_length: u32| {
+        let lengths = [key_length.to_le_bytes(), value_length.to_le_bytes()].concat();
+        file(&[lengths.as_slice(), &key, b"Doe^John"].concat())
+    };
+
+    let records = |bytes: Vec<u8>| {
+        DCKVReader::new(bytes.as_slice())
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+    };
+    let read = records(record(8, 8)).unwrap();
+    assert_eq!(read[0].0.to_string(), "00100010:PN");
+    assert_eq!(read[0].1.bytes(), b"Doe^John");
+
+    // Lengths over the rest of the file, up to 4 GiB, are not allocated.
+    for (key_length, value_length) in [(8, u32::MAX), (u32::MAX - 7, 8), (8, 9)] {
+        assert!(matches!(
+            records(record(key_length, value_length)),
+            Err(DCKVError::InvalidDCKVRecord)
+        ));
+    }
+    // Keys are whole blocks.
+    for key_length in [0, 4] {
+        assert!(matches!(
+            records(record(key_length, 0)),
+            Err(DCKVError::InvalidDCKVRecord)
+        ));
+    }
+    // Reading stops at the first error.
+    let bytes = record(8, 4);
+    let mut reader = DCKVReader::new(bytes.as_slice()).unwrap();
+    assert!(matches!(reader.next(), Some(Ok(_))));
+    assert!(matches!(
+        reader.next(),
+        Some(Err(DCKVError::InvalidDCKVRecord))
+    ));
+    assert!(reader.next().is_none());
+}
+
+#[test]
+fn readers_reject_files_of_the_other_format() {
+    let kvmap = sr();
+    let file = dckv_file(DCKVFormat::Dckv, |writer| kvmap.write_dckv(writer));
+
+    assert!(matches!(
+        DCKVReader::edckv(file.as_slice()),
+        Err(DCKVError::UnexpectedDCKVFormat(DCKVFormat::Dckv))
+    ));
+}
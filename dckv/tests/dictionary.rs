@@ -0,0 +1,60 @@
+//! Lookups in the standard data dictionary (PS3.6).
+
+use dckv::Dictionary;
+
+#[test]
+fn registry_holds_elements_of_every_module() {
+    for (tag, keyword, vr) in [
+        (0x00020010, "TransferSyntaxUID", "UI"),
+        (0x00041220, "DirectoryRecordSequence", "SQ"),
+        (0x00089215, "DerivationCodeSequence", "SQ"),
+        (0x00100010, "PatientName", "PN"),
+        (0x00120062, "PatientIdentityRemoved", "CS"),
+        (0x00189117, "MRDiffusionSequence", "SQ"),
+        (0x00189087, "DiffusionBValue", "FD"),
+        (0x00209165, "DimensionIndexPointer", "AT"),
+        (0x00281201, "RedPaletteColorLookupTableData", "OW"),
+        (0x0040A730, "ContentSequence", "SQ"),
+        (0x00540016, "RadiopharmaceuticalInformationSequence", "SQ"),
+        (0x00620002, "SegmentSequence", "SQ"),
+        (0x00700001, "GraphicAnnotationSequence", "SQ"),
+        (0x30060020, "StructureSetROISequence", "SQ"),
+        (0x300A00B0, "BeamSequence", "SQ"),
+        (0x52009229, "SharedFunctionalGroupsSequence", "SQ"),
+        (0x52009230, "PerFrameFunctionalGroupsSequence", "SQ"),
+        (0x54000100, "WaveformSequence", "SQ"),
+        (0x7FE00001, "ExtendedOffsetTable", "OV"),
+    ] {
+        let entry = Dictionary::get(tag).unwrap_or_else(|| panic!("{tag:08X} is missing"));
+        assert_eq!((entry.keyword(), entry.vr()), (keyword, vr));
+        assert_eq!(Dictionary::by_keyword(keyword), Some(entry));
+    }
+}
+
+#[test]
+fn retired_elements_are_flagged() {
+    assert!(Dictionary::get(0x00081000).unwrap().is_retired());
+    assert!(!Dictionary::get(0x00080018).unwrap().is_retired());
+}
+
+#[test]
+fn repeating_groups_only_match_even_groups() {
+    let overlay_data = Dictionary::get(0x60023000).unwrap();
+    assert_eq!(overlay_data.keyword(), "OverlayData");
+    assert_eq!(overlay_data.tag(), 0x60003000);
+    assert_eq!(Dictionary::get(0x601E0010).unwrap().keyword(), "OverlayRows");
+    assert_eq!(Dictionary::get(0x50100005).unwrap().keyword(), "CurveDimensions");
+
+    // Odd groups are private.
+    assert_eq!(Dictionary::get(0x60013000), None);
+    assert_eq!(Dictionary::get(0x60010010), None);
+    assert_eq!(Dictionary::get(0x50010005), None);
+}
+
+#[test]
+fn registry_entries_are_found_by_tag_and_keyword() {
+    for entry in Dictionary::entries() {
+        assert_eq!(Dictionary::get(entry.tag()), Some(entry), "{:08X}", entry.tag());
+        assert_eq!(Dictionary::by_keyword(entry.keyword()), Some(entry), "{}", entry.keyword());
+    }
+}
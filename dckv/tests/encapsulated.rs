@@ -0,0 +1,152 @@
+//! Encapsulated pixel data: fragments, offset tables and frames (PS3.5
+//! A.4).
+
+mod common;
+
+use std::io::Cursor;
+
+use common::{element, item, items, tag_bytes};
+use dckv::{BulkDataPolicy, DCKVError, Filter, KVMap, SyncDeserializer};
+
+const PIXEL_DATA: u32 = 0x7FE00010;
+
+fn number_of_frames(frames: usize) -> Vec<u8> {
+    element(0x00280008, b"IS", format!("{frames:<2}").as_bytes())
+}
+
+/// Encapsulated pixel data of `vr`, its first fragment being the Basic
+/// Offset Table.
+fn pixel_data(vr: &[u8; 2], offsets: &[u32], fragments: &[&[u8]]) -> Vec<u8> {
+    let table = offsets
+        .iter()
+        .flat_map(|offset| offset.to_le_bytes())
+        .collect::<Vec<u8>>();
+    let fragments = [table.as_slice()]
+        .iter()
+        .chain(fragments)
+        .map(|fragment| item(fragment, true))
+        .collect::<Vec<_>>();
+
+    let mut bytes = tag_bytes(PIXEL_DATA);
+    bytes.extend_from_slice(vr);
+    bytes.extend_from_slice(&[0, 0, 0xFF, 0xFF, 0xFF, 0xFF]);
+    bytes.extend_from_slice(&items(&fragments, false));
+    bytes
+}
+
+fn parse(bytes: Vec<u8>) -> KVMap {
+    let mut kvmap = KVMap::new();
+    SyncDeserializer::deserialize(&mut kvmap, Cursor::new(bytes), &Filter::none()).unwrap();
+    kvmap
+}
+
+fn frames(kvmap: &KVMap) -> Vec<Vec<u8>> {
+    (0..)
+        .map_while(|index| kvmap.frame(index).unwrap())
+        .collect()
+}
+
+#[test]
+fn empty_basic_offset_tables_hold_a_frame_per_fragment() {
+    let kvmap = parse(
+        [
+            number_of_frames(2),
+            pixel_data(b"OB", &[], &[&[1, 2], &[3, 4]]),
+        ]
+        .concat(),
+    );
+    assert_eq!(frames(&kvmap), [vec![1, 2], vec![3, 4]]);
+
+    // A single frame spans every fragment.
+    let kvmap = parse(pixel_data(b"OB", &[], &[&[1, 2], &[3, 4]]));
+    assert_eq!(frames(&kvmap), [vec![1, 2, 3, 4]]);
+}
+
+#[test]
+fn basic_offset_tables_gather_the_fragments_of_a_frame() {
+    // Offsets count the 8 bytes of the item tag and length of every
+    // fragment.
+    let kvmap = parse(
+        [
+            number_of_frames(2),
+            pixel_data(b"OB", &[0, 20], &[&[1, 2], &[3, 4, 5, 6], &[7, 8]]),
+        ]
+        .concat(),
+    );
+
+    assert_eq!(kvmap.fragment(0).unwrap(), &[0, 0, 0, 0, 20, 0, 0, 0]);
+    assert_eq!(kvmap.fragment(3).unwrap(), &[7, 8]);
+    assert_eq!(kvmap.fragment(4), None);
+    assert_eq!(frames(&kvmap), [vec![1, 2, 3, 4, 5, 6], vec![7, 8]]);
+}
+
+#[test]
+fn ow_pixel_data_is_found_as_well() {
+    let kvmap = parse(
+        [
+            number_of_frames(2),
+            pixel_data(b"OW", &[0, 10], &[&[1, 2], &[3, 4]]),
+        ]
+        .concat(),
+    );
+
+    assert_eq!(kvmap.fragment(1).unwrap(), &[1, 2]);
+    assert_eq!(frames(&kvmap), [vec![1, 2], vec![3, 4]]);
+}
+
+#[test]
+fn extended_offset_tables_take_precedence() {
+    let table = |numbers: &[u64]| {
+        numbers
+            .iter()
+            .flat_map(|number| number.to_le_bytes())
+            .collect::<Vec<u8>>()
+    };
+    // The last fragment of each frame is padded to an even length.
+    let kvmap = parse(
+        [
+            number_of_frames(2),
+            element(0x7FE00001, b"OV", &table(&[0, 20])),
+            element(0x7FE00002, b"OV", &table(&[5, 3])),
+            pixel_data(b"OB", &[], &[&[1, 2], &[3, 4, 5, 0], &[6, 7, 8, 0]]),
+        ]
+        .concat(),
+    );
+
+    assert_eq!(frames(&kvmap), [vec![1, 2, 3, 4, 5], vec![6, 7, 8]]);
+}
+
+#[test]
+fn frames_need_fragments() {
+    assert_eq!(parse(number_of_frames(1)).frame(0).unwrap(), None);
+
+    // Pixel data holding only its Basic Offset Table.
+    let kvmap = parse(pixel_data(b"OB", &[], &[]));
+    assert_eq!(kvmap.fragment(0).unwrap(), &[]);
+    assert_eq!(kvmap.frame(0).unwrap(), None);
+}
+
+#[test]
+fn deferred_fragments_are_fetched_first() {
+    let bytes = [
+        number_of_frames(2),
+        pixel_data(b"OB", &[], &[&[1, 2], &[3, 4]]),
+    ]
+    .concat();
+    let mut kvmap = KVMap::with_bulk_data(BulkDataPolicy::new().vr("OB"));
+    SyncDeserializer::deserialize(&mut kvmap, Cursor::new(bytes), &Filter::none()).unwrap();
+
+    assert_eq!(kvmap.fragment(1), None);
+    assert!(matches!(kvmap.frame(1), Err(DCKVError::DeferredValue)));
+}
+
+#[test]
+fn only_sequences_and_encapsulated_values_have_an_undefined_length() {
+    let mut bytes = tag_bytes(0x0040A160);
+    bytes.extend_from_slice(b"UT\0\0\xFF\xFF\xFF\xFF");
+    bytes.extend_from_slice(b"Text");
+
+    let mut kvmap = KVMap::new();
+    let result = SyncDeserializer::deserialize(&mut kvmap, Cursor::new(bytes), &Filter::none());
+    assert!(matches!(result, Err(DCKVError::UndefinedValueLength(vr)) if vr == "UT"));
+}
@@ -0,0 +1,182 @@
+//! Elements kept or skipped by the filter modes, at every nesting level.
+
+mod common;
+
+use std::io::Cursor;
+
+use common::{element, sequence};
+use dckv::{Filter, KVMap, KeyKind, SyncDeserializer};
+
+/// Patient elements around the edges of (0010,0010)-(0010,0020), and an SR
+/// content tree nesting some of them.
+fn dataset() -> Vec<u8> {
+    [
+        element(0x00080060, b"CS", b"SR"),
+        element(0x0010000F, b"LO", b"Before"),
+        element(0x00100010, b"PN", b"Doe^John"),
+        element(0x00100020, b"LO", b"ID"),
+        element(0x00100021, b"LO", b"Issuer"),
+        sequence(
+            0x0040A730,
+            &[[
+                element(0x00100020, b"LO", b"Nested ID"),
+                element(0x0040A040, b"CS", b"CONTAINER"),
+                sequence(0x0040A730, &[element(0x0040A040, b"CS", b"TEXT")]),
+            ]
+            .concat()],
+        ),
+    ]
+    .concat()
+}
+
+/// Textual keys of the elements kept by `filter`.
+fn kept(filter: &Filter) -> Vec<String> {
+    let mut kvmap = KVMap::new();
+    SyncDeserializer::deserialize(&mut kvmap, Cursor::new(dataset()), filter).unwrap();
+
+    kvmap
+        .range(..)
+        .filter(|(key, _)| matches!(key.kind(), KeyKind::Element | KeyKind::SequenceStart))
+        .map(|(key, _)| key.to_string())
+        .collect()
+}
+
+#[test]
+fn no_filter_keeps_everything() {
+    assert_eq!(
+        kept(&Filter::none()),
+        [
+            "00080060:CS",
+            "0010000F:LO",
+            "00100010:PN",
+            "00100020:LO",
+            "00100021:LO",
+            "0040A730:SQ",
+            "0040A730[1].00100020:LO",
+            "0040A730[1].0040A040:CS",
+            "0040A730[1].0040A730:SQ",
+            "0040A730[1].0040A730[1].0040A040:CS",
+        ]
+    );
+}
+
+#[test]
+fn whitelisted_tags_are_kept_within_whitelisted_sequences_only() {
+    assert_eq!(
+        kept(&Filter::whitelist().tag(0x00100020).tag(0x0040A040)),
+        ["00100020:LO"]
+    );
+
+    // Whitelisting the sequence looks into its items, at every level.
+    assert_eq!(
+        kept(&Filter::whitelist().tags([0x00100020, 0x0040A040, 0x0040A730])),
+        [
+            "00100020:LO",
+            "0040A730:SQ",
+            "0040A730[1].00100020:LO",
+            "0040A730[1].0040A040:CS",
+            "0040A730[1].0040A730:SQ",
+            "0040A730[1].0040A730[1].0040A040:CS",
+        ]
+    );
+}
+
+#[test]
+fn blacklisted_tags_are_skipped_at_every_level() {
+    assert_eq!(
+        kept(&Filter::blacklist().tag(0x00100020).tag(0x0040A040)),
+        [
+            "00080060:CS",
+            "0010000F:LO",
+            "00100010:PN",
+            "00100021:LO",
+            "0040A730:SQ",
+            "0040A730[1].0040A730:SQ",
+        ]
+    );
+}
+
+#[test]
+fn ranges_include_both_of_their_ends() {
+    assert_eq!(
+        kept(&Filter::whitelist().range(0x00100010..=0x00100020)),
+        ["00100010:PN", "00100020:LO"]
+    );
+    assert_eq!(
+        kept(&Filter::blacklist().range(0x00100010..=0x00100020)),
+        [
+            "00080060:CS",
+            "0010000F:LO",
+            "00100021:LO",
+            "0040A730:SQ",
+            "0040A730[1].0040A040:CS",
+            "0040A730[1].0040A730:SQ",
+            "0040A730[1].0040A730[1].0040A040:CS",
+        ]
+    );
+}
+
+#[test]
+fn groups_match_all_of_their_elements() {
+    assert_eq!(
+        kept(&Filter::whitelist().group(0x0010)),
+        ["0010000F:LO", "00100010:PN", "00100020:LO", "00100021:LO"]
+    );
+    assert_eq!(
+        kept(&Filter::blacklist().group(0x0040).group(0x0010)),
+        ["00080060:CS"]
+    );
+}
+
+#[test]
+fn sequences_match_their_whole_subtree() {
+    assert_eq!(
+        kept(&Filter::whitelist().sequence(0x0040A730)),
+        [
+            "0040A730:SQ",
+            "0040A730[1].00100020:LO",
+            "0040A730[1].0040A040:CS",
+            "0040A730[1].0040A730:SQ",
+            "0040A730[1].0040A730[1].0040A040:CS",
+        ]
+    );
+    assert_eq!(
+        kept(&Filter::blacklist().sequence(0x0040A730)),
+        [
+            "00080060:CS",
+            "0010000F:LO",
+            "00100010:PN",
+            "00100020:LO",
+            "00100021:LO",
+        ]
+    );
+}
+
+#[test]
+fn paths_match_their_position_only() {
+    let path = "(0040,A730)[*].(0040,A730)[1].(0040,A040)".parse().unwrap();
+    assert_eq!(
+        kept(&Filter::whitelist().path(path)),
+        [
+            "0040A730:SQ",
+            "0040A730[1].0040A730:SQ",
+            "0040A730[1].0040A730[1].0040A040:CS",
+        ]
+    );
+
+    let path = "(0040,A730)[1].(0010,0020)".parse().unwrap();
+    assert_eq!(
+        kept(&Filter::blacklist().path(path)),
+        [
+            "00080060:CS",
+            "0010000F:LO",
+            "00100010:PN",
+            "00100020:LO",
+            "00100021:LO",
+            "0040A730:SQ",
+            "0040A730[1].0040A040:CS",
+            "0040A730[1].0040A730:SQ",
+            "0040A730[1].0040A730[1].0040A040:CS",
+        ]
+    );
+}
@@ -0,0 +1,124 @@
+//! DICOM JSON model of KVMaps.
+
+mod common;
+
+use std::io::Cursor;
+
+use common::element;
+use dckv::{BulkDataPolicy, DCKVError, Filter, KVMap, SyncDeserializer, Value};
+
+fn kvmap(entries: &[(&str, &[u8])]) -> KVMap {
+    let mut kvmap = KVMap::new();
+    for (key, value) in entries {
+        kvmap.insert(key.parse().unwrap(), Value::new(value.to_vec()));
+    }
+    kvmap
+}
+
+#[test]
+fn kvmaps_encode_to_dicom_json() {
+    let mut kvmap = kvmap(&[
+        ("ImageType", b"ORIGINAL\\\\AXIAL "),
+        ("PatientID", b""),
+        ("PixelSpacing", b"0.5\\.25"),
+        ("Rows", &512u16.to_le_bytes()),
+        ("7FE00010:OB", b""),
+        ("7FE00010:OB#1", &[1, 2, 3, 4]),
+        ("00291010:OB", &[0xFF, 0x00]),
+    ]);
+
+    let json = kvmap
+        .to_json(|key| Some(key.to_string()).filter(|_| key.group() == 0x7FE0))
+        .unwrap();
+    assert_eq!(
+        json,
+        concat!(
+            r#"{"00080008":{"vr":"CS","Value":["ORIGINAL",null,"AXIAL"]},"#,
+            r#""00100020":{"vr":"LO"},"#,
+            r#""00280010":{"vr":"US","Value":[512]},"#,
+            r#""00280030":{"vr":"DS","Value":[0.5,0.25]},"#,
+            r#""00291010":{"vr":"OB","InlineBinary":"/wA="},"#,
+            r#""7FE00010":{"vr":"OB","BulkDataURI":"7FE00010:OB"}}"#,
+        )
+    );
+
+    // Malformed numbers are kept as text, the rest being encoded.
+    kvmap.insert(
+        "SliceThickness".parse().unwrap(),
+        Value::new(b"NaN".to_vec()),
+    );
+    kvmap.insert(
+        "InstanceNumber".parse().unwrap(),
+        Value::new(b"1\\2.5".to_vec()),
+    );
+    kvmap.insert(
+        "PixelSpacing".parse().unwrap(),
+        Value::new(b"0,5\\0.5".to_vec()),
+    );
+    let json = kvmap.to_json(|key| Some(key.to_string())).unwrap();
+    for attribute in [
+        r#""00180050":{"vr":"DS","Value":["NaN"]}"#,
+        r#""00200013":{"vr":"IS","Value":[1,"2.5"]}"#,
+        r#""00280030":{"vr":"DS","Value":["0,5",0.5]}"#,
+        r#""00280010":{"vr":"US","Value":[512]}"#,
+    ] {
+        assert!(json.contains(attribute), "{attribute}");
+    }
+}
+
+#[test]
+fn file_meta_information_is_left_out() {
+    let kvmap = kvmap(&[
+        ("00020010:UI", b"1.2.840.10008.1.2.1\0"),
+        ("PatientID", b"ID"),
+    ]);
+
+    assert_eq!(
+        kvmap.to_json(|_| None).unwrap(),
+        r#"{"00100020":{"vr":"LO","Value":["ID"]}}"#
+    );
+}
+
+#[test]
+fn decimal_and_integer_strings_keep_their_digits() {
+    let kvmap = kvmap(&[
+        ("SliceThickness", b"1.10"),
+        (
+            "PixelSpacing",
+            b"0.1000000000000000055511151231257827\\1E-3 ",
+        ),
+        ("InstanceNumber", b"+0012"),
+        ("SeriesNumber", b"-7 "),
+    ]);
+
+    assert_eq!(
+        kvmap.to_json(|_| None).unwrap(),
+        concat!(
+            r#"{"00180050":{"vr":"DS","Value":[1.10]},"#,
+            r#""00200011":{"vr":"IS","Value":[-7]},"#,
+            r#""00200013":{"vr":"IS","Value":[12]},"#,
+            r#""00280030":{"vr":"DS","Value":[0.1000000000000000055511151231257827,1E-3]}}"#,
+        )
+    );
+}
+
+#[test]
+fn deferred_and_encapsulated_values_need_a_bulk_data_uri() {
+    let encapsulated = kvmap(&[("7FE00010:OB", b""), ("7FE00010:OB#1", &[1, 2])]);
+    assert!(matches!(
+        encapsulated.to_json(|_| None),
+        Err(DCKVError::MissingBulkDataURI(key)) if key == "7FE00010:OB"
+    ));
+
+    let bytes = element(0x00100010, b"PN", b"Doe^John");
+    let mut deferred = KVMap::with_bulk_data(BulkDataPolicy::new().vr("PN"));
+    SyncDeserializer::deserialize(&mut deferred, Cursor::new(bytes), &Filter::none()).unwrap();
+    assert!(matches!(
+        deferred.to_json(|_| None),
+        Err(DCKVError::MissingBulkDataURI(key)) if key == "00100010:PN"
+    ));
+    assert_eq!(
+        deferred.to_json(|key| Some(key.to_string())).unwrap(),
+        r#"{"00100010":{"vr":"PN","BulkDataURI":"00100010:PN"}}"#
+    );
+}
@@ -0,0 +1,96 @@
+//! Keys: their textual form and what their blocks tell of the sequence
+//! tree.
+
+mod common;
+
+use bytes::Bytes;
+
+use common::{dckv_file, sr, sr_bytes};
+use dckv::{
+    BytesDeserializer, Control, DCKVError, DCKVFormat, DCKVReader, Filter, Key, KeyKind, KeyRef,
+};
+
+/// Keys of a parse, in order.
+#[derive(Clone, Default)]
+struct Keys(Vec<Key>);
+
+impl BytesDeserializer for Keys {
+    fn append(
+        &mut self,
+        key: KeyRef<'_>,
+        _value: Bytes,
+        _vr: Option<u16>,
+    ) -> Result<Control, DCKVError> {
+        self.0.push(key.to_key());
+        Ok(Control::Continue)
+    }
+}
+
+#[test]
+fn keys_round_trip_through_their_textual_form() {
+    let mut keys = Keys::default();
+    BytesDeserializer::deserialize(&mut keys, Bytes::from(sr_bytes()), &Filter::none()).unwrap();
+
+    assert!(keys
+        .0
+        .iter()
+        .any(|key| key.to_string().ends_with(":SQ.end")));
+    for key in &keys.0 {
+        let text = key.to_string();
+        assert_eq!(&text.parse::<Key>().unwrap(), key, "{text}");
+    }
+
+    let fragment = "PixelData:OB#3".parse::<Key>().unwrap();
+    assert_eq!(fragment.bytes(), 0x7FE000104F420003u64.to_be_bytes());
+    assert_eq!(fragment.to_string(), "7FE00010:OB#3");
+
+    let end = "ContentSequence[2].end".parse::<Key>().unwrap();
+    assert_eq!(
+        end.bytes(),
+        [0x0040A73000000002u64, 0xFFFFFFFF5F5F0000]
+            .iter()
+            .flat_map(|block| block.to_be_bytes())
+            .collect::<Vec<u8>>()
+    );
+    assert_eq!(end.to_string(), "0040A730[2].end");
+
+    assert!("0040A730.end".parse::<Key>().is_err());
+    assert!("00100010:pn".parse::<Key>().is_err());
+
+    // Items are numbered from 1 to 0xFFFF, below the sequence end block.
+    assert!("ContentSequence[65535].CodeValue".parse::<Key>().is_ok());
+    for number in [0, 65536, 0xFFFF0000u32] {
+        let text = format!("ContentSequence[{number}].CodeValue");
+        assert!(
+            matches!(text.parse::<Key>(), Err(DCKVError::InvalidKey(invalid)) if invalid == text),
+            "{text}"
+        );
+    }
+}
+
+#[test]
+fn key_kinds_rebuild_the_sequence_tree() {
+    let tag = |key: &Key| ((key.group() as u32) << 16) + key.element() as u32;
+
+    let kvmap = sr();
+    let file = dckv_file(DCKVFormat::Dckv, |writer| kvmap.write_dckv(writer));
+
+    // Open sequences, with the number of their last item.
+    let mut open = Vec::<(u32, u32)>::new();
+    for record in DCKVReader::new(file.as_slice()).unwrap() {
+        let (key, _) = record.unwrap();
+        match key.kind() {
+            KeyKind::SequenceStart => open.push((tag(&key), 0)),
+            KeyKind::ItemStart => {
+                let (sequence, last) = open.last_mut().unwrap();
+                assert_eq!(key.parents().last(), Some((*sequence, *last + 1)));
+                *last += 1;
+            }
+            KeyKind::SequenceEnd => assert_eq!(open.pop().map(|(tag, _)| tag), Some(tag(&key))),
+            KeyKind::Element | KeyKind::ItemEnd => {
+                assert_eq!(key.parents().collect::<Vec<_>>(), open)
+            }
+        }
+    }
+    assert!(open.is_empty());
+}
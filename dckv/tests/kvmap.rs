@@ -0,0 +1,96 @@
+//! KVMaps: navigation through sequences and items, and key order.
+
+mod common;
+
+use bytes::Bytes;
+
+use common::{dckv_file, sr, sr_bytes};
+use dckv::{
+    BulkDataPolicy, BytesDeserializer, Control, DCKVError, DCKVFormat, DCKVReader, Filter, KVMap,
+    KeyKind, KeyRef, SyncDeserializer, Value,
+};
+
+#[test]
+fn kvmaps_navigate_sequence_items() {
+    let kvmap = sr();
+
+    let (sequence, _) = kvmap.get_tag(0x0040A730).unwrap();
+    assert_eq!(sequence.kind(), KeyKind::SequenceStart);
+
+    let items = kvmap.items(&sequence.to_key()).collect::<Vec<_>>();
+    assert!(!items.is_empty());
+    for (number, item) in items.iter().enumerate() {
+        assert_eq!(item.item_number(), Some(number as u32 + 1));
+
+        let entries = kvmap.item_entries(&item.to_key()).collect::<Vec<_>>();
+        assert_eq!(entries.first().unwrap().0, *item);
+        assert_eq!(entries.last().unwrap().0.kind(), KeyKind::ItemEnd);
+    }
+
+    assert!(kvmap
+        .group(0x0040)
+        .all(|(key, _)| key.bytes().starts_with(&[0x00, 0x40])));
+    assert!(kvmap
+        .group(0x0040)
+        .any(|(key, _)| key.keyword() == Some("ValueType")));
+}
+
+#[test]
+fn kvmaps_keep_keys_in_dicom_order_whatever_the_insertion_order() {
+    #[derive(Clone, Default)]
+    struct Keys(Vec<Vec<u8>>);
+
+    impl BytesDeserializer for Keys {
+        fn append(
+            &mut self,
+            key: KeyRef<'_>,
+            _value: Bytes,
+            _vr: Option<u16>,
+        ) -> Result<Control, DCKVError> {
+            self.0.push(key.bytes().to_vec());
+            Ok(Control::Continue)
+        }
+    }
+
+    let kvmap = sr();
+    let file = dckv_file(DCKVFormat::Dckv, |writer| kvmap.write_dckv(writer));
+    let mut records = DCKVReader::new(file.as_slice())
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    // Parsed order is DICOM order.
+    let mut parsed = Keys::default();
+    BytesDeserializer::deserialize(&mut parsed, Bytes::from(sr_bytes()), &Filter::none()).unwrap();
+    let keys = records.iter().map(|(key, _)| key.bytes().to_vec());
+    assert!(keys.eq(parsed.0.iter().cloned()));
+
+    let (removed, _) = records.remove(records.len() / 2);
+    let mut reversed = KVMap::new();
+    for (key, value) in records.into_iter().rev() {
+        reversed.insert(key, value);
+    }
+
+    let diff = kvmap.diff(&reversed).collect::<Vec<_>>();
+    assert_eq!(diff.len(), 1);
+    assert_eq!(diff[0].0, removed.as_key_ref());
+    assert_eq!(diff[0].2, None);
+
+    reversed.merge(kvmap.clone());
+    assert_eq!(reversed, kvmap);
+}
+
+#[test]
+fn kvmaps_compare_their_keys_and_values_only() {
+    let mut bounded = KVMap::with_bulk_data(BulkDataPolicy::new().vr("OW")).with_max_depth(4);
+    bounded
+        .deserialize_bytes(&sr_bytes(), &Filter::none())
+        .unwrap();
+    assert_eq!(bounded, sr());
+
+    bounded.insert(
+        "00100010:PN".parse().unwrap(),
+        Value::new(b"Doe^John".to_vec()),
+    );
+    assert_ne!(bounded, sr());
+}
@@ -0,0 +1,46 @@
+//! Nesting: key blocks grow with every level, up to the maximum depth.
+
+mod common;
+
+use bytes::Bytes;
+use std::io::Cursor;
+
+use common::{element, sequence};
+use dckv::{BytesDeserializer, DCKVError, Deserializer, Filter, KVMap, SyncDeserializer};
+
+/// Code value nested in `depth` content sequences.
+fn nested(depth: usize) -> Vec<u8> {
+    (0..depth).fold(element(0x00080100, b"SH", b"121071"), |content, _| {
+        sequence(0x0040A730, &[content])
+    })
+}
+
+#[tokio::test]
+async fn nesting_is_limited_by_the_max_depth() {
+    let mut kvmap = KVMap::new();
+    kvmap
+        .deserialize_bytes(&nested(20), &Filter::none())
+        .unwrap();
+    let deepest = kvmap.range(..).map(|(key, _)| key.level()).max();
+    assert_eq!(deepest, Some(20));
+
+    let bytes = nested(5);
+    assert!(KVMap::new()
+        .with_max_depth(5)
+        .deserialize_bytes(&bytes, &Filter::none())
+        .is_ok());
+
+    let mut kvmap = KVMap::new().with_max_depth(4);
+    assert!(matches!(
+        Deserializer::deserialize(&mut kvmap, Cursor::new(bytes.clone()), &Filter::none()).await,
+        Err(DCKVError::TooManyNestedLevels(4))
+    ));
+    assert!(matches!(
+        kvmap.deserialize_bytes(&bytes, &Filter::none()),
+        Err(DCKVError::TooManyNestedLevels(4))
+    ));
+    assert!(matches!(
+        BytesDeserializer::deserialize(&mut kvmap, Bytes::from(bytes), &Filter::none()),
+        Err(DCKVError::TooManyNestedLevels(4))
+    ));
+}
@@ -0,0 +1,121 @@
+//! File meta information (PS3.10 7.1), with or without preamble and prefix.
+
+mod common;
+
+use std::io::{Cursor, Seek};
+
+use common::{element, implicit_element};
+use dckv::{FileMeta, Filter, KVMap, SyncDeserializer};
+
+const IMPLICIT_LITTLE_ENDIAN: &[u8] = b"1.2.840.10008.1.2\0";
+
+/// File meta elements led by their group length.
+fn meta_group(elements: &[Vec<u8>]) -> Vec<u8> {
+    let elements = elements.concat();
+    let length = (elements.len() as u32).to_le_bytes();
+    [element(0x00020000, b"UL", &length), elements].concat()
+}
+
+fn dataset() -> Vec<u8> {
+    implicit_element(0x00100010, b"Doe^John")
+}
+
+fn read(bytes: &[u8]) -> (Option<FileMeta>, KVMap) {
+    let mut reader = Cursor::new(bytes);
+    let meta = FileMeta::read_sync(&mut reader).unwrap();
+
+    let mut kvmap = KVMap::new();
+    let parsed =
+        SyncDeserializer::deserialize(&mut kvmap, Cursor::new(bytes), &Filter::none()).unwrap();
+    assert_eq!(parsed, meta);
+
+    (meta, kvmap)
+}
+
+#[tokio::test]
+async fn preamble_and_prefix_are_optional() {
+    let meta = meta_group(&[element(0x00020010, b"UI", IMPLICIT_LITTLE_ENDIAN)]);
+
+    for prefix in [
+        [vec![0; 128], b"DICM".to_vec()].concat(),
+        b"DICM".to_vec(),
+        vec![],
+    ] {
+        let bytes = [prefix, meta.clone(), dataset()].concat();
+
+        let (meta, kvmap) = read(&bytes);
+        assert_eq!(meta.unwrap().transfer_syntax_uid(), "1.2.840.10008.1.2");
+        assert_eq!(
+            kvmap.get("PatientName".parse().unwrap()).unwrap(),
+            b"Doe^John"
+        );
+
+        let mut reader = Cursor::new(bytes);
+        let meta = FileMeta::read(&mut reader).await.unwrap().unwrap();
+        assert_eq!(meta.transfer_syntax_uid(), "1.2.840.10008.1.2");
+    }
+}
+
+#[tokio::test]
+async fn bare_datasets_have_no_file_meta() {
+    let bytes = dataset();
+
+    let (meta, kvmap) = read(&bytes);
+    assert_eq!(meta, None);
+    assert_eq!(
+        kvmap.get("PatientName".parse().unwrap()).unwrap(),
+        b"Doe^John"
+    );
+
+    // The reader is left where the dataset starts.
+    let mut reader = Cursor::new(bytes);
+    assert_eq!(FileMeta::read(&mut reader).await.unwrap(), None);
+    assert_eq!(reader.stream_position().unwrap(), 0);
+}
+
+#[test]
+fn the_group_length_ends_the_file_meta() {
+    // The group length leaves out the last group 0002 element, which
+    // belongs to the dataset.
+    let bytes = [
+        meta_group(&[element(0x00020010, b"UI", IMPLICIT_LITTLE_ENDIAN)]),
+        implicit_element(0x00020013, b"DATASET "),
+        dataset(),
+    ]
+    .concat();
+
+    let (meta, kvmap) = read(&bytes);
+    let meta = meta.unwrap();
+    assert_eq!(meta.transfer_syntax_uid(), "1.2.840.10008.1.2");
+    assert_eq!(meta.implementation_version_name(), "");
+    assert!(kvmap.get("PatientName".parse().unwrap()).is_some());
+}
+
+#[test]
+fn another_group_ends_the_file_meta_without_group_length() {
+    let bytes = [
+        element(0x00020010, b"UI", IMPLICIT_LITTLE_ENDIAN),
+        element(0x00020013, b"SH", b"DCKV 1.0"),
+        dataset(),
+    ]
+    .concat();
+
+    let (meta, kvmap) = read(&bytes);
+    assert_eq!(meta.unwrap().implementation_version_name(), "DCKV 1.0");
+    assert!(kvmap.get("PatientName".parse().unwrap()).is_some());
+}
+
+#[test]
+fn invalid_utf8_is_replaced() {
+    let bytes = [
+        meta_group(&[
+            element(0x00020010, b"UI", IMPLICIT_LITTLE_ENDIAN),
+            element(0x00020013, b"SH", b"V\xE91.0 "),
+        ]),
+        dataset(),
+    ]
+    .concat();
+
+    let (meta, _) = read(&bytes);
+    assert_eq!(meta.unwrap().implementation_version_name(), "V\u{FFFD}1.0");
+}
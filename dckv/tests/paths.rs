@@ -0,0 +1,167 @@
+//! Tag paths: nibble wildcards, item selection and any depth segments, and
+//! the sequences a whitelist looks into for them.
+
+mod common;
+
+use std::io::Cursor;
+
+use common::{element, sequence};
+use dckv::{DCKVError, Filter, KVMap, KeyKind, SyncDeserializer, TagPath};
+
+const CODE_VALUE: u32 = 0x00080100;
+const CODE_MEANING: u32 = 0x00080104;
+const CONCEPT_NAME: u32 = 0x0040A043;
+const CONTENT: u32 = 0x0040A730;
+
+/// Code values at the top level, in the first content item and its concept
+/// name, and in a content item nested in the second one.
+fn dataset() -> Vec<u8> {
+    [
+        element(CODE_VALUE, b"SH", b"TOP"),
+        element(CODE_MEANING, b"LO", b"Top"),
+        sequence(
+            CONTENT,
+            &[
+                [
+                    element(CODE_VALUE, b"SH", b"ITEM"),
+                    sequence(CONCEPT_NAME, &[element(CODE_VALUE, b"SH", b"CONCEPT")]),
+                ]
+                .concat(),
+                sequence(CONTENT, &[element(CODE_VALUE, b"SH", b"NESTED")]),
+            ],
+        ),
+    ]
+    .concat()
+}
+
+/// Values of the code values kept by `filter`, and whether the concept
+/// name sequence was parsed.
+fn kept(filter: &Filter) -> (Vec<String>, bool) {
+    let mut kvmap = KVMap::new();
+    SyncDeserializer::deserialize(&mut kvmap, Cursor::new(dataset()), filter).unwrap();
+
+    let code_values = kvmap
+        .range(..)
+        .filter(|(key, _)| key.kind() == KeyKind::Element)
+        .filter(|(key, _)| key.group() == 0x0008 && key.element() == 0x0100)
+        .map(|(_, value)| String::from_utf8(value.clone()).unwrap())
+        .collect();
+    let concept_name = kvmap
+        .range(..)
+        .any(|(key, _)| key.group() == 0x0040 && key.element() == 0xA043);
+
+    (code_values, concept_name)
+}
+
+fn whitelisted(path: &str) -> Vec<String> {
+    kept(&Filter::whitelist().path(path.parse().unwrap())).0
+}
+
+#[test]
+fn x_stands_for_any_nibble() {
+    assert_eq!(whitelisted("(0008,01xx)"), ["TOP"]);
+    assert_eq!(whitelisted("0008010x"), ["TOP"]);
+    // Matching the content sequence, with its subtree.
+    assert_eq!(
+        whitelisted("(xxxx,xxxx)"),
+        ["TOP", "ITEM", "CONCEPT", "NESTED"]
+    );
+
+    // Elements of other tags are not matched.
+    let mut kvmap = KVMap::new();
+    let filter = Filter::whitelist().path("(0008,010X)".parse().unwrap());
+    SyncDeserializer::deserialize(&mut kvmap, Cursor::new(dataset()), &filter).unwrap();
+    assert_eq!(kvmap.range(..).count(), 2);
+}
+
+#[test]
+fn items_are_selected_by_number_or_any() {
+    assert_eq!(whitelisted("(0040,A730)[1].(0008,0100)"), ["ITEM"]);
+    assert_eq!(
+        whitelisted("(0040,A730)[2].(0008,0100)"),
+        Vec::<String>::new()
+    );
+    assert_eq!(whitelisted("(0040,A730)[*].(0008,0100)"), ["ITEM"]);
+    assert_eq!(whitelisted("(0040,A730).(0008,0100)"), ["ITEM"]);
+    assert_eq!(
+        whitelisted("(0040,A730)[2].(0040,A730)[*].(0008,0100)"),
+        ["NESTED"]
+    );
+}
+
+#[test]
+fn any_depth_matches_no_level_or_several() {
+    // At the start.
+    assert_eq!(
+        whitelisted("**.(0008,0100)"),
+        ["TOP", "ITEM", "CONCEPT", "NESTED"]
+    );
+    // In the middle.
+    assert_eq!(
+        whitelisted("(0040,A730).**.(0008,0100)"),
+        ["ITEM", "CONCEPT", "NESTED"]
+    );
+    assert_eq!(
+        whitelisted("(0040,A730)[2].**.(0040,A730).(0008,0100)"),
+        ["NESTED"]
+    );
+
+    // A path ends with an element.
+    for path in [
+        "(0040,A730).**",
+        "(0040,A730)[1]",
+        "(0040,A73)",
+        "(0040;A730)",
+    ] {
+        assert!(
+            matches!(path.parse::<TagPath>(), Err(DCKVError::InvalidTagPath(_))),
+            "{path}"
+        );
+    }
+}
+
+#[test]
+fn whitelists_only_look_into_sequences_paths_may_match_within() {
+    // The concept name cannot hold an element of the path.
+    assert_eq!(
+        kept(&Filter::whitelist().path("(0040,A730)[1].(0008,0100)".parse().unwrap())),
+        (vec!["ITEM".to_string()], false)
+    );
+    assert_eq!(
+        kept(&Filter::whitelist().path("(0040,A730).(0040,A043).(0008,0100)".parse().unwrap())),
+        (vec!["CONCEPT".to_string()], true)
+    );
+    assert!(kept(&Filter::whitelist().path("**.(0008,0100)".parse().unwrap())).1);
+}
+
+#[test]
+fn whitelisted_paths_keep_the_subtree_of_their_sequences() {
+    assert_eq!(
+        kept(&Filter::whitelist().path("(0040,A730)[*].(0040,A043)".parse().unwrap())),
+        (vec!["CONCEPT".to_string()], true)
+    );
+    assert_eq!(whitelisted("(0040,A730)[2].(0040,A730)"), ["NESTED"]);
+    assert_eq!(whitelisted("(0040,A730)"), ["ITEM", "CONCEPT", "NESTED"]);
+}
+
+#[test]
+fn blacklisted_paths_skip_their_position_only() {
+    assert_eq!(
+        kept(&Filter::blacklist().path("(0040,A730).**.(0008,0100)".parse().unwrap())).0,
+        ["TOP"]
+    );
+    assert_eq!(
+        kept(&Filter::blacklist().path("(0040,A730)[*].(0040,A043)".parse().unwrap())),
+        (
+            vec!["TOP".to_string(), "ITEM".to_string(), "NESTED".to_string()],
+            false
+        )
+    );
+}
+
+#[test]
+fn paths_display_in_their_canonical_form() {
+    let path: TagPath = "0040a730[2].**.(0008,010x)".parse().unwrap();
+    assert_eq!(path.to_string(), "(0040,A730)[2].**.(0008,010x)");
+    assert_eq!(path.to_string().parse::<TagPath>().unwrap(), path);
+}
@@ -0,0 +1,101 @@
+//! PN values: their groups and components, display forms and DICOM JSON.
+
+use dckv::{DCKVError, KVMap, PersonName, Value};
+
+fn name(text: &str) -> PersonName {
+    text.parse().unwrap()
+}
+
+#[test]
+fn names_parse_into_groups_and_components() {
+    let yamada = name("Yamada^Tarou=山田^太郎=やまだ^たろう");
+    let alphabetic = yamada.alphabetic().unwrap();
+    assert_eq!(
+        (alphabetic.family(), alphabetic.given()),
+        ("Yamada", "Tarou")
+    );
+    assert_eq!(yamada.ideographic().unwrap().family(), "山田");
+    assert_eq!(yamada.phonetic().unwrap().given(), "たろう");
+    assert_eq!((yamada.family(), yamada.given()), ("Yamada", "Tarou"));
+
+    let full = name("Adams^John Robert Quincy^^Rev.^B.A. M.Div.");
+    let alphabetic = full.alphabetic().unwrap();
+    assert_eq!(alphabetic.given_names(), ["John", "Robert", "Quincy"]);
+    assert_eq!(alphabetic.middle(), "");
+    assert_eq!(
+        (alphabetic.prefix(), alphabetic.suffix()),
+        ("Rev.", "B.A. M.Div.")
+    );
+
+    // Empty groups are missing, whatever their delimiters.
+    let ideographic = name("=山田^太郎");
+    assert_eq!(ideographic.alphabetic(), None);
+    assert_eq!(ideographic.family(), "");
+    assert_eq!(ideographic.ideographic().unwrap().given(), "太郎");
+    assert_eq!(name("^^=^"), PersonName::default());
+
+    for invalid in ["A=B=C=D", "A^B^C^D^E^F"] {
+        assert!(
+            matches!(
+                invalid.parse::<PersonName>(),
+                Err(DCKVError::InvalidPersonName(_))
+            ),
+            "{invalid}"
+        );
+    }
+}
+
+#[test]
+fn names_display_in_reading_order() {
+    let alphabetic = name("Apellido1>Apellido2^Nombre1^Medio^Dr.^Jr.");
+    let alphabetic = alphabetic.alphabetic().unwrap();
+    assert_eq!(alphabetic.family_names(), ["Apellido1", "Apellido2"]);
+    assert_eq!(
+        alphabetic.display(),
+        "Dr. Nombre1 Medio Apellido1 Apellido2 Jr."
+    );
+    assert_eq!(name("Doe").alphabetic().unwrap().display(), "Doe");
+}
+
+#[test]
+fn initials_follow_given_middle_and_family_names() {
+    let initials = |text: &str| name(text).alphabetic().unwrap().initials();
+
+    assert_eq!(initials("Apellido1>Apellido2^Nombre1"), "NAA");
+    assert_eq!(initials("adams^john robert^quincy"), "JRQA");
+    assert_eq!(initials("Doe"), "D");
+    assert_eq!(initials("^^^Dr."), "");
+}
+
+#[test]
+fn names_encode_without_trailing_empty_components() {
+    for (text, encoded) in [
+        ("Doe^John^^^", "Doe^John"),
+        ("Doe^John==", "Doe^John"),
+        ("Doe ^ John", "Doe^John"),
+        ("=山田^太郎", "=山田^太郎"),
+        ("Doe^^^Dr.", "Doe^^^Dr."),
+    ] {
+        assert_eq!(name(text).to_string(), encoded, "{text}");
+        assert_eq!(name(encoded), name(text));
+    }
+
+    assert_eq!(
+        name("=山田^太郎=やまだ").to_json(),
+        r#"{"Ideographic":"山田^太郎","Phonetic":"やまだ"}"#
+    );
+}
+
+#[test]
+fn malformed_names_encode_as_their_alphabetic_text() {
+    let mut kvmap = KVMap::new();
+    kvmap.insert(
+        "PatientName".parse().unwrap(),
+        Value::new(b"A=B=C=\"D\"\\Doe^John".to_vec()),
+    );
+
+    assert_eq!(
+        kvmap.to_json(|_| None).unwrap(),
+        r#"{"00100010":{"vr":"PN","Value":[{"Alphabetic":"A=B=C=\"D\""},{"Alphabetic":"Doe^John"}]}}"#
+    );
+}
@@ -0,0 +1,130 @@
+//! Private elements: the blocks their creators reserve, per dataset, and
+//! their VR resolved through a private dictionary.
+
+mod common;
+
+use std::io::Cursor;
+
+use common::{dckv_file, element, implicit_element, implicit_sequence, tag_bytes};
+use dckv::{
+    DCKVFormat, DCKVReader, DictionaryEntry, Filter, KVMap, Key, PrivateDictionary,
+    SyncDeserializer,
+};
+
+static ACME: &[DictionaryEntry] = &[DictionaryEntry::new(
+    0x00290005,
+    "AcmeWindow",
+    "Acme Window",
+    "US",
+    "1",
+)];
+
+fn parse(bytes: &[u8], kvmap: KVMap, filter: &Filter) -> KVMap {
+    let mut kvmap = kvmap;
+    SyncDeserializer::deserialize(&mut kvmap, Cursor::new(bytes), filter).unwrap();
+    kvmap
+}
+
+#[test]
+fn private_elements_are_resolved_against_the_creator_of_their_block() {
+    let dictionary = PrivateDictionary::new().creator("ACME 1.0", ACME);
+
+    // The creator reserves the block 0x11 at the top level, and 0x10 in
+    // the item, where the block 0x11 is not reserved.
+    let bytes = [
+        implicit_element(0x00290011, b"ACME 1.0"),
+        implicit_element(0x00291105, &512u16.to_le_bytes()),
+        implicit_sequence(
+            0x0040A730,
+            &[[
+                implicit_element(0x00290010, b"ACME 1.0"),
+                implicit_element(0x00291005, &256u16.to_le_bytes()),
+                implicit_element(0x00291105, &128u16.to_le_bytes()),
+            ]
+            .concat()],
+        ),
+    ]
+    .concat();
+
+    let with_dictionary = || KVMap::new().with_private_dictionary(dictionary.clone());
+    let kvmap = parse(&bytes, with_dictionary(), &Filter::none());
+    for key in [
+        "00291105:US",
+        "ContentSequence[1].00291005:US",
+        "ContentSequence[1].00291105:UN",
+    ] {
+        assert!(kvmap.get(key.parse().unwrap()).is_some(), "{key}");
+    }
+
+    // The dictionary is a setting of the parse, not of the process.
+    let kvmap = parse(&bytes, KVMap::new(), &Filter::none());
+    assert!(kvmap.get("00291105:UN".parse().unwrap()).is_some());
+
+    // The filter targets the element whichever block holds it.
+    let filter = Filter::blacklist().private(0x0029, "ACME 1.0", 0x05);
+    let kvmap = parse(&bytes, with_dictionary(), &filter);
+
+    let file = dckv_file(DCKVFormat::Dckv, |writer| kvmap.write_dckv(writer));
+    let keys = DCKVReader::new(file.as_slice())
+        .unwrap()
+        .map(|record| record.unwrap().0)
+        .collect::<Vec<Key>>();
+
+    let private = keys
+        .iter()
+        .filter(|key| key.group() == 0x0029 && key.element() >= 0x1000)
+        .collect::<Vec<&Key>>();
+    assert_eq!(private.len(), 1);
+    // The block 0x11 is not reserved in the item.
+    assert_eq!(private[0].to_string(), "0040A730[1].00291105:UN");
+    assert_eq!(kvmap.private_offset(private[0]), None);
+
+    let creators = keys
+        .iter()
+        .filter(|key| key.group() == 0x0029 && key.element() < 0x0100)
+        .count();
+    assert_eq!(creators, 2);
+    let first = keys.iter().find(|key| key.element() == 0x0011).unwrap();
+    assert_eq!(kvmap.private_offset(first), None);
+
+    // Offsets are the same whichever block the creator reserved.
+    let kvmap = parse(&bytes, with_dictionary(), &Filter::none());
+    for key in ["00291105:US", "ContentSequence[1].00291005:US"] {
+        assert_eq!(
+            kvmap.private_offset(&key.parse().unwrap()),
+            Some(("ACME 1.0".to_string(), 0x05)),
+            "{key}"
+        );
+    }
+}
+
+#[test]
+fn private_creators_are_found_whatever_their_vr_and_bounded_to_an_lo() {
+    let dictionary = PrivateDictionary::new().creator("ACME 1.0", ACME);
+
+    // A creator converted to UN, e.g. by a router, still holds its block.
+    let mut converted = tag_bytes(0x00290010);
+    converted.extend_from_slice(b"UN\0\0");
+    converted.extend_from_slice(&8u32.to_le_bytes());
+    converted.extend_from_slice(b"ACME 1.0");
+    let bytes = [converted, element(0x00291005, b"US", &256u16.to_le_bytes())].concat();
+
+    let kvmap = parse(&bytes, KVMap::new(), &Filter::none());
+    let key = "00291005:US".parse().unwrap();
+    assert_eq!(kvmap.private_creator(&key).as_deref(), Some("ACME 1.0"));
+
+    // Values longer than an LO reserve nothing.
+    let long = format!("{:<66}", "ACME 1.0");
+    let bytes = [
+        implicit_element(0x00290010, long.as_bytes()),
+        implicit_element(0x00291005, &256u16.to_le_bytes()),
+    ]
+    .concat();
+
+    let kvmap = parse(
+        &bytes,
+        KVMap::new().with_private_dictionary(dictionary),
+        &Filter::none(),
+    );
+    assert!(kvmap.get("00291005:UN".parse().unwrap()).is_some());
+}
@@ -0,0 +1,589 @@
+//! Regression suite for the sequence and item state machine (PS3.5 7.5).
+
+mod common;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use std::io::{Cursor, Read, Seek};
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+use common::{
+    element, implicit_element, implicit_sequence_of_items, item, items, sequence_of_items,
+    tag_bytes,
+};
+use dckv::{
+    BytesDeserializer, Control, DCKVError, Deserializer, Filter, Key, KeyRef, SyncDeserializer,
+    Value,
+};
+
+const ITEM_START: u64 = 0x2b2b0000;
+const ITEM_END: u64 = 0xFFFFFFFF5F5F0000;
+const UNDEFINED: u32 = u32::MAX;
+
+#[derive(Debug, Clone, Default)]
+struct Recorder {
+    keys: Vec<Vec<u8>>,
+    values: Vec<Vec<u8>>,
+    /// Controls returned for given keys, `Continue` otherwise.
+    controls: Vec<(Vec<u8>, Control)>,
+}
+
+#[async_trait(?Send)]
+impl Deserializer for Recorder {
+    async fn append<R: AsyncReadExt + AsyncSeekExt + Unpin>(
+        &mut self,
+        reader: &mut R,
+        key: Key,
+        length: usize,
+        _vr: Option<u16>,
+    ) -> Result<Control, DCKVError> {
+        let control = self
+            .controls
+            .iter()
+            .find(|(control_key, _)| control_key == key.bytes())
+            .map_or(Control::Continue, |&(_, control)| control);
+
+        let value = match control {
+            Control::Skip => vec![],
+            _ => Value::read(reader, length).await?.into_bytes(),
+        };
+        self.keys.push(key.into_bytes());
+        self.values.push(value);
+        Ok(control)
+    }
+}
+
+impl SyncDeserializer for Recorder {
+    fn append<R: Read + Seek>(
+        &mut self,
+        reader: &mut R,
+        key: Key,
+        length: usize,
+        _vr: Option<u16>,
+    ) -> Result<Control, DCKVError> {
+        let value = Value::read_sync(reader, length)?;
+        self.keys.push(key.into_bytes());
+        self.values.push(value.into_bytes());
+        Ok(Control::Continue)
+    }
+}
+
+impl BytesDeserializer for Recorder {
+    fn append(
+        &mut self,
+        key: KeyRef<'_>,
+        value: Bytes,
+        _vr: Option<u16>,
+    ) -> Result<Control, DCKVError> {
+        self.keys.push(key.bytes().to_vec());
+        self.values.push(value.to_vec());
+        Ok(Control::Continue)
+    }
+}
+
+async fn parse(bytes: Vec<u8>) -> Result<Recorder, DCKVError> {
+    parse_with(bytes, vec![]).await
+}
+
+async fn parse_with(
+    bytes: Vec<u8>,
+    controls: Vec<(Vec<u8>, Control)>,
+) -> Result<Recorder, DCKVError> {
+    let mut recorder = Recorder {
+        controls,
+        ..Recorder::default()
+    };
+    Deserializer::deserialize(&mut recorder, Cursor::new(bytes), &Filter::none()).await?;
+    Ok(recorder)
+}
+
+fn key(blocks: &[u64]) -> Vec<u8> {
+    blocks
+        .iter()
+        .flat_map(|block| block.to_be_bytes())
+        .collect()
+}
+
+fn element_block(tag: u32, vr: &[u8; 2]) -> u64 {
+    ((tag as u64) << 32) + ((u16::from_be_bytes(*vr) as u64) << 16)
+}
+
+fn sequence_block(tag: u32) -> u64 {
+    (tag as u64) << 32
+}
+
+fn item_block(tag: u32, item_number: u64) -> u64 {
+    ((tag as u64) << 32) + item_number
+}
+
+fn sequence_end_block(tag: u32) -> u64 {
+    ((tag as u64) << 32) + 0xFFFF0000
+}
+
+fn element_or_implicit(tag: u32, vr: &[u8; 2], value: &[u8], implicit: bool) -> Vec<u8> {
+    match implicit {
+        true => implicit_element(tag, value),
+        false => element(tag, vr, value),
+    }
+}
+
+const CODE_VALUE: u32 = 0x00080100;
+const CODE_MEANING: u32 = 0x00080104;
+const CONCEPT_NAME: u32 = 0x0040A043;
+const CONTENT: u32 = 0x0040A730;
+const PATIENT_NAME: u32 = 0x00100010;
+
+/// Keys of a (0040,A730) sequence with two items, each holding a
+/// (0008,0100) element, followed by (0010,0010).
+fn two_items_keys() -> Vec<Vec<u8>> {
+    vec![
+        key(&[sequence_block(CONTENT)]),
+        key(&[item_block(CONTENT, 1), ITEM_START]),
+        key(&[item_block(CONTENT, 1), element_block(CODE_VALUE, b"SH")]),
+        key(&[item_block(CONTENT, 1), ITEM_END]),
+        key(&[item_block(CONTENT, 2), ITEM_START]),
+        key(&[item_block(CONTENT, 2), element_block(CODE_VALUE, b"SH")]),
+        key(&[item_block(CONTENT, 2), ITEM_END]),
+        key(&[sequence_end_block(CONTENT)]),
+        key(&[element_block(PATIENT_NAME, b"PN")]),
+    ]
+}
+
+#[tokio::test]
+async fn every_length_combination_gives_the_same_keys() {
+    for defined_sequence in [true, false] {
+        for defined_items in [true, false] {
+            let bytes = [
+                sequence_of_items(
+                    CONTENT,
+                    &[
+                        item(&element(CODE_VALUE, b"SH", b"121071"), defined_items),
+                        item(&element(CODE_VALUE, b"SH", b"111776"), defined_items),
+                    ],
+                    defined_sequence,
+                ),
+                element(PATIENT_NAME, b"PN", b"Doe^John"),
+            ]
+            .concat();
+
+            let recorder = parse(bytes).await.unwrap();
+
+            assert_eq!(
+                recorder.keys,
+                two_items_keys(),
+                "defined sequence: {defined_sequence}, defined items: {defined_items}"
+            );
+            assert_eq!(recorder.values[2], b"121071");
+            assert_eq!(recorder.values[5], b"111776");
+            assert_eq!(recorder.values[8], b"Doe^John");
+        }
+    }
+}
+
+#[tokio::test]
+async fn nested_sequences_with_mixed_lengths() {
+    let concept_name = |defined| {
+        sequence_of_items(
+            CONCEPT_NAME,
+            &[item(
+                &[
+                    element(CODE_VALUE, b"SH", b"11528-7"),
+                    element(CODE_MEANING, b"LO", b"Report"),
+                ]
+                .concat(),
+                defined,
+            )],
+            !defined,
+        )
+    };
+
+    let bytes = [
+        sequence_of_items(
+            CONTENT,
+            &[
+                item(&concept_name(true), false),
+                item(&concept_name(false), true),
+            ],
+            true,
+        ),
+        element(PATIENT_NAME, b"PN", b"Doe^John"),
+    ]
+    .concat();
+
+    let recorder = parse(bytes).await.unwrap();
+
+    let mut expected = vec![key(&[sequence_block(CONTENT)])];
+    for item_number in 1..=2 {
+        let parent = item_block(CONTENT, item_number);
+        let nested = item_block(CONCEPT_NAME, 1);
+        expected.extend([
+            key(&[parent, ITEM_START]),
+            key(&[parent, sequence_block(CONCEPT_NAME)]),
+            key(&[parent, nested, ITEM_START]),
+            key(&[parent, nested, element_block(CODE_VALUE, b"SH")]),
+            key(&[parent, nested, element_block(CODE_MEANING, b"LO")]),
+            key(&[parent, nested, ITEM_END]),
+            key(&[parent, sequence_end_block(CONCEPT_NAME)]),
+            key(&[parent, ITEM_END]),
+        ]);
+    }
+    expected.extend([
+        key(&[sequence_end_block(CONTENT)]),
+        key(&[element_block(PATIENT_NAME, b"PN")]),
+    ]);
+
+    assert_eq!(recorder.keys, expected);
+}
+
+#[tokio::test]
+async fn empty_sequences_and_items() {
+    for defined in [true, false] {
+        let bytes = [
+            sequence_of_items(CONCEPT_NAME, &[], defined),
+            sequence_of_items(CONTENT, &[item(&[], defined)], defined),
+            element(PATIENT_NAME, b"PN", b"Doe^John"),
+        ]
+        .concat();
+
+        let recorder = parse(bytes).await.unwrap();
+
+        assert_eq!(
+            recorder.keys,
+            vec![
+                key(&[sequence_block(CONCEPT_NAME)]),
+                key(&[sequence_end_block(CONCEPT_NAME)]),
+                key(&[sequence_block(CONTENT)]),
+                key(&[item_block(CONTENT, 1), ITEM_START]),
+                key(&[item_block(CONTENT, 1), ITEM_END]),
+                key(&[sequence_end_block(CONTENT)]),
+                key(&[element_block(PATIENT_NAME, b"PN")]),
+            ],
+            "defined: {defined}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn implicit_vr_sequences() {
+    for defined in [true, false] {
+        let bytes = [
+            implicit_sequence_of_items(
+                CONTENT,
+                &[
+                    item(&implicit_element(CODE_VALUE, b"121071"), defined),
+                    item(&implicit_element(CODE_VALUE, b"111776"), !defined),
+                ],
+                defined,
+            ),
+            implicit_element(PATIENT_NAME, b"Doe^John"),
+        ]
+        .concat();
+
+        let recorder = parse(bytes).await.unwrap();
+
+        assert_eq!(recorder.keys, two_items_keys(), "defined: {defined}");
+    }
+}
+
+#[tokio::test]
+async fn undefined_length_un_is_an_implicit_vr_sequence() {
+    let content = items(
+        &[
+            item(&implicit_element(CODE_VALUE, b"121071"), true),
+            item(&implicit_element(CODE_VALUE, b"111776"), false),
+        ],
+        false,
+    );
+
+    let mut bytes = tag_bytes(CONTENT);
+    bytes.extend_from_slice(b"UN\0\0");
+    bytes.extend_from_slice(&UNDEFINED.to_le_bytes());
+    bytes.extend_from_slice(&content);
+    bytes.extend_from_slice(&element(PATIENT_NAME, b"PN", b"Doe^John"));
+
+    let recorder = parse(bytes).await.unwrap();
+
+    assert_eq!(recorder.keys, two_items_keys());
+}
+
+#[tokio::test]
+async fn unknown_implicit_vr_sequences_are_recognized_by_their_first_item() {
+    // (0029,1001) is a private sequence and (0029,1002) a private value,
+    // both missing from the dictionary.
+    let dataset = |implicit: bool| {
+        let field = |tag, vr, value: &[u8]| element_or_implicit(tag, vr, value, implicit);
+        let items = |items: &[Vec<u8>], defined| match implicit {
+            true => implicit_sequence_of_items(0x00291001, items, defined),
+            false => sequence_of_items(0x00291001, items, defined),
+        };
+        let mut private_value = tag_bytes(0x00291002);
+        if !implicit {
+            private_value.extend_from_slice(b"UN\0\0");
+        }
+        private_value.extend_from_slice(&8u32.to_le_bytes());
+        private_value.extend_from_slice(b"01234567");
+
+        [
+            field(PATIENT_NAME, b"PN", b"Doe^John"),
+            field(0x00290010, b"LO", b"ACME 1.0"),
+            items(&[item(&field(CODE_VALUE, b"SH", b"121071"), true)], true),
+            private_value,
+        ]
+        .concat()
+    };
+
+    let implicit = parse(dataset(true)).await.unwrap();
+    let explicit = parse(dataset(false)).await.unwrap();
+
+    assert_eq!(implicit.keys, explicit.keys);
+    assert_eq!(implicit.values, explicit.values);
+    assert!(implicit.keys.contains(&key(&[
+        item_block(0x00291001, 1),
+        element_block(CODE_VALUE, b"SH")
+    ])));
+    assert!(implicit
+        .keys
+        .contains(&key(&[element_block(0x00291002, b"UN")])));
+}
+
+#[tokio::test]
+async fn item_longer_than_its_sequence_fails() {
+    let mut content = item(&element(CODE_VALUE, b"SH", b"121071"), true);
+    // Item length 2 bytes beyond the end of the sequence.
+    content[4] += 2;
+
+    let mut bytes = tag_bytes(CONTENT);
+    bytes.extend_from_slice(b"SQ\0\0");
+    bytes.extend_from_slice(&(content.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(&content);
+    bytes.extend_from_slice(&element(PATIENT_NAME, b"PN", b"Doe^John"));
+
+    assert!(matches!(
+        parse(bytes).await,
+        Err(DCKVError::InvalidSQItemLength)
+    ));
+}
+
+#[tokio::test]
+async fn sequence_longer_than_its_item_fails() {
+    let mut nested = sequence_of_items(CONCEPT_NAME, &[], true);
+    // Sequence length 8 bytes beyond the end of the item.
+    nested[8] += 8;
+
+    let bytes = [
+        sequence_of_items(CONTENT, &[item(&nested, true)], true),
+        element(PATIENT_NAME, b"PN", b"Doe^John"),
+    ]
+    .concat();
+
+    assert!(matches!(
+        parse(bytes).await,
+        Err(DCKVError::InvalidSQLength)
+    ));
+}
+
+#[tokio::test]
+async fn item_outside_a_sequence_fails() {
+    let bytes = item(&element(CODE_VALUE, b"SH", b"121071"), true);
+
+    assert!(matches!(parse(bytes).await, Err(DCKVError::InvalidSQTag)));
+}
+
+#[tokio::test]
+async fn skipped_sequences_and_items_are_not_parsed() {
+    for defined in [true, false] {
+        let bytes = [
+            sequence_of_items(
+                CONCEPT_NAME,
+                &[item(&element(CODE_VALUE, b"SH", b"11528-7"), defined)],
+                defined,
+            ),
+            sequence_of_items(
+                CONTENT,
+                &[
+                    item(&element(CODE_VALUE, b"SH", b"121071"), defined),
+                    item(&element(CODE_VALUE, b"SH", b"111776"), defined),
+                ],
+                defined,
+            ),
+            element(PATIENT_NAME, b"PN", b"Doe^John"),
+        ]
+        .concat();
+
+        let controls = vec![
+            (key(&[sequence_block(CONCEPT_NAME)]), Control::Skip),
+            (key(&[item_block(CONTENT, 1), ITEM_START]), Control::Skip),
+        ];
+        let recorder = parse_with(bytes, controls).await.unwrap();
+
+        assert_eq!(
+            recorder.keys,
+            vec![
+                key(&[sequence_block(CONCEPT_NAME)]),
+                key(&[sequence_block(CONTENT)]),
+                key(&[item_block(CONTENT, 1), ITEM_START]),
+                key(&[item_block(CONTENT, 2), ITEM_START]),
+                key(&[item_block(CONTENT, 2), element_block(CODE_VALUE, b"SH")]),
+                key(&[item_block(CONTENT, 2), ITEM_END]),
+                key(&[sequence_end_block(CONTENT)]),
+                key(&[element_block(PATIENT_NAME, b"PN")]),
+            ],
+            "defined: {defined}"
+        );
+        assert_eq!(recorder.values[4], b"111776");
+    }
+}
+
+#[tokio::test]
+async fn stop_ends_the_parsing() {
+    let bytes = [
+        sequence_of_items(
+            CONTENT,
+            &[item(&element(CODE_VALUE, b"SH", b"121071"), false)],
+            false,
+        ),
+        element(PATIENT_NAME, b"PN", b"Doe^John"),
+    ]
+    .concat();
+
+    let controls = vec![(
+        key(&[item_block(CONTENT, 1), element_block(CODE_VALUE, b"SH")]),
+        Control::Stop,
+    )];
+    let recorder = parse_with(bytes, controls).await.unwrap();
+
+    assert_eq!(recorder.keys, two_items_keys()[..3]);
+}
+
+#[tokio::test]
+async fn truncated_value_fails() {
+    let mut bytes = element(PATIENT_NAME, b"PN", b"Doe^John");
+    bytes.truncate(bytes.len() - 2);
+
+    assert!(matches!(parse(bytes).await, Err(DCKVError::IOError(_))));
+}
+
+#[tokio::test]
+async fn truncated_sequences_fail() {
+    let nested = |defined| {
+        sequence_of_items(
+            CONTENT,
+            &[item(
+                &sequence_of_items(
+                    CONCEPT_NAME,
+                    &[item(&element(CODE_VALUE, b"SH", b"121071"), defined)],
+                    defined,
+                ),
+                defined,
+            )],
+            defined,
+        )
+    };
+
+    for defined in [false, true] {
+        let bytes = nested(defined);
+        // Cut before the nested element, or else before each delimiter.
+        let cuts = match defined {
+            true => vec![bytes.len() - 14],
+            false => vec![
+                bytes.len() - 32,
+                bytes.len() - 24,
+                bytes.len() - 16,
+                bytes.len() - 8,
+            ],
+        };
+        for cut in cuts {
+            let truncated = bytes[..cut].to_vec();
+            assert!(
+                matches!(parse(truncated.clone()).await, Err(DCKVError::TruncatedSQ)),
+                "{defined} {cut}"
+            );
+
+            let mut recorder = Recorder::default();
+            assert!(matches!(
+                recorder.deserialize_bytes(&truncated, &Filter::none()),
+                Err(DCKVError::TruncatedSQ)
+            ));
+        }
+    }
+}
+
+#[tokio::test]
+async fn sync_and_bytes_parsers_give_the_same_keys_and_values() {
+    let sample = std::fs::read(concat!(env!("CARGO_MANIFEST_DIR"), "/../sr.dcm")).unwrap();
+    let undefined = [
+        sequence_of_items(
+            CONTENT,
+            &[item(&element(CODE_VALUE, b"SH", b"121071"), false)],
+            false,
+        ),
+        element(PATIENT_NAME, b"PN", b"Doe^John"),
+    ]
+    .concat();
+
+    for bytes in [sample, undefined] {
+        let expected = parse(bytes.clone()).await.unwrap();
+
+        let mut recorder = Recorder::default();
+        recorder.deserialize_bytes(&bytes, &Filter::none()).unwrap();
+
+        assert_eq!(recorder.keys, expected.keys);
+        assert_eq!(recorder.values, expected.values);
+
+        let mut recorder = Recorder::default();
+        BytesDeserializer::deserialize(&mut recorder, Bytes::from(bytes), &Filter::none()).unwrap();
+
+        assert_eq!(recorder.keys, expected.keys);
+        assert_eq!(recorder.values, expected.values);
+    }
+}
+
+/// sr.dcm holds defined length sequences only, with back-to-back defined
+/// length items, empty sequences and sequences nested two levels deep.
+#[tokio::test]
+async fn structured_report_sample() {
+    let bytes = std::fs::read(concat!(env!("CARGO_MANIFEST_DIR"), "/../sr.dcm")).unwrap();
+
+    let recorder = parse(bytes).await.unwrap();
+
+    let content_items = (1..=5)
+        .map(|item_number| key(&[item_block(CONTENT, item_number), ITEM_START]))
+        .filter(|item_key| recorder.keys.contains(item_key))
+        .count();
+    assert_eq!(content_items, 5);
+
+    let nested = key(&[
+        item_block(CONTENT, 5),
+        item_block(CONCEPT_NAME, 1),
+        element_block(CODE_MEANING, b"LO"),
+    ]);
+    assert!(recorder.keys.contains(&nested));
+
+    // Every item and sequence is closed at the level it was opened.
+    let mut open = vec![];
+    for key in &recorder.keys {
+        let last = u64::from_be_bytes(key[key.len() - 8..].try_into().unwrap());
+        match last {
+            ITEM_START => open.push(key[..key.len() - 8].to_vec()),
+            ITEM_END => assert_eq!(open.pop().as_deref(), Some(&key[..key.len() - 8])),
+            _ => {}
+        }
+    }
+    assert!(open.is_empty());
+
+    for tag in [0x00081111u32, 0x0040A372, CONTENT] {
+        let start = recorder
+            .keys
+            .iter()
+            .position(|k| *k == key(&[sequence_block(tag)]));
+        let end = recorder
+            .keys
+            .iter()
+            .position(|k| *k == key(&[sequence_end_block(tag)]));
+        assert!(start < end, "({:04x},{:04x})", tag >> 16, tag & 0xFFFF);
+    }
+
+    // Top-level elements after the last sequence are not swallowed by it.
+    let last = recorder.keys.last().unwrap();
+    assert_eq!(last.len(), 8);
+}
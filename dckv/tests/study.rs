@@ -0,0 +1,112 @@
+//! Study maps: the instances of a study under eDCKV keys.
+
+mod common;
+
+use common::{dckv_file, element, kvmap};
+use dckv::{DCKVError, DCKVFormat, DCKVReader, DCKVWriter, EKey, KVMap, Key, KeyKind, StudyMap};
+
+/// Instance of a series, with its numbers when given.
+fn instance(series: &[u8], series_number: &[u8], uid: &[u8], number: &[u8]) -> KVMap {
+    let mut bytes = element(0x00080018, b"UI", uid);
+    if !number.is_empty() {
+        bytes.extend(element(0x00100010, b"PN", b"Doe^John"));
+        bytes.extend(element(0x00200013, b"IS", number));
+    }
+    bytes.extend(element(0x0020000E, b"UI", series));
+    if !series_number.is_empty() {
+        bytes.extend(element(0x00200011, b"IS", series_number));
+    }
+    kvmap(&bytes)
+}
+
+#[test]
+fn study_maps_sort_by_series_instance_and_attribute() {
+    let first = instance(b"1.2.1", b"", b"1.2.3.4", b"");
+    let second = instance(b"1.2.1", b"", b"1.2.3.5", b"");
+
+    let mut study = StudyMap::new();
+    study.add_instance(2, 1, first.clone());
+    study.add_instance(1, 7, second.clone());
+    study.add_instance(1, 3, first.clone());
+
+    assert_eq!(study.instance(1, 7), second);
+    assert_eq!(study.instance(2, 1), first);
+    assert_eq!(study.instance(3, 1), KVMap::new());
+    assert_eq!(
+        study.instances().collect::<Vec<_>>(),
+        [(1, 3), (1, 7), (2, 1)]
+    );
+
+    let file = dckv_file(DCKVFormat::Edckv, |writer| study.write_dckv(writer));
+    let keys = DCKVReader::edckv(file.as_slice())
+        .unwrap()
+        .map(|record| record.unwrap().0)
+        .collect::<Vec<EKey>>();
+    assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!((keys[0].series(), keys[0].instance()), (1, 3));
+    let last = &keys[keys.len() - 1];
+    assert_eq!((last.series(), last.instance()), (2, 1));
+
+    let reader = DCKVReader::edckv(file.as_slice()).unwrap();
+    assert_eq!(StudyMap::read_dckv(reader).unwrap(), study);
+}
+
+#[test]
+fn study_maps_write_edckv_files_only() {
+    let study = StudyMap::from_instances([instance(b"1.2.1", b"1", b"1.2.1.1", b"1")]);
+
+    let mut writer = DCKVWriter::new(vec![], DCKVFormat::Dckv).unwrap();
+    assert!(matches!(
+        study.write_dckv(&mut writer),
+        Err(DCKVError::UnexpectedDCKVFormat(DCKVFormat::Dckv))
+    ));
+
+    let mut writer = DCKVWriter::new(vec![], DCKVFormat::Edckv).unwrap();
+    assert!(matches!(
+        study.instance(1, 1).write_dckv(&mut writer),
+        Err(DCKVError::UnexpectedDCKVFormat(DCKVFormat::Edckv))
+    ));
+}
+
+#[test]
+fn instances_are_numbered_by_series_and_instance_number() {
+    let study = StudyMap::from_instances([
+        instance(b"1.2.2", b"2 ", b"1.2.2.2", b"10"),
+        instance(b"1.2.9", b"", b"1.2.9.1", b"1 "),
+        instance(b"1.2.2", b"2 ", b"1.2.2.1", b"9 "),
+        instance(b"1.2.1", b"1 ", b"1.2.1.2", b""),
+        instance(b"1.2.1", b"1 ", b"1.2.1.1", b""),
+        instance(b"1.2.3", b"10", b"1.2.3.1", b"1 "),
+    ]);
+
+    let uid = |series, instance| {
+        let key = EKey::new(
+            series,
+            instance,
+            "SOPInstanceUID".parse::<Key>().unwrap().as_key_ref(),
+        );
+        study.get(&key).cloned()
+    };
+    assert_eq!(
+        study.instances().collect::<Vec<_>>(),
+        [(1, 1), (1, 2), (2, 1), (2, 2), (3, 1), (4, 1)]
+    );
+    // Series 1 by SOP Instance UID, series 2 by Instance Number, and the
+    // series without a Series Number last.
+    assert_eq!(uid(1, 1).unwrap(), b"1.2.1.1");
+    assert_eq!(uid(1, 2).unwrap(), b"1.2.1.2");
+    assert_eq!(uid(2, 1).unwrap(), b"1.2.2.1");
+    assert_eq!(uid(2, 2).unwrap(), b"1.2.2.2");
+    assert_eq!(uid(3, 1).unwrap(), b"1.2.3.1");
+    assert_eq!(uid(4, 1).unwrap(), b"1.2.9.1");
+}
+
+#[test]
+fn ekeys_display_their_numbers_before_their_key() {
+    let key: Key = "ContentSequence[2].start".parse().unwrap();
+    let ekey = EKey::new(3, 12, key.as_key_ref());
+
+    assert_eq!(ekey.to_string(), "3/12/0040A730[2].start");
+    assert_eq!((ekey.level(), ekey.kind()), (1, KeyKind::ItemStart));
+    assert_eq!(ekey.key().to_key(), key);
+}
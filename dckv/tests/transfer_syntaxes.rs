@@ -0,0 +1,180 @@
+//! Datasets of every native transfer syntax, behind their file meta
+//! information (PS3.5 A).
+
+mod common;
+
+use bytes::Bytes;
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+use std::io::{Cursor, Write};
+
+use common::{big_endian_element, big_endian_sequence, element, sequence};
+use dckv::{
+    BytesDeserializer, DCKVError, Deserializer, Filter, KVMap, SyncDeserializer, TransferSyntax,
+    Value,
+};
+
+const EXPLICIT_LITTLE_ENDIAN: &str = "1.2.840.10008.1.2.1";
+const DEFLATED: &str = "1.2.840.10008.1.2.1.99";
+const BIG_ENDIAN: &str = "1.2.840.10008.1.2.2";
+
+/// Preamble, prefix and file meta information of a file in `syntax`.
+fn file_meta(syntax: &str) -> Vec<u8> {
+    let mut uid = syntax.as_bytes().to_vec();
+    if uid.len() % 2 == 1 {
+        uid.push(0);
+    }
+    let transfer_syntax = element(0x00020010, b"UI", &uid);
+    let group_length = element(
+        0x00020000,
+        b"UL",
+        &(transfer_syntax.len() as u32).to_le_bytes(),
+    );
+
+    [
+        vec![0; 128],
+        b"DICM".to_vec(),
+        group_length,
+        transfer_syntax,
+    ]
+    .concat()
+}
+
+/// Map of `bytes`, checked to be the same with every parser.
+async fn parse(bytes: Vec<u8>) -> Result<KVMap, DCKVError> {
+    let mut kvmap = KVMap::new();
+    Deserializer::deserialize(&mut kvmap, Cursor::new(bytes.clone()), &Filter::none()).await?;
+
+    let mut sync = KVMap::new();
+    SyncDeserializer::deserialize(&mut sync, Cursor::new(bytes.clone()), &Filter::none())?;
+    assert_eq!(sync, kvmap);
+
+    let mut from_bytes = KVMap::new();
+    BytesDeserializer::deserialize(&mut from_bytes, Bytes::from(bytes), &Filter::none())?;
+    assert_eq!(from_bytes, kvmap);
+
+    Ok(kvmap)
+}
+
+fn value(kvmap: &KVMap, key: &str) -> Value {
+    let value = kvmap.get(key.parse().unwrap());
+    Value::new(value.unwrap_or_else(|| panic!("{key} is missing")).clone())
+}
+
+/// Entries of the dataset, out of the file meta information.
+fn entries(kvmap: &KVMap) -> Vec<(String, Vec<u8>)> {
+    kvmap
+        .range(..)
+        .filter(|(key, _)| key.group() != 0x0002)
+        .map(|(key, value)| (key.to_string(), value.clone()))
+        .collect()
+}
+
+#[tokio::test]
+async fn big_endian_numbers_are_swapped() {
+    let mut lut = vec![];
+    for number in [1u16, 0x0203, 0xFFFE] {
+        lut.extend_from_slice(&number.to_be_bytes());
+    }
+    let dataset = [
+        big_endian_element(0x00189087, b"FD", &1000.5f64.to_be_bytes()),
+        big_endian_sequence(
+            0x00209222,
+            &[big_endian_element(
+                0x00209165,
+                b"AT",
+                &[0x00, 0x18, 0x90, 0x87],
+            )],
+        ),
+        big_endian_element(0x00280010, b"US", &512u16.to_be_bytes()),
+        big_endian_element(0x00281201, b"OW", &lut),
+    ]
+    .concat();
+
+    let kvmap = parse([file_meta(BIG_ENDIAN), dataset].concat())
+        .await
+        .unwrap();
+
+    assert_eq!(value(&kvmap, "00189087:FD").to_f64s().unwrap(), [1000.5]);
+    assert_eq!(
+        value(&kvmap, "00209222[1].DimensionIndexPointer")
+            .to_tags()
+            .unwrap(),
+        [(0x0018, 0x9087)]
+    );
+    assert_eq!(value(&kvmap, "Rows").to_u16s().unwrap(), [512]);
+    assert_eq!(
+        value(&kvmap, "RedPaletteColorLookupTableData")
+            .to_u16s()
+            .unwrap(),
+        [1, 0x0203, 0xFFFE]
+    );
+}
+
+#[tokio::test]
+async fn deflated_datasets_are_inflated() {
+    let dataset = [
+        element(0x00080060, b"CS", b"SR"),
+        sequence(
+            0x0040A730,
+            &[
+                element(0x0040A040, b"CS", b"TEXT"),
+                [
+                    element(0x0040A040, b"CS", b"CONTAINER"),
+                    sequence(0x0040A730, &[element(0x0040A160, b"UT", b"Nested")]),
+                ]
+                .concat(),
+            ],
+        ),
+        element(0x00100010, b"PN", b"Doe^John"),
+    ]
+    .concat();
+
+    let mut encoder = DeflateEncoder::new(vec![], Compression::default());
+    encoder.write_all(&dataset).unwrap();
+    let deflated = encoder.finish().unwrap();
+
+    let inflated = parse([file_meta(EXPLICIT_LITTLE_ENDIAN), dataset].concat())
+        .await
+        .unwrap();
+    let kvmap = parse([file_meta(DEFLATED), deflated].concat())
+        .await
+        .unwrap();
+
+    assert_eq!(entries(&kvmap), entries(&inflated));
+    assert_eq!(
+        value(&kvmap, "ContentSequence[2].ValueType").bytes(),
+        b"CONTAINER"
+    );
+    assert_eq!(
+        value(&kvmap, "ContentSequence[2].ContentSequence[1].TextValue").bytes(),
+        b"Nested"
+    );
+    assert_eq!(value(&kvmap, "PatientName").bytes(), b"Doe^John");
+}
+
+#[tokio::test]
+async fn encapsulated_syntaxes_are_explicit_vr_little_endian() {
+    let dataset = element(0x00100010, b"PN", b"Doe^John");
+
+    for uid in ["1.2.840.10008.1.2.4.50", "1.2.840.10008.1.2.5"] {
+        assert_eq!(
+            TransferSyntax::from_uid(uid).unwrap(),
+            TransferSyntax::ExplicitVRLittleEndian
+        );
+        let kvmap = parse([file_meta(uid), dataset.clone()].concat())
+            .await
+            .unwrap();
+        assert_eq!(value(&kvmap, "PatientName").bytes(), b"Doe^John");
+    }
+}
+
+#[tokio::test]
+async fn unknown_transfer_syntaxes_fail() {
+    let dataset = element(0x00100010, b"PN", b"Doe^John");
+
+    assert!(matches!(
+        parse([file_meta("1.2.3.4"), dataset].concat()).await,
+        Err(DCKVError::UnsupportedTransferSyntax(uid)) if uid == "1.2.3.4"
+    ));
+}
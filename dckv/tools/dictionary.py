@@ -0,0 +1,141 @@
+#!/usr/bin/env python3
+"""Generates src/dictionary/registry.rs from the DocBook source of PS3.6.
+
+    curl -LO https://dicom.nema.org/medical/dicom/current/source/docbook/part06/part06.xml
+    python3 tools/dictionary.py part06.xml
+
+With `--check`, the registry is compared with the one generated instead of
+being written, e.g. to tell whether it is up to date with an edition:
+
+    python3 tools/dictionary.py --check part06.xml
+
+The registry holds the tables of data elements (6-1), file meta elements
+(7-1) and directory structuring elements (8-1).
+"""
+
+import sys
+import xml.etree.ElementTree as ET
+from pathlib import Path
+
+NS = {"db": "http://docbook.org/ns/docbook"}
+TABLES = ("table_6-1", "table_7-1", "table_8-1")
+OUTPUT = Path(__file__).resolve().parent.parent / "src" / "dictionary" / "registry.rs"
+
+
+def text(cell):
+    """Text of a table cell, without the zero width spaces of long names."""
+    return "".join(cell.itertext()).replace("​", "").strip()
+
+
+def parse_tag(tag):
+    """Tag value and mask of a tag such as `(60xx,3000)`, `x` digits matching
+    any digit."""
+    digits = tag.strip("()").replace(",", "")
+    value = int(digits.replace("x", "0").replace("X", "0"), 16)
+    mask = int("".join("0" if d in "xX" else "F" for d in digits), 16)
+    return value, mask
+
+
+def read(path):
+    """Entries of the registry tables, as (tag, mask, keyword, name, vr, vm,
+    retired) tuples."""
+    root = ET.parse(path).getroot()
+    entries = []
+    for table in root.iter("{%s}table" % NS["db"]):
+        if table.get("{http://www.w3.org/XML/1998/namespace}id") not in TABLES:
+            continue
+        for row in table.iterfind("db:tbody/db:tr", NS):
+            cells = [text(cell) for cell in row.iterfind("db:td", NS)]
+            if len(cells) < 5:
+                continue
+            tag, name, keyword, vr, vm = cells[:5]
+            note = cells[5] if len(cells) > 5 else ""
+            # Items and delimiters have no keyword nor VR.
+            if not keyword or not vr or "See Note" in vr:
+                continue
+            value, mask = parse_tag(tag)
+            entries.append((value, mask, keyword, name, vr, vm, note.startswith("RET")))
+    return entries
+
+
+def literal(value):
+    return '"%s"' % value.replace("\\", "\\\\").replace('"', '\\"')
+
+
+def entry(value, mask, keyword, name, vr, vm, retired):
+    code = "E::new(0x%08X, %s, %s, %s, %s)" % (
+        value,
+        literal(keyword),
+        literal(name),
+        literal(vr),
+        literal(vm),
+    )
+    if mask != 0xFFFFFFFF:
+        code += ".repeating(0x%08X)" % mask
+    if retired:
+        code += ".retired()"
+    return "    %s,\n" % code
+
+
+def render(entries):
+    elements = sorted((e for e in entries if e[1] == 0xFFFFFFFF), key=lambda e: e[0])
+    repeating = sorted((e for e in entries if e[1] != 0xFFFFFFFF), key=lambda e: e[0])
+    keywords = sorted((e[2], e[0]) for e in entries)
+
+    code = [
+        "//! Registry of DICOM data elements (PS3.6 6, 7 and 8), generated by\n",
+        "//! `tools/dictionary.py`.\n",
+        "\n",
+        "use super::DictionaryEntry as E;\n",
+        "\n",
+        "/// Elements sorted by tag.\n",
+        "#[rustfmt::skip]\n",
+        "pub(super) static ELEMENTS: &[E] = &[\n",
+    ]
+    code += [entry(*e) for e in elements]
+    code += [
+        "];\n",
+        "\n",
+        "/// Elements of repeating groups, e.g. (60xx,3000), sorted by tag.\n",
+        "#[rustfmt::skip]\n",
+        "pub(super) static REPEATING: &[E] = &[\n",
+    ]
+    code += [entry(*e) for e in repeating]
+    code += [
+        "];\n",
+        "\n",
+        "/// Keywords sorted for binary search, with the tag they stand for.\n",
+        "#[rustfmt::skip]\n",
+        "pub(super) static KEYWORDS: &[(&str, u32)] = &[\n",
+    ]
+    code += ["    (%s, 0x%08X),\n" % (literal(k), t) for k, t in keywords]
+    code += ["];\n"]
+    return "".join(code)
+
+
+def write(entries, output=OUTPUT):
+    output.write_text(render(entries))
+
+
+def check(entries, output=OUTPUT):
+    """Whether `output` is the registry of `entries`, telling the number of
+    entries of both otherwise."""
+    current = output.read_text()
+    if current == render(entries):
+        return True
+    print(
+        "%s holds %d entries, PS3.6 %d"
+        % (output.name, current.count("E::new("), len(entries)),
+        file=sys.stderr,
+    )
+    return False
+
+
+if __name__ == "__main__":
+    match sys.argv[1:]:
+        case [path]:
+            write(read(path))
+        case ["--check", path]:
+            sys.exit(0 if check(read(path)) else 1)
+        case _:
+            sys.exit(__doc__)